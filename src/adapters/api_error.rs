@@ -0,0 +1,247 @@
+//! Mapping of failed YNAB API responses to typed errors.
+//!
+//! YNAB reports failures as `{"error": {"id": "404.2", "name": "resource_not_found",
+//! "detail": "Resource not found"}}`. The status code picks the [`YnabError`]
+//! variant, and for `404` the request path identifies which resource is missing.
+
+use crate::domain::YnabError;
+use serde_json::Value;
+use std::time::Duration;
+
+/// The `error` object of a failed YNAB API response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiErrorBody {
+    pub id: String,
+    pub name: String,
+    pub detail: String,
+}
+
+impl ApiErrorBody {
+    /// Parses an error body, falling back to the status code when fields are missing.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ApiErrorBody;
+    /// use serde_json::json;
+    ///
+    /// let body = json!({"error": {"id": "401", "name": "unauthorized", "detail": "Unauthorized"}});
+    /// let error = ApiErrorBody::parse(401, &body);
+    /// assert_eq!(error.id, "401");
+    /// assert_eq!(error.detail, "Unauthorized");
+    ///
+    /// let empty = ApiErrorBody::parse(503, &serde_json::Value::Null);
+    /// assert_eq!(empty.id, "503");
+    /// ```
+    pub fn parse(status: u16, body: &Value) -> Self {
+        let error = &body["error"];
+        let field = |name: &str| error[name].as_str().unwrap_or_default().to_string();
+        let id = field("id");
+
+        Self {
+            id: if id.is_empty() {
+                status.to_string()
+            } else {
+                id
+            },
+            name: field("name"),
+            detail: field("detail"),
+        }
+    }
+}
+
+/// Maps a failed response to the matching [`YnabError`].
+///
+/// - `401`, `403`, `409` and `429` map to `Unauthorized`, `Forbidden`,
+///   `Conflict` and `RateLimited`, carrying YNAB's error id and detail.
+/// - `404` on a budget or one of its records maps to `InvalidBudgetId`,
+///   `CategoryNotFound`, `AccountNotFound`, `PayeeNotFound` or
+///   `TransactionNotFound`; any other `404` maps to `NotFound`.
+/// - Other statuses map to `ApiError` naming the status and path.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::adapters::map_error_response;
+/// use ynab_mcp::YnabError;
+/// use serde_json::json;
+///
+/// let body = json!({"error": {"id": "404.2", "name": "resource_not_found", "detail": "Resource not found"}});
+/// let error = map_error_response(404, "/budgets/b-1/categories/c-9", &body, None);
+/// assert_eq!(error, YnabError::category_not_found("c-9"));
+/// ```
+pub fn map_error_response(
+    status: u16,
+    path: &str,
+    body: &Value,
+    retry_after: Option<Duration>,
+) -> YnabError {
+    let error = ApiErrorBody::parse(status, body);
+
+    match status {
+        401 => YnabError::unauthorized(error.id, error.detail),
+        403 => YnabError::forbidden(error.id, error.detail),
+        404 => missing_resource(path, &error)
+            .unwrap_or_else(|| YnabError::not_found(error.id, error.detail)),
+        409 => YnabError::conflict(error.id, error.detail),
+        429 if error.detail.is_empty() => {
+            YnabError::rate_limited(0, retry_after.unwrap_or_default())
+        }
+        429 => YnabError::rate_limited(0, retry_after.unwrap_or_default())
+            .with_api_detail(error.id, error.detail),
+        _ if error.detail.is_empty() => {
+            YnabError::api_error(format!("HTTP {} for {}", status, path))
+        }
+        _ => YnabError::api_error(format!("HTTP {} for {}: {}", status, path, error.detail)),
+    }
+}
+
+/// Identifies the missing record from the path of a `404` response.
+///
+/// YNAB answers `404.1` for URIs that do not exist at all, which says nothing
+/// about the ids in the path.
+fn missing_resource(path: &str, error: &ApiErrorBody) -> Option<YnabError> {
+    if error.id == "404.1" {
+        return None;
+    }
+
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["budgets", budget_id] | ["budgets", budget_id, _] => {
+            Some(YnabError::invalid_budget_id(*budget_id))
+        }
+        ["budgets", _, "categories", category_id, ..] => {
+            Some(YnabError::category_not_found(*category_id))
+        }
+        ["budgets", _, "accounts", account_id, ..] => {
+            Some(YnabError::account_not_found(*account_id))
+        }
+        ["budgets", _, "payees", payee_id, ..] => Some(YnabError::payee_not_found(*payee_id)),
+        ["budgets", _, "transactions", transaction_id, ..] => {
+            Some(YnabError::transaction_not_found(*transaction_id))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn error_body(id: &str, name: &str, detail: &str) -> Value {
+        json!({"error": {"id": id, "name": name, "detail": detail}})
+    }
+
+    #[test]
+    fn should_map_status_codes_to_typed_errors() {
+        let cases = [
+            (
+                401,
+                error_body("401", "unauthorized", "Unauthorized"),
+                YnabError::unauthorized("401", "Unauthorized"),
+            ),
+            (
+                403,
+                error_body("403.1", "subscription_lapsed", "Subscription lapsed"),
+                YnabError::forbidden("403.1", "Subscription lapsed"),
+            ),
+            (
+                409,
+                error_body("409", "conflict", "Duplicate import id"),
+                YnabError::conflict("409", "Duplicate import id"),
+            ),
+        ];
+
+        for (status, body, expected) in cases {
+            assert_eq!(
+                map_error_response(status, "/budgets", &body, None),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn should_map_throttling_with_retry_after() {
+        let body = error_body("429", "too_many_requests", "Too many requests");
+
+        let error = map_error_response(429, "/budgets", &body, Some(Duration::from_secs(90)));
+
+        assert_eq!(
+            error,
+            YnabError::RateLimited {
+                remaining: 0,
+                retry_after_secs: 90,
+                id: "429".to_string(),
+                detail: "Too many requests".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn should_identify_missing_records_from_path() {
+        let body = error_body("404.2", "resource_not_found", "Resource not found");
+        let cases = [
+            ("/budgets/b-1", YnabError::invalid_budget_id("b-1")),
+            (
+                "/budgets/b-1/transactions?last_knowledge_of_server=5",
+                YnabError::invalid_budget_id("b-1"),
+            ),
+            (
+                "/budgets/b-1/categories/c-1",
+                YnabError::category_not_found("c-1"),
+            ),
+            (
+                "/budgets/b-1/accounts/a-1",
+                YnabError::account_not_found("a-1"),
+            ),
+            ("/budgets/b-1/payees/p-1", YnabError::payee_not_found("p-1")),
+            (
+                "/budgets/b-1/transactions/t-1",
+                YnabError::transaction_not_found("t-1"),
+            ),
+            ("/user", YnabError::not_found("404.2", "Resource not found")),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(
+                map_error_response(404, path, &body, None),
+                expected,
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn should_not_blame_ids_for_unknown_uris() {
+        let body = error_body("404.1", "not_found", "The requested URI does not exist");
+
+        let error = map_error_response(404, "/budgets/b-1/widgets", &body, None);
+
+        assert_eq!(
+            error,
+            YnabError::not_found("404.1", "The requested URI does not exist")
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_status_for_unstructured_bodies() {
+        let error = map_error_response(502, "/budgets", &json!("Bad Gateway"), None);
+        let unauthorized = map_error_response(401, "/budgets", &Value::Null, None);
+
+        assert_eq!(error, YnabError::api_error("HTTP 502 for /budgets"));
+        assert_eq!(unauthorized, YnabError::unauthorized("401", ""));
+    }
+
+    #[test]
+    fn should_include_detail_in_generic_api_errors() {
+        let body = error_body("500", "internal_server_error", "Something went wrong");
+
+        let error = map_error_response(500, "/budgets", &body, None);
+
+        assert_eq!(
+            error,
+            YnabError::api_error("HTTP 500 for /budgets: Something went wrong")
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Local snapshots of YNAB endpoints kept current with delta requests.
+//!
+//! YNAB returns a `server_knowledge` value with every delta-capable response.
+//! Passing it back as `last_knowledge_of_server` returns only the records that
+//! changed since, including records marked `deleted`. A [`DeltaSnapshot`] holds
+//! the merged records so the full dataset is only downloaded once.
+
+use crate::domain::{YnabError, YnabResult};
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+
+/// Shape of a delta-capable endpoint's records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaEndpoint {
+    /// A flat list of records under the given key, such as `"transactions"`.
+    Records(&'static str),
+    /// Category groups, each nesting its categories.
+    CategoryGroups,
+}
+
+impl DeltaEndpoint {
+    /// Returns the key of the record list inside the response's `data` object.
+    pub fn data_key(&self) -> &'static str {
+        match self {
+            DeltaEndpoint::Records(key) => key,
+            DeltaEndpoint::CategoryGroups => "category_groups",
+        }
+    }
+}
+
+/// Merged records of one endpoint of one budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaSnapshot {
+    endpoint: DeltaEndpoint,
+    server_knowledge: Option<i64>,
+    records: Vec<Value>,
+}
+
+impl DeltaSnapshot {
+    /// Creates a snapshot from a full (non-delta) API response.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::{DeltaEndpoint, DeltaSnapshot};
+    /// use serde_json::json;
+    ///
+    /// let full = json!({"data": {"transactions": [{"id": "t-1", "amount": -1000}], "server_knowledge": 10}});
+    /// let mut snapshot = DeltaSnapshot::from_response(DeltaEndpoint::Records("transactions"), &full).unwrap();
+    ///
+    /// let delta = json!({"data": {"transactions": [{"id": "t-1", "deleted": true}], "server_knowledge": 11}});
+    /// snapshot.apply_delta(&delta).unwrap();
+    ///
+    /// assert_eq!(snapshot.server_knowledge(), Some(11));
+    /// assert!(snapshot.is_empty());
+    /// ```
+    pub fn from_response(endpoint: DeltaEndpoint, json: &Value) -> YnabResult<Self> {
+        let mut snapshot = Self {
+            endpoint,
+            server_knowledge: None,
+            records: Vec::new(),
+        };
+        snapshot.apply_delta(json)?;
+        Ok(snapshot)
+    }
+
+    /// Returns the server knowledge to send with the next delta request.
+    pub fn server_knowledge(&self) -> Option<i64> {
+        self.server_knowledge
+    }
+
+    /// Returns the number of top-level records in the snapshot.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether the snapshot holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Merges a delta response: changed records replace their previous version,
+    /// new records are appended and records marked `deleted` are removed.
+    pub fn apply_delta(&mut self, json: &Value) -> YnabResult<()> {
+        let key = self.endpoint.data_key();
+        let changes = json["data"][key]
+            .as_array()
+            .ok_or_else(|| YnabError::ApiError(format!("Invalid {} response format", key)))?;
+
+        match self.endpoint {
+            DeltaEndpoint::Records(_) => merge_records(&mut self.records, changes),
+            DeltaEndpoint::CategoryGroups => merge_category_groups(&mut self.records, changes),
+        }
+
+        if let Some(knowledge) = json["data"]["server_knowledge"].as_i64() {
+            self.server_knowledge = Some(
+                self.server_knowledge
+                    .map_or(knowledge, |current| current.max(knowledge)),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Renders the snapshot in the shape of a full API response.
+    pub fn to_response(&self) -> Value {
+        let mut data = serde_json::Map::new();
+        data.insert(
+            self.endpoint.data_key().to_string(),
+            Value::Array(self.records.clone()),
+        );
+        if let Some(knowledge) = self.server_knowledge {
+            data.insert("server_knowledge".to_string(), json!(knowledge));
+        }
+
+        json!({ "data": data })
+    }
+}
+
+/// Returns whether a record is marked as deleted.
+fn is_deleted(record: &Value) -> bool {
+    record["deleted"].as_bool().unwrap_or(false)
+}
+
+/// Merges changed records into `records` by `id`, preserving existing order.
+fn merge_records(records: &mut Vec<Value>, changes: &[Value]) {
+    let mut positions: HashMap<String, usize> = records
+        .iter()
+        .enumerate()
+        .filter_map(|(index, record)| Some((record["id"].as_str()?.to_string(), index)))
+        .collect();
+    let mut deleted = HashSet::new();
+
+    for change in changes {
+        let Some(id) = change["id"].as_str() else {
+            continue;
+        };
+
+        if is_deleted(change) {
+            deleted.insert(id.to_string());
+            continue;
+        }
+
+        deleted.remove(id);
+        match positions.get(id) {
+            Some(&index) => records[index] = change.clone(),
+            None => {
+                positions.insert(id.to_string(), records.len());
+                records.push(change.clone());
+            }
+        }
+    }
+
+    records.retain(|record| record["id"].as_str().is_none_or(|id| !deleted.contains(id)));
+}
+
+/// Merges changed category groups, moving categories that changed group.
+fn merge_category_groups(groups: &mut Vec<Value>, changes: &[Value]) {
+    // A category listed under a new group must leave its old one
+    let new_group_of: HashMap<&str, &str> = changes
+        .iter()
+        .filter_map(|group| Some((group["id"].as_str()?, group["categories"].as_array()?)))
+        .flat_map(|(group_id, categories)| {
+            categories
+                .iter()
+                .filter(|category| !is_deleted(category))
+                .filter_map(move |category| Some((category["id"].as_str()?, group_id)))
+        })
+        .collect();
+    for group in groups.iter_mut() {
+        let group_id = group["id"].as_str().unwrap_or_default().to_string();
+        if let Some(categories) = group["categories"].as_array_mut() {
+            categories.retain(|category| {
+                category["id"]
+                    .as_str()
+                    .and_then(|id| new_group_of.get(id))
+                    .is_none_or(|new_group| *new_group == group_id)
+            });
+        }
+    }
+
+    for change in changes {
+        let Some(group_id) = change["id"].as_str() else {
+            continue;
+        };
+        let changed_categories = change["categories"].as_array().cloned().unwrap_or_default();
+
+        if is_deleted(change) {
+            groups.retain(|group| group["id"].as_str() != Some(group_id));
+            continue;
+        }
+
+        let mut merged = change.clone();
+        let existing = groups
+            .iter()
+            .position(|group| group["id"].as_str() == Some(group_id));
+        let mut categories = existing
+            .and_then(|index| groups[index]["categories"].as_array().cloned())
+            .unwrap_or_default();
+        merge_records(&mut categories, &changed_categories);
+        merged["categories"] = Value::Array(categories);
+
+        match existing {
+            Some(index) => groups[index] = merged,
+            None => groups.push(merged),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transactions_response(transactions: Value, knowledge: i64) -> Value {
+        json!({"data": {"transactions": transactions, "server_knowledge": knowledge}})
+    }
+
+    #[test]
+    fn should_update_add_and_delete_records() {
+        let full = transactions_response(
+            json!([
+                {"id": "t-1", "amount": -1000},
+                {"id": "t-2", "amount": -2000},
+                {"id": "t-3", "amount": -3000}
+            ]),
+            100,
+        );
+        let mut snapshot =
+            DeltaSnapshot::from_response(DeltaEndpoint::Records("transactions"), &full).unwrap();
+
+        let delta = transactions_response(
+            json!([
+                {"id": "t-2", "amount": -2500},
+                {"id": "t-3", "amount": -3000, "deleted": true},
+                {"id": "t-4", "amount": -4000}
+            ]),
+            105,
+        );
+        snapshot.apply_delta(&delta).unwrap();
+
+        let response = snapshot.to_response();
+        let amounts: Vec<i64> = response["data"]["transactions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["amount"].as_i64().unwrap())
+            .collect();
+        assert_eq!(amounts, vec![-1000, -2500, -4000]);
+        assert_eq!(snapshot.server_knowledge(), Some(105));
+        assert_eq!(response["data"]["server_knowledge"], 105);
+    }
+
+    #[test]
+    fn should_drop_deleted_records_from_initial_response() {
+        let full = transactions_response(
+            json!([{"id": "t-1", "deleted": true}, {"id": "t-2", "deleted": false}]),
+            1,
+        );
+
+        let snapshot =
+            DeltaSnapshot::from_response(DeltaEndpoint::Records("transactions"), &full).unwrap();
+
+        assert_eq!(snapshot.len(), 1);
+    }
+
+    #[test]
+    fn should_never_move_server_knowledge_backwards() {
+        let full = transactions_response(json!([]), 50);
+        let mut snapshot =
+            DeltaSnapshot::from_response(DeltaEndpoint::Records("transactions"), &full).unwrap();
+
+        snapshot
+            .apply_delta(&transactions_response(json!([]), 40))
+            .unwrap();
+
+        assert_eq!(snapshot.server_knowledge(), Some(50));
+    }
+
+    #[test]
+    fn should_reject_response_without_records() {
+        let result = DeltaSnapshot::from_response(
+            DeltaEndpoint::Records("transactions"),
+            &json!({"data": {}}),
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::ApiError("Invalid transactions response format".to_string())
+        );
+    }
+
+    #[test]
+    fn should_merge_categories_within_groups() {
+        let full = json!({"data": {"server_knowledge": 7, "category_groups": [
+            {"id": "g-1", "name": "Bills", "categories": [
+                {"id": "c-1", "name": "Rent", "category_group_id": "g-1"},
+                {"id": "c-2", "name": "Power", "category_group_id": "g-1"}
+            ]},
+            {"id": "g-2", "name": "Fun", "categories": [
+                {"id": "c-3", "name": "Games", "category_group_id": "g-2"}
+            ]}
+        ]}});
+        let mut snapshot =
+            DeltaSnapshot::from_response(DeltaEndpoint::CategoryGroups, &full).unwrap();
+
+        // Power moves to Fun, Games is deleted and Bills is renamed
+        let delta = json!({"data": {"server_knowledge": 9, "category_groups": [
+            {"id": "g-1", "name": "Fixed Bills", "categories": []},
+            {"id": "g-2", "name": "Fun", "categories": [
+                {"id": "c-2", "name": "Power", "category_group_id": "g-2"},
+                {"id": "c-3", "name": "Games", "category_group_id": "g-2", "deleted": true}
+            ]}
+        ]}});
+        snapshot.apply_delta(&delta).unwrap();
+
+        let groups = snapshot.to_response()["data"]["category_groups"].clone();
+        assert_eq!(groups[0]["name"], "Fixed Bills");
+        assert_eq!(
+            groups[0]["categories"],
+            json!([{"id": "c-1", "name": "Rent", "category_group_id": "g-1"}])
+        );
+        assert_eq!(groups[1]["categories"].as_array().unwrap().len(), 1);
+        assert_eq!(groups[1]["categories"][0]["id"], "c-2");
+    }
+
+    #[test]
+    fn should_remove_deleted_category_groups() {
+        let full = json!({"data": {"server_knowledge": 1, "category_groups": [
+            {"id": "g-1", "name": "Old", "categories": [{"id": "c-1"}]}
+        ]}});
+        let mut snapshot =
+            DeltaSnapshot::from_response(DeltaEndpoint::CategoryGroups, &full).unwrap();
+
+        snapshot
+            .apply_delta(&json!({"data": {"server_knowledge": 2, "category_groups": [
+                {"id": "g-1", "deleted": true, "categories": []}
+            ]}}))
+            .unwrap();
+
+        assert!(snapshot.is_empty());
+    }
+}
@@ -3,10 +3,16 @@
 //! This module contains adapters for external services and APIs,
 //! including the YNAB API client and caching mechanisms.
 
+pub mod api_error;
 pub mod cache;
+pub mod delta_sync;
+pub mod rate_limiter;
 pub mod response_mapper;
 pub mod ynab_client;
 
+pub use api_error::*;
 pub use cache::*;
+pub use delta_sync::*;
+pub use rate_limiter::*;
 pub use response_mapper::*;
 pub use ynab_client::*;
@@ -0,0 +1,242 @@
+//! Client-side rate limiting and retry policy for the YNAB API.
+//!
+//! YNAB allows 200 requests per hour per access token. A [`RateLimiter`] tracks
+//! that quota locally as a token bucket so requests can be refused before the
+//! API starts answering `429 Too Many Requests`, and a [`RetryPolicy`] decides
+//! how long to back off when a request is throttled or fails with a 5xx status.
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Requests allowed per hour for a YNAB personal access token.
+pub const YNAB_REQUESTS_PER_HOUR: u32 = 200;
+
+/// Token bucket that refills continuously over its period.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::adapters::RateLimiter;
+/// use std::time::Duration;
+///
+/// let limiter = RateLimiter::new(2, Duration::from_secs(3600));
+/// assert!(limiter.try_acquire().is_ok());
+/// assert!(limiter.try_acquire().is_ok());
+/// assert!(limiter.try_acquire().is_err());
+/// assert_eq!(limiter.remaining(), 0);
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: u32,
+    period: Duration,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a full bucket allowing `capacity` requests per `period`.
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        Self {
+            capacity,
+            period,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(capacity),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Creates a limiter matching YNAB's quota of 200 requests per hour.
+    pub fn ynab_default() -> Self {
+        Self::new(YNAB_REQUESTS_PER_HOUR, Duration::from_secs(3600))
+    }
+
+    /// Takes a token for one request, or returns how long until one is available.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut state = self.refilled_state();
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            return Ok(());
+        }
+
+        let missing = 1.0 - state.tokens;
+        Err(self.time_per_token().mul_f64(missing))
+    }
+
+    /// Returns the number of whole requests currently available.
+    pub fn remaining(&self) -> u32 {
+        self.refilled_state().tokens.floor() as u32
+    }
+
+    /// Returns how long until the next request can be made.
+    pub fn time_until_available(&self) -> Duration {
+        let state = self.refilled_state();
+        if state.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            self.time_per_token().mul_f64(1.0 - state.tokens)
+        }
+    }
+
+    /// Lowers the available tokens to the quota the API reports as remaining.
+    pub fn sync_remaining(&self, remaining: u32) {
+        let mut state = self.refilled_state();
+        state.tokens = state.tokens.min(f64::from(remaining));
+    }
+
+    /// Empties the bucket, typically after the API answered `429`.
+    pub fn exhaust(&self) {
+        self.refilled_state().tokens = 0.0;
+    }
+
+    fn time_per_token(&self) -> Duration {
+        self.period / self.capacity.max(1)
+    }
+
+    /// Locks the bucket after adding the tokens earned since the last refill.
+    fn refilled_state(&self) -> std::sync::MutexGuard<'_, BucketState> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = Instant::now();
+        let earned = now.duration_since(state.last_refill).as_secs_f64()
+            / self.period.as_secs_f64()
+            * f64::from(self.capacity);
+        state.tokens = (state.tokens + earned).min(f64::from(self.capacity));
+        state.last_refill = now;
+        state
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::ynab_default()
+    }
+}
+
+/// Retry behaviour for throttled (`429`) and server error (`5xx`) responses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each later retry.
+    pub base_delay: Duration,
+    /// Longest delay the client will wait; longer waits are reported as errors.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy.
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn no_retries() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Returns the exponential backoff delay for a retry, with random jitter.
+    ///
+    /// The delay for retry `attempt` (starting at 0) is `base_delay * 2^attempt`,
+    /// capped at `max_delay`, then scaled by a random factor between 0.5 and 1.0
+    /// so concurrent clients do not retry in lockstep.
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        exponential.mul_f64(0.5 + jitter_fraction(attempt) / 2.0)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(500), Duration::from_secs(30))
+    }
+}
+
+/// Returns a pseudo-random fraction in `[0, 1)`.
+fn jitter_fraction(attempt: u32) -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos())
+        .unwrap_or_default();
+    let hash = RandomState::new().hash_one((attempt, nanos));
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_refuse_requests_once_bucket_is_empty() {
+        let limiter = RateLimiter::new(200, Duration::from_secs(3600));
+
+        for _ in 0..200 {
+            assert!(limiter.try_acquire().is_ok());
+        }
+        let wait = limiter.try_acquire().unwrap_err();
+
+        // One token is earned every 18 seconds
+        assert!(wait <= Duration::from_secs(18));
+        assert!(wait > Duration::from_secs(17));
+    }
+
+    #[test]
+    fn should_refill_over_time() {
+        let limiter = RateLimiter::new(10, Duration::from_millis(100));
+        limiter.exhaust();
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert!(limiter.remaining() >= 2);
+        assert!(limiter.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn should_sync_remaining_quota_downwards_only() {
+        let limiter = RateLimiter::ynab_default();
+
+        limiter.sync_remaining(12);
+        assert_eq!(limiter.remaining(), 12);
+
+        limiter.sync_remaining(150);
+        assert_eq!(limiter.remaining(), 12);
+    }
+
+    #[test]
+    fn should_report_time_until_available() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+
+        assert_eq!(limiter.time_until_available(), Duration::ZERO);
+        limiter.exhaust();
+        assert!(limiter.time_until_available() > Duration::from_secs(59));
+    }
+
+    #[test]
+    fn should_back_off_exponentially_with_jitter() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_millis(1000));
+
+        for attempt in 0..6 {
+            let expected =
+                Duration::from_millis(100 * 2u64.pow(attempt)).min(Duration::from_millis(1000));
+            let delay = policy.backoff_delay(attempt);
+
+            assert!(delay >= expected / 2, "attempt {}: {:?}", attempt, delay);
+            assert!(delay <= expected, "attempt {}: {:?}", attempt, delay);
+        }
+    }
+}
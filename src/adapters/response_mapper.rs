@@ -1,6 +1,10 @@
 //! Response mapper for converting YNAB API JSON responses to domain entities.
 
-use crate::domain::{Budget, Category, Money, Transaction, YnabError, YnabResult};
+use crate::domain::{
+    Account, AccountType, Budget, BudgetMonth, CalendarDate, Category, CategoryGoal, ClearedStatus,
+    Frequency, GoalType, Money, Payee, ScheduledTransaction, Transaction, TransactionBuilder,
+    YnabError, YnabResult,
+};
 use serde_json::Value;
 
 /// Maps YNAB API responses to domain entities.
@@ -34,6 +38,138 @@ impl ResponseMapper {
         Ok(Budget::new(id, name))
     }
 
+    /// Maps a YNAB budgets API response to a vector of Budget domain entities.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB budgets API
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ResponseMapper;
+    /// use serde_json::json;
+    ///
+    /// let mapper = ResponseMapper::new();
+    /// let response = json!({"data": {"budgets": [{"id": "budget-1", "name": "Household"}]}});
+    /// let budgets = mapper.map_budgets_from_response(&response).unwrap();
+    /// assert_eq!(budgets[0].name(), "Household");
+    /// ```
+    pub fn map_budgets_from_response(&self, json: &Value) -> YnabResult<Vec<Budget>> {
+        let budgets_array = json["data"]["budgets"]
+            .as_array()
+            .ok_or_else(|| YnabError::ApiError("Invalid budgets response format".to_string()))?;
+
+        budgets_array
+            .iter()
+            .map(|budget_json| self.map_budget(budget_json))
+            .collect()
+    }
+
+    /// Maps a YNAB account JSON response to an Account domain entity.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB API
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ResponseMapper;
+    /// use ynab_mcp::AccountType;
+    /// use serde_json::json;
+    ///
+    /// let mapper = ResponseMapper::new();
+    /// let json = json!({"id": "acc-1", "name": "Visa", "type": "creditCard", "on_budget": true});
+    /// let account = mapper.map_account(&json).unwrap();
+    /// assert_eq!(account.account_type(), &AccountType::CreditCard);
+    /// ```
+    pub fn map_account(&self, json: &Value) -> YnabResult<Account> {
+        let id = json["id"].as_str().unwrap_or("").to_string();
+        let name = json["name"].as_str().unwrap_or("").to_string();
+        let type_name = json["type"].as_str().unwrap_or("");
+        // Keep accounts of types added to YNAB after this server, so totals stay complete
+        let account_type = AccountType::from_api_name(type_name)
+            .unwrap_or_else(|| AccountType::Unknown(type_name.to_string()));
+        let on_budget = json["on_budget"].as_bool().unwrap_or(false);
+        let milliunits = |field: &str| Money::from_milliunits(json[field].as_i64().unwrap_or(0));
+
+        let mut account = Account::new(id, name, account_type, on_budget)
+            .with_balances(
+                milliunits("balance"),
+                milliunits("cleared_balance"),
+                milliunits("uncleared_balance"),
+            )
+            .with_closed(json["closed"].as_bool().unwrap_or(false));
+        if let Some(note) = json["note"].as_str() {
+            account = account.with_note(note.to_string());
+        }
+        if let Some(last_reconciled_at) = json["last_reconciled_at"].as_str() {
+            account = account.with_last_reconciled_at(last_reconciled_at.to_string());
+        }
+
+        Ok(account)
+    }
+
+    /// Maps a YNAB accounts API response to a vector of Account domain entities.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB accounts API
+    pub fn map_accounts_from_response(&self, json: &Value) -> YnabResult<Vec<Account>> {
+        let accounts_array = json["data"]["accounts"]
+            .as_array()
+            .ok_or_else(|| YnabError::ApiError("Invalid accounts response format".to_string()))?;
+
+        accounts_array
+            .iter()
+            .map(|account_json| self.map_account(account_json))
+            .collect()
+    }
+
+    /// Maps a YNAB payee JSON response to a Payee domain entity.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB API
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ResponseMapper;
+    /// use serde_json::json;
+    ///
+    /// let mapper = ResponseMapper::new();
+    /// let json = json!({
+    ///     "id": "payee-1",
+    ///     "name": "Transfer : Savings",
+    ///     "transfer_account_id": "acc-2",
+    ///     "deleted": false
+    /// });
+    /// let payee = mapper.map_payee(&json).unwrap();
+    /// assert_eq!(payee.transfer_account_id(), Some("acc-2"));
+    /// ```
+    pub fn map_payee(&self, json: &Value) -> YnabResult<Payee> {
+        let id = json["id"].as_str().unwrap_or("").to_string();
+        let name = json["name"].as_str().unwrap_or("").to_string();
+
+        let mut payee =
+            Payee::new(id, name).with_deleted(json["deleted"].as_bool().unwrap_or(false));
+        if let Some(transfer_account_id) = json["transfer_account_id"].as_str() {
+            payee = payee.with_transfer_account_id(transfer_account_id.to_string());
+        }
+
+        Ok(payee)
+    }
+
+    /// Maps a YNAB payees API response to a vector of Payee domain entities.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB payees API
+    pub fn map_payees_from_response(&self, json: &Value) -> YnabResult<Vec<Payee>> {
+        let payees_array = json["data"]["payees"]
+            .as_array()
+            .ok_or_else(|| YnabError::ApiError("Invalid payees response format".to_string()))?;
+
+        payees_array
+            .iter()
+            .map(|payee_json| self.map_payee(payee_json))
+            .collect()
+    }
+
     /// Maps a YNAB category JSON response to a Category domain entity.
     ///
     /// # Arguments
@@ -54,14 +190,131 @@ impl ResponseMapper {
         let name = json["name"].as_str().unwrap_or("").to_string();
         let group_id = json["category_group_id"].as_str().map(|s| s.to_string());
 
-        Ok(match group_id {
+        let milliunits = |field: &str| Money::from_milliunits(json[field].as_i64().unwrap_or(0));
+
+        let category = match group_id {
             Some(gid) => Category::new_with_group(id, name, gid),
             None => Category::new(id, name),
+        }
+        .with_month_amounts(
+            milliunits("budgeted"),
+            milliunits("activity"),
+            milliunits("balance"),
+        );
+
+        // Goal types added by YNAB after this mapper was written are skipped
+        let goal_type = json["goal_type"].as_str().and_then(GoalType::from_api_name);
+        Ok(match goal_type {
+            Some(goal_type) => category.with_goal(CategoryGoal {
+                goal_type,
+                target: milliunits("goal_target"),
+                target_month: json["goal_target_month"]
+                    .as_str()
+                    .map(CalendarDate::parse)
+                    .transpose()?,
+                percentage_complete: json["goal_percentage_complete"].as_i64(),
+                under_funded: json["goal_under_funded"]
+                    .as_i64()
+                    .map(Money::from_milliunits),
+            }),
+            None => category,
         })
     }
 
+    /// Maps a YNAB month JSON object to a BudgetMonth domain entity.
+    ///
+    /// Month summaries from the months list have no `categories`; month details
+    /// do, and deleted categories among them are skipped.
+    ///
+    /// # Arguments
+    /// * `json` - A month object from the YNAB API
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ResponseMapper;
+    /// use ynab_mcp::Money;
+    /// use serde_json::json;
+    ///
+    /// let mapper = ResponseMapper::new();
+    /// let json = json!({
+    ///     "month": "2024-03-01",
+    ///     "income": 5000000,
+    ///     "budgeted": 4500000,
+    ///     "activity": -4200000,
+    ///     "to_be_budgeted": 500000,
+    ///     "categories": [
+    ///         {"id": "cat-1", "name": "Dining Out", "budgeted": 100000, "activity": -130000, "balance": -30000}
+    ///     ]
+    /// });
+    /// let month = mapper.map_budget_month(&json).unwrap();
+    /// assert_eq!(month.to_be_budgeted(), Money::from_milliunits(500000));
+    /// assert!(month.categories()[0].is_overspent());
+    /// ```
+    pub fn map_budget_month(&self, json: &Value) -> YnabResult<BudgetMonth> {
+        let month = json["month"]
+            .as_str()
+            .ok_or_else(|| YnabError::ApiError("Budget month is missing its month".to_string()))?;
+        let milliunits = |field: &str| Money::from_milliunits(json[field].as_i64().unwrap_or(0));
+
+        let mut budget_month = BudgetMonth::new(month.to_string()).with_totals(
+            milliunits("income"),
+            milliunits("budgeted"),
+            milliunits("activity"),
+            milliunits("to_be_budgeted"),
+        );
+        if let Some(note) = json["note"].as_str() {
+            budget_month = budget_month.with_note(note.to_string());
+        }
+        if let Some(age_of_money) = json["age_of_money"].as_i64() {
+            budget_month = budget_month.with_age_of_money(age_of_money);
+        }
+        if let Some(categories_array) = json["categories"].as_array() {
+            let categories = categories_array
+                .iter()
+                .filter(|category_json| !category_json["deleted"].as_bool().unwrap_or(false))
+                .map(|category_json| self.map_category(category_json))
+                .collect::<YnabResult<Vec<Category>>>()?;
+            budget_month = budget_month.with_categories(categories);
+        }
+
+        Ok(budget_month)
+    }
+
+    /// Maps a YNAB months list response to BudgetMonth summaries without categories.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB months API
+    pub fn map_budget_months_from_response(&self, json: &Value) -> YnabResult<Vec<BudgetMonth>> {
+        let months_array = json["data"]["months"]
+            .as_array()
+            .ok_or_else(|| YnabError::ApiError("Invalid months response format".to_string()))?;
+
+        months_array
+            .iter()
+            .map(|month_json| self.map_budget_month(month_json))
+            .collect()
+    }
+
+    /// Maps a YNAB single month response to a BudgetMonth with its categories.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB month detail API
+    pub fn map_budget_month_from_response(&self, json: &Value) -> YnabResult<BudgetMonth> {
+        if !json["data"]["month"].is_object() {
+            return Err(YnabError::ApiError(
+                "Invalid month response format".to_string(),
+            ));
+        }
+        self.map_budget_month(&json["data"]["month"])
+    }
+
     /// Maps a YNAB transaction JSON response to a Transaction domain entity.
     ///
+    /// Unknown `cleared` values map to [`ClearedStatus::Uncleared`]. The
+    /// `subtransactions` of a split transaction become its lines, which take
+    /// the parent's account, date and status, and its payee and memo when they
+    /// have none of their own.
+    ///
     /// # Arguments
     /// * `json` - The JSON response from the YNAB API
     ///
@@ -82,6 +335,45 @@ impl ResponseMapper {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn map_transaction(&self, json: &Value) -> YnabResult<Transaction> {
+        let mut subtransactions = Vec::new();
+        for subtransaction_json in json["subtransactions"].as_array().into_iter().flatten() {
+            subtransactions.push(self.map_subtransaction(subtransaction_json, json)?);
+        }
+
+        Ok(self
+            .transaction_builder(json)?
+            .subtransactions(subtransactions)
+            .build())
+    }
+
+    /// Maps one line of a split transaction, filling the fields YNAB only
+    /// records on the parent.
+    fn map_subtransaction(&self, json: &Value, parent: &Value) -> YnabResult<Transaction> {
+        let mut line = parent.clone();
+        if let Some(fields) = line.as_object_mut() {
+            for field in ["subtransactions", "import_id", "matched_transaction_id"] {
+                fields.remove(field);
+            }
+            for (field, value) in json.as_object().into_iter().flatten() {
+                let inherited = matches!(field.as_str(), "memo" | "payee_id" | "payee_name");
+                if field != "transaction_id" && !(inherited && value.is_null()) {
+                    fields.insert(field.clone(), value.clone());
+                }
+            }
+            let deleted = parent["deleted"].as_bool().unwrap_or(false)
+                || json["deleted"].as_bool().unwrap_or(false);
+            fields.insert("deleted".to_string(), Value::Bool(deleted));
+        }
+
+        let parent_id = parent["id"].as_str().unwrap_or("").to_string();
+        Ok(self
+            .transaction_builder(&line)?
+            .parent_id(parent_id)
+            .build())
+    }
+
+    /// Reads the fields shared by transactions and their split lines into a builder.
+    fn transaction_builder(&self, json: &Value) -> YnabResult<TransactionBuilder> {
         let id = json["id"].as_str().unwrap_or("").to_string();
         let account_id = json["account_id"].as_str().unwrap_or("").to_string();
         let category_id = json["category_id"].as_str().unwrap_or("").to_string();
@@ -89,14 +381,22 @@ impl ResponseMapper {
         let amount_milliunits = json["amount"].as_i64().unwrap_or(0);
         let amount = Money::from_milliunits(amount_milliunits);
 
-        let date = json["date"].as_str().map(|s| s.to_string());
+        let date = json["date"].as_str().map(CalendarDate::parse).transpose()?;
         let description = json["memo"].as_str().map(|s| s.to_string());
 
         let mut builder = Transaction::builder()
             .id(id)
             .account_id(account_id)
             .category_id(category_id)
-            .amount(amount);
+            .amount(amount)
+            .cleared(
+                json["cleared"]
+                    .as_str()
+                    .and_then(ClearedStatus::from_api_name)
+                    .unwrap_or_default(),
+            )
+            .approved(json["approved"].as_bool().unwrap_or(false))
+            .deleted(json["deleted"].as_bool().unwrap_or(false));
 
         if let Some(pid) = payee_id {
             builder = builder.payee_id(pid);
@@ -110,7 +410,30 @@ impl ResponseMapper {
             builder = builder.description(desc);
         }
 
-        Ok(builder.build())
+        let text = |field: &str| json[field].as_str().map(|s| s.to_string());
+        if let Some(flag_color) = text("flag_color") {
+            builder = builder.flag_color(flag_color);
+        }
+        if let Some(account_name) = text("account_name") {
+            builder = builder.account_name(account_name);
+        }
+        if let Some(payee_name) = text("payee_name") {
+            builder = builder.payee_name(payee_name);
+        }
+        if let Some(category_name) = text("category_name") {
+            builder = builder.category_name(category_name);
+        }
+        if let Some(transfer_account_id) = text("transfer_account_id") {
+            builder = builder.transfer_account_id(transfer_account_id);
+        }
+        if let Some(import_id) = text("import_id") {
+            builder = builder.import_id(import_id);
+        }
+        if let Some(matched_transaction_id) = text("matched_transaction_id") {
+            builder = builder.matched_transaction_id(matched_transaction_id);
+        }
+
+        Ok(builder)
     }
 
     /// Maps a YNAB transactions API response to a vector of Transaction domain entities.
@@ -153,6 +476,137 @@ impl ResponseMapper {
 
         Ok(transactions)
     }
+
+    /// Maps a YNAB scheduled transaction JSON response to a ScheduledTransaction domain entity.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB API
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ResponseMapper;
+    /// use ynab_mcp::Frequency;
+    /// use serde_json::json;
+    ///
+    /// let mapper = ResponseMapper::new();
+    /// let json = json!({
+    ///     "id": "st-1",
+    ///     "account_id": "acc-1",
+    ///     "frequency": "monthly",
+    ///     "date_first": "2024-01-01",
+    ///     "date_next": "2024-03-01",
+    ///     "amount": -1500000,
+    ///     "payee_id": "payee-1",
+    ///     "payee_name": "Landlord"
+    /// });
+    /// let scheduled = mapper.map_scheduled_transaction(&json).unwrap();
+    /// assert_eq!(scheduled.frequency(), Frequency::Monthly);
+    /// assert_eq!(scheduled.payee_name(), Some("Landlord"));
+    /// ```
+    pub fn map_scheduled_transaction(&self, json: &Value) -> YnabResult<ScheduledTransaction> {
+        let text = |field: &str| json[field].as_str().map(str::to_string);
+        let frequency_name = json["frequency"].as_str().unwrap_or("");
+        let frequency = Frequency::from_api_name(frequency_name)
+            .ok_or_else(|| YnabError::ApiError(format!("Unknown frequency: {}", frequency_name)))?;
+
+        let date_next = CalendarDate::parse(json["date_next"].as_str().unwrap_or(""))?;
+        let date_first = match json["date_first"].as_str() {
+            Some(date_first) => CalendarDate::parse(date_first)?,
+            None => date_next,
+        };
+
+        let mut scheduled = ScheduledTransaction::new(
+            text("id").unwrap_or_default(),
+            text("account_id").unwrap_or_default(),
+            frequency,
+            date_first,
+            date_next,
+            Money::from_milliunits(json["amount"].as_i64().unwrap_or(0)),
+        )
+        .with_deleted(json["deleted"].as_bool().unwrap_or(false));
+        if let Some(payee_id) = text("payee_id") {
+            scheduled = scheduled.with_payee(payee_id, text("payee_name"));
+        }
+        if let Some(category_id) = text("category_id") {
+            scheduled = scheduled.with_category(category_id, text("category_name"));
+        }
+        if let Some(transfer_account_id) = text("transfer_account_id") {
+            scheduled = scheduled.with_transfer_account_id(transfer_account_id);
+        }
+        if let Some(memo) = text("memo") {
+            scheduled = scheduled.with_memo(memo);
+        }
+
+        Ok(scheduled)
+    }
+
+    /// Maps a YNAB scheduled transactions API response to ScheduledTransaction domain entities.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB scheduled transactions API
+    pub fn map_scheduled_transactions_from_response(
+        &self,
+        json: &Value,
+    ) -> YnabResult<Vec<ScheduledTransaction>> {
+        let scheduled_array = json["data"]["scheduled_transactions"]
+            .as_array()
+            .ok_or_else(|| {
+                YnabError::ApiError("Invalid scheduled transactions response format".to_string())
+            })?;
+
+        scheduled_array
+            .iter()
+            .map(|scheduled_json| self.map_scheduled_transaction(scheduled_json))
+            .collect()
+    }
+
+    /// Maps a YNAB categories API response to a flat vector of Category domain entities.
+    ///
+    /// The YNAB API nests categories inside their category groups, so this
+    /// flattens every group's categories into a single list.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON response from the YNAB categories API
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::adapters::ResponseMapper;
+    /// use serde_json::json;
+    ///
+    /// let mapper = ResponseMapper::new();
+    /// let response = json!({
+    ///     "data": {
+    ///         "category_groups": [
+    ///             {
+    ///                 "id": "group-1",
+    ///                 "name": "Everyday Expenses",
+    ///                 "categories": [
+    ///                     {"id": "cat-1", "name": "Groceries", "category_group_id": "group-1"}
+    ///                 ]
+    ///             }
+    ///         ]
+    ///     }
+    /// });
+    /// let categories = mapper.map_categories_from_response(&response).unwrap();
+    /// assert_eq!(categories.len(), 1);
+    /// assert_eq!(categories[0].name(), "Groceries");
+    /// ```
+    pub fn map_categories_from_response(&self, json: &Value) -> YnabResult<Vec<Category>> {
+        let groups_array = json["data"]["category_groups"]
+            .as_array()
+            .ok_or_else(|| YnabError::ApiError("Invalid categories response format".to_string()))?;
+
+        let mut categories = Vec::new();
+        for group_json in groups_array {
+            if let Some(categories_array) = group_json["categories"].as_array() {
+                for category_json in categories_array {
+                    categories.push(self.map_category(category_json)?);
+                }
+            }
+        }
+
+        Ok(categories)
+    }
 }
 
 impl Default for ResponseMapper {
@@ -238,10 +692,147 @@ mod tests {
         assert_eq!(transaction.category_id(), "category-456");
         assert_eq!(transaction.payee_id(), Some("payee-789"));
         assert_eq!(transaction.amount(), Money::from_milliunits(-50000));
-        assert_eq!(transaction.date(), Some("2024-01-15"));
+        assert_eq!(transaction.date(), "2024-01-15".parse().ok());
         assert_eq!(transaction.description(), Some("Grocery shopping"));
     }
 
+    #[test]
+    fn should_map_every_ynab_transaction_field() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "id": "trans-789",
+            "date": "2024-01-15",
+            "amount": -50000,
+            "memo": null,
+            "cleared": "reconciled",
+            "approved": true,
+            "flag_color": "purple",
+            "account_id": "account-123",
+            "account_name": "Checking",
+            "payee_id": "payee-789",
+            "payee_name": "Transfer : Savings",
+            "category_id": null,
+            "category_name": "Uncategorized",
+            "transfer_account_id": "account-456",
+            "transfer_transaction_id": "trans-790",
+            "matched_transaction_id": "trans-imported",
+            "import_id": "YNAB:-50000:2024-01-15:1",
+            "deleted": true
+        });
+
+        let transaction = mapper.map_transaction(&json).unwrap();
+
+        assert_eq!(transaction.cleared(), ClearedStatus::Reconciled);
+        assert!(transaction.is_approved());
+        assert_eq!(transaction.flag_color(), Some("purple"));
+        assert_eq!(transaction.account_name(), Some("Checking"));
+        assert_eq!(transaction.payee_name(), Some("Transfer : Savings"));
+        assert_eq!(transaction.category_id(), "");
+        assert_eq!(transaction.category_name(), Some("Uncategorized"));
+        assert_eq!(transaction.transfer_account_id(), Some("account-456"));
+        assert_eq!(transaction.matched_transaction_id(), Some("trans-imported"));
+        assert_eq!(transaction.import_id(), Some("YNAB:-50000:2024-01-15:1"));
+        assert_eq!(transaction.description(), None);
+        assert!(transaction.is_deleted());
+    }
+
+    #[test]
+    fn should_map_split_transaction_lines_with_inherited_fields() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "id": "trans-split",
+            "date": "2024-03-02",
+            "amount": -90000,
+            "memo": "Costco run",
+            "cleared": "cleared",
+            "approved": true,
+            "account_id": "account-123",
+            "account_name": "Checking",
+            "payee_id": "payee-costco",
+            "payee_name": "Costco",
+            "category_id": "category-split",
+            "category_name": "Split",
+            "import_id": "YNAB:-90000:2024-03-02:1",
+            "deleted": false,
+            "subtransactions": [
+                {
+                    "id": "sub-1",
+                    "transaction_id": "trans-split",
+                    "amount": -60000,
+                    "memo": null,
+                    "payee_id": null,
+                    "payee_name": null,
+                    "category_id": "category-groceries",
+                    "category_name": "Groceries",
+                    "transfer_account_id": null,
+                    "deleted": false
+                },
+                {
+                    "id": "sub-2",
+                    "transaction_id": "trans-split",
+                    "amount": -30000,
+                    "memo": "Vacuum",
+                    "payee_id": "payee-gift",
+                    "payee_name": "Gift",
+                    "category_id": "category-household",
+                    "category_name": "Household",
+                    "transfer_account_id": null,
+                    "deleted": true
+                }
+            ]
+        });
+
+        let transaction = mapper.map_transaction(&json).unwrap();
+
+        assert!(transaction.is_split());
+        assert_eq!(transaction.category_name(), Some("Split"));
+        let groceries = &transaction.subtransactions()[0];
+        assert_eq!(groceries.id(), "sub-1");
+        assert_eq!(groceries.parent_id(), Some("trans-split"));
+        assert_eq!(groceries.amount(), Money::from_milliunits(-60000));
+        assert_eq!(groceries.category_id(), "category-groceries");
+        assert_eq!(groceries.category_name(), Some("Groceries"));
+        assert_eq!(groceries.account_id(), "account-123");
+        assert_eq!(groceries.date().unwrap().to_string(), "2024-03-02");
+        assert_eq!(groceries.cleared(), ClearedStatus::Cleared);
+        assert_eq!(groceries.payee_id(), Some("payee-costco"));
+        assert_eq!(groceries.description(), Some("Costco run"));
+        assert_eq!(groceries.import_id(), None);
+        assert!(!groceries.is_split());
+        let household = &transaction.subtransactions()[1];
+        assert_eq!(household.payee_name(), Some("Gift"));
+        assert_eq!(household.description(), Some("Vacuum"));
+        assert!(household.is_deleted());
+    }
+
+    #[test]
+    fn should_default_missing_transaction_status_fields() {
+        let mapper = ResponseMapper::new();
+        let json = json!({"id": "trans-1", "account_id": "account-1", "amount": -1000, "cleared": "pending"});
+
+        let transaction = mapper.map_transaction(&json).unwrap();
+
+        assert_eq!(transaction.cleared(), ClearedStatus::Uncleared);
+        assert!(!transaction.is_approved());
+        assert_eq!(transaction.flag_color(), None);
+        assert!(!transaction.is_deleted());
+    }
+
+    #[test]
+    fn should_reject_transactions_with_malformed_dates() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "id": "trans-789",
+            "account_id": "account-123",
+            "amount": -50000,
+            "date": "15/01/2024"
+        });
+
+        let result = mapper.map_transaction(&json);
+
+        assert_eq!(result, Err(YnabError::invalid_date("15/01/2024")));
+    }
+
     #[test]
     fn should_map_multiple_transactions_from_api_response() {
         let mapper = ResponseMapper::new();
@@ -295,6 +886,344 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_map_categories_from_nested_category_groups() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "data": {
+                "category_groups": [
+                    {
+                        "id": "group-1",
+                        "name": "Everyday Expenses",
+                        "categories": [
+                            {"id": "cat-1", "name": "Groceries", "category_group_id": "group-1"},
+                            {"id": "cat-2", "name": "Fuel", "category_group_id": "group-1"}
+                        ]
+                    },
+                    {
+                        "id": "group-2",
+                        "name": "Monthly Bills",
+                        "categories": [
+                            {"id": "cat-3", "name": "Rent", "category_group_id": "group-2"}
+                        ]
+                    }
+                ]
+            }
+        });
+
+        let categories = mapper.map_categories_from_response(&json).unwrap();
+
+        assert_eq!(categories.len(), 3);
+        assert_eq!(categories[0].name(), "Groceries");
+        assert_eq!(categories[2].id(), "cat-3");
+        assert_eq!(categories[2].group_id(), Some("group-2"));
+    }
+
+    #[test]
+    fn should_handle_invalid_categories_response_format() {
+        let mapper = ResponseMapper::new();
+        let json = json!({"data": {}});
+
+        let result = mapper.map_categories_from_response(&json);
+
+        match result {
+            Err(YnabError::ApiError(msg)) => {
+                assert_eq!(msg, "Invalid categories response format")
+            }
+            other => panic!("Expected ApiError, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_map_account_balances_and_details() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "id": "acc-1",
+            "name": "Checking",
+            "type": "checking",
+            "on_budget": true,
+            "closed": true,
+            "note": "Joint account",
+            "balance": 125000,
+            "cleared_balance": 100000,
+            "uncleared_balance": 25000,
+            "last_reconciled_at": "2024-05-31T09:30:00Z"
+        });
+
+        let account = mapper.map_account(&json).unwrap();
+
+        assert_eq!(account.balance(), Money::from_milliunits(125000));
+        assert_eq!(account.cleared_balance(), Money::from_milliunits(100000));
+        assert_eq!(account.uncleared_balance(), Money::from_milliunits(25000));
+        assert!(account.is_closed());
+        assert_eq!(account.note(), Some("Joint account"));
+        assert_eq!(account.last_reconciled_at(), Some("2024-05-31T09:30:00Z"));
+    }
+
+    #[test]
+    fn should_map_accounts_from_api_response() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {
+                "accounts": [
+                    {"id": "acc-1", "name": "Checking", "type": "checking", "on_budget": true},
+                    {"id": "acc-2", "name": "Mortgage", "type": "mortgage", "on_budget": false}
+                ]
+            }
+        });
+
+        let accounts = mapper.map_accounts_from_response(&response).unwrap();
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].account_type(), &AccountType::Checking);
+        assert!(accounts[0].is_on_budget());
+        assert!(accounts[1].is_liability());
+        assert!(!accounts[1].is_on_budget());
+    }
+
+    #[test]
+    fn should_map_category_month_amounts() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "id": "cat-1",
+            "name": "Groceries",
+            "category_group_id": "group-1",
+            "budgeted": 400000,
+            "activity": -380000,
+            "balance": 20000
+        });
+
+        let category = mapper.map_category(&json).unwrap();
+
+        assert_eq!(category.budgeted(), Money::from_milliunits(400000));
+        assert_eq!(category.activity(), Money::from_milliunits(-380000));
+        assert_eq!(category.balance(), Money::from_milliunits(20000));
+    }
+
+    #[test]
+    fn should_map_category_goal_fields() {
+        let mapper = ResponseMapper::new();
+        let json = json!({
+            "id": "cat-1",
+            "name": "Vacation",
+            "balance": 300000,
+            "goal_type": "TBD",
+            "goal_target": 1200000,
+            "goal_target_month": "2024-12-01",
+            "goal_percentage_complete": 25,
+            "goal_under_funded": 100000
+        });
+
+        let category = mapper.map_category(&json).unwrap();
+
+        assert_eq!(
+            category.goal(),
+            Some(&CategoryGoal {
+                goal_type: GoalType::TargetBalanceByDate,
+                target: Money::from_milliunits(1200000),
+                target_month: "2024-12-01".parse().ok(),
+                percentage_complete: Some(25),
+                under_funded: Some(Money::from_milliunits(100000)),
+            })
+        );
+    }
+
+    #[test]
+    fn should_map_categories_without_goal_or_with_unknown_goal_type() {
+        let mapper = ResponseMapper::new();
+
+        let plain = mapper
+            .map_category(&json!({"id": "cat-1", "name": "Rent", "goal_type": null}))
+            .unwrap();
+        let unknown = mapper
+            .map_category(&json!({"id": "cat-2", "name": "Misc", "goal_type": "FUTURE"}))
+            .unwrap();
+
+        assert_eq!(plain.goal(), None);
+        assert_eq!(unknown.goal(), None);
+    }
+
+    #[test]
+    fn should_map_month_detail_without_deleted_categories() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {
+                "month": {
+                    "month": "2024-03-01",
+                    "note": null,
+                    "income": 5000000,
+                    "budgeted": 4500000,
+                    "activity": -4200000,
+                    "to_be_budgeted": 500000,
+                    "age_of_money": 35,
+                    "categories": [
+                        {"id": "cat-1", "name": "Groceries", "budgeted": 400000, "activity": -380000, "balance": 20000, "deleted": false},
+                        {"id": "cat-2", "name": "Old", "budgeted": 0, "activity": 0, "balance": -5000, "deleted": true}
+                    ]
+                }
+            }
+        });
+
+        let month = mapper.map_budget_month_from_response(&response).unwrap();
+
+        assert_eq!(month.month(), "2024-03-01");
+        assert_eq!(month.income(), Money::from_milliunits(5000000));
+        assert_eq!(month.age_of_money(), Some(35));
+        assert_eq!(month.note(), None);
+        assert_eq!(month.categories().len(), 1);
+        assert!(mapper.map_budget_month_from_response(&json!({})).is_err());
+    }
+
+    #[test]
+    fn should_map_month_summaries_from_months_list() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {
+                "months": [
+                    {"month": "2024-02-01", "income": 100, "budgeted": 90, "activity": -80, "to_be_budgeted": 10},
+                    {"month": "2024-03-01", "income": 200, "budgeted": 150, "activity": -120, "to_be_budgeted": 50}
+                ]
+            }
+        });
+
+        let months = mapper.map_budget_months_from_response(&response).unwrap();
+
+        assert_eq!(months.len(), 2);
+        assert_eq!(months[1].month(), "2024-03-01");
+        assert_eq!(months[1].budgeted(), Money::from_milliunits(150));
+        assert!(months[1].categories().is_empty());
+        assert!(
+            mapper
+                .map_budget_months_from_response(&json!({"data": {"months": [{}]}}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_map_scheduled_transactions_from_api_response() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {
+                "scheduled_transactions": [
+                    {
+                        "id": "st-1",
+                        "account_id": "acc-1",
+                        "frequency": "everyOtherWeek",
+                        "date_first": "2024-01-05",
+                        "date_next": "2024-03-01",
+                        "amount": 2000000,
+                        "memo": "Paycheck",
+                        "payee_id": "p-1",
+                        "payee_name": "Employer",
+                        "category_id": "c-1",
+                        "category_name": "Inflow: Ready to Assign",
+                        "transfer_account_id": null,
+                        "deleted": false
+                    },
+                    {
+                        "id": "st-2",
+                        "account_id": "acc-1",
+                        "frequency": "monthly",
+                        "date_first": "2024-01-15",
+                        "date_next": "2024-03-15",
+                        "amount": -500000,
+                        "transfer_account_id": "acc-2",
+                        "deleted": true
+                    }
+                ]
+            }
+        });
+
+        let scheduled = mapper
+            .map_scheduled_transactions_from_response(&response)
+            .unwrap();
+
+        assert_eq!(scheduled.len(), 2);
+        assert_eq!(scheduled[0].frequency(), Frequency::EveryOtherWeek);
+        assert_eq!(scheduled[0].date_next().to_string(), "2024-03-01");
+        assert_eq!(scheduled[0].amount(), Money::from_milliunits(2000000));
+        assert_eq!(scheduled[0].memo(), Some("Paycheck"));
+        assert_eq!(
+            scheduled[0].category_name(),
+            Some("Inflow: Ready to Assign")
+        );
+        assert_eq!(scheduled[1].transfer_account_id(), Some("acc-2"));
+        assert_eq!(scheduled[1].payee_id(), None);
+        assert!(scheduled[1].is_deleted());
+    }
+
+    #[test]
+    fn should_reject_unknown_frequency() {
+        let mapper = ResponseMapper::new();
+        let json = json!({"id": "st-1", "frequency": "hourly"});
+
+        assert_eq!(
+            mapper.map_scheduled_transaction(&json).unwrap_err(),
+            YnabError::ApiError("Unknown frequency: hourly".to_string())
+        );
+    }
+
+    #[test]
+    fn should_map_payees_with_transfer_and_deleted_flags() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {
+                "payees": [
+                    {"id": "p-1", "name": "Whole Foods", "transfer_account_id": null, "deleted": false},
+                    {"id": "p-2", "name": "Transfer : Savings", "transfer_account_id": "acc-2", "deleted": false},
+                    {"id": "p-3", "name": "Old Gym", "deleted": true}
+                ]
+            }
+        });
+
+        let payees = mapper.map_payees_from_response(&response).unwrap();
+
+        assert_eq!(payees.len(), 3);
+        assert_eq!(payees[0].name(), "Whole Foods");
+        assert!(!payees[0].is_transfer());
+        assert_eq!(payees[1].transfer_account_id(), Some("acc-2"));
+        assert!(payees[2].is_deleted());
+        assert!(mapper.map_payees_from_response(&json!({})).is_err());
+    }
+
+    #[test]
+    fn should_keep_accounts_of_unknown_type() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {
+                "accounts": [
+                    {"id": "acc-1", "name": "Checking", "type": "checking", "on_budget": true},
+                    {"id": "acc-2", "name": "Mystery", "type": "crypto", "balance": 250000}
+                ]
+            }
+        });
+
+        let accounts = mapper.map_accounts_from_response(&response).unwrap();
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(
+            accounts[1].account_type(),
+            &AccountType::Unknown("crypto".to_string())
+        );
+        assert_eq!(accounts[1].account_type().api_name(), "crypto");
+        assert_eq!(accounts[1].balance(), Money::from_milliunits(250000));
+        assert!(!accounts[1].is_liability());
+    }
+
+    #[test]
+    fn should_map_budgets_from_api_response() {
+        let mapper = ResponseMapper::new();
+        let response = json!({
+            "data": {"budgets": [{"id": "b-1", "name": "Home"}, {"id": "b-2", "name": "Work"}]}
+        });
+
+        let budgets = mapper.map_budgets_from_response(&response).unwrap();
+
+        assert_eq!(budgets.len(), 2);
+        assert_eq!(budgets[1].id(), "b-2");
+        assert!(mapper.map_budgets_from_response(&json!({})).is_err());
+    }
+
     #[test]
     fn should_support_default_trait() {
         let mapper: ResponseMapper = Default::default();
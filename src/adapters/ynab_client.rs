@@ -1,16 +1,32 @@
 //! YNAB API client for making HTTP requests to the YNAB API.
 
+use crate::adapters::api_error::map_error_response;
 use crate::adapters::cache::ApiResponseCache;
+use crate::adapters::delta_sync::{DeltaEndpoint, DeltaSnapshot};
+use crate::adapters::rate_limiter::{RateLimiter, RetryPolicy};
 use crate::domain::{YnabError, YnabResult};
+use reqwest::StatusCode;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 /// YNAB API client with authentication, HTTP capabilities, and caching.
+///
+/// Transactions, scheduled transactions, categories and accounts are kept in
+/// per-budget snapshots that are refreshed with delta requests once the cached
+/// response expires.
+///
+/// Requests draw from a token-bucket [`RateLimiter`] shared by every clone, and
+/// throttled or failed requests are retried according to a [`RetryPolicy`].
 #[derive(Debug)]
 pub struct YnabClient {
     api_token: String,
     base_url: String,
     client: reqwest::Client,
     cache: Arc<Mutex<ApiResponseCache>>,
+    snapshots: Arc<Mutex<HashMap<String, DeltaSnapshot>>>,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
 }
 
 impl YnabClient {
@@ -29,6 +45,9 @@ impl YnabClient {
             base_url: "https://api.ynab.com/v1".to_string(),
             client: reqwest::Client::new(),
             cache: Arc::new(Mutex::new(ApiResponseCache::new())),
+            snapshots: Arc::new(Mutex::new(HashMap::new())),
+            rate_limiter: Arc::new(RateLimiter::ynab_default()),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -50,9 +69,44 @@ impl YnabClient {
             base_url,
             client: reqwest::Client::new(),
             cache: Arc::new(Mutex::new(ApiResponseCache::new())),
+            snapshots: Arc::new(Mutex::new(HashMap::new())),
+            rate_limiter: Arc::new(RateLimiter::ynab_default()),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// Replaces the rate limiter, for example to share one between separately created clients.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Replaces the retry policy used for throttled and failed requests.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::YnabClient;
+    /// use ynab_mcp::adapters::RetryPolicy;
+    ///
+    /// let client = YnabClient::new("your-api-token".to_string())
+    ///     .with_retry_policy(RetryPolicy::no_retries());
+    /// assert_eq!(client.retry_policy().max_retries, 0);
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns the number of requests left in the local rate limit quota.
+    pub fn remaining_requests(&self) -> u32 {
+        self.rate_limiter.remaining()
+    }
+
     /// Returns the API token (for testing purposes).
     pub fn api_token(&self) -> &str {
         &self.api_token
@@ -95,31 +149,155 @@ impl YnabClient {
         }
 
         // Cache miss - make HTTP request
+        let json = self.fetch_json(path).await?;
+
+        // Store in cache
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.set(path, json.clone());
+        }
+
+        Ok(json)
+    }
+
+    /// Makes an authenticated GET request without consulting or filling the cache.
+    ///
+    /// Each attempt takes a rate limit token. `429` and `5xx` responses are retried
+    /// with exponential backoff, honouring `Retry-After` on `429`, until the retry
+    /// policy is exhausted or the required wait exceeds its maximum delay. The
+    /// final failure is mapped from YNAB's error body by [`map_error_response`].
+    async fn fetch_json(&self, path: &str) -> YnabResult<serde_json::Value> {
         let url = format!("{}{}", self.base_url, path);
+        let mut attempt = 0;
+
+        loop {
+            self.acquire_request_token().await?;
+
+            let response = self
+                .client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", self.api_token))
+                .send()
+                .await?;
+            self.sync_quota(&response);
+
+            let status = response.status();
+            if status.is_success() {
+                return Ok(response.json::<serde_json::Value>().await?);
+            }
+
+            let throttled = status == StatusCode::TOO_MANY_REQUESTS;
+            let server_delay = retry_after(&response).filter(|_| throttled);
+            // Without a Retry-After hint the local quota is the only guide
+            if throttled && server_delay.is_none() {
+                self.rate_limiter.exhaust();
+            }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(YnabError::api_error(format!(
-                "HTTP {} for {}",
-                response.status(),
-                url
-            )));
+            let delay = server_delay.unwrap_or_else(|| self.retry_policy.backoff_delay(attempt));
+            let retryable = throttled || status.is_server_error();
+
+            if !retryable
+                || attempt >= self.retry_policy.max_retries
+                || delay > self.retry_policy.max_delay
+            {
+                let body = response
+                    .json::<serde_json::Value>()
+                    .await
+                    .unwrap_or_default();
+                return Err(map_error_response(
+                    status.as_u16(),
+                    path,
+                    &body,
+                    Some(delay),
+                ));
+            }
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         }
+    }
 
-        let json = response.json::<serde_json::Value>().await?;
+    /// Takes a rate limit token, waiting for one if the wait fits the retry policy.
+    async fn acquire_request_token(&self) -> YnabResult<()> {
+        match self.rate_limiter.try_acquire() {
+            Ok(()) => Ok(()),
+            Err(wait) if wait <= self.retry_policy.max_delay => {
+                tokio::time::sleep(wait).await;
+                self.rate_limiter
+                    .try_acquire()
+                    .map_err(|wait| YnabError::rate_limited(self.rate_limiter.remaining(), wait))
+            }
+            Err(wait) => Err(YnabError::rate_limited(self.rate_limiter.remaining(), wait)),
+        }
+    }
+
+    /// Aligns the local quota with YNAB's `X-Rate-Limit: used/limit` header.
+    fn sync_quota(&self, response: &reqwest::Response) {
+        let quota = response
+            .headers()
+            .get("X-Rate-Limit")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split_once('/'))
+            .and_then(|(used, limit)| {
+                Some((
+                    used.trim().parse::<u32>().ok()?,
+                    limit.trim().parse::<u32>().ok()?,
+                ))
+            });
+
+        if let Some((used, limit)) = quota {
+            self.rate_limiter.sync_remaining(limit.saturating_sub(used));
+        }
+    }
+
+    /// Returns an endpoint's records, downloading only changes since the last request.
+    ///
+    /// Fresh cached responses are returned as-is. Otherwise the request carries the
+    /// snapshot's `last_knowledge_of_server`, the delta is merged into the snapshot
+    /// and the merged snapshot is returned in the shape of a full response.
+    async fn get_json_with_delta(
+        &self,
+        path: &str,
+        endpoint: DeltaEndpoint,
+    ) -> YnabResult<serde_json::Value> {
+        if let Ok(mut cache) = self.cache.lock()
+            && let Some(cached_data) = cache.get(path)
+        {
+            return Ok(cached_data);
+        }
+
+        let knowledge = self
+            .snapshots
+            .lock()
+            .ok()
+            .and_then(|snapshots| snapshots.get(path)?.server_knowledge());
+        let response = match knowledge {
+            Some(knowledge) => {
+                let delta_path = format!("{}?last_knowledge_of_server={}", path, knowledge);
+                self.fetch_json(&delta_path).await?
+            }
+            None => self.fetch_json(path).await?,
+        };
+
+        let merged = {
+            let mut snapshots = self
+                .snapshots
+                .lock()
+                .map_err(|_| YnabError::api_error("Snapshot store is unavailable"))?;
+            match snapshots.get_mut(path) {
+                Some(snapshot) if knowledge.is_some() => snapshot.apply_delta(&response)?,
+                _ => {
+                    let snapshot = DeltaSnapshot::from_response(endpoint, &response)?;
+                    snapshots.insert(path.to_string(), snapshot);
+                }
+            }
+            snapshots[path].to_response()
+        };
 
-        // Store in cache
         if let Ok(mut cache) = self.cache.lock() {
-            cache.set(path, json.clone());
+            cache.set(path, merged.clone());
         }
 
-        Ok(json)
+        Ok(merged)
     }
 
     /// Gets the list of budgets for the authenticated user.
@@ -155,7 +333,29 @@ impl YnabClient {
     /// ```
     pub async fn get_categories(&self, budget_id: &str) -> YnabResult<serde_json::Value> {
         let path = format!("/budgets/{}/categories", budget_id);
-        self.get_json(&path).await
+        self.get_json_with_delta(&path, DeltaEndpoint::CategoryGroups)
+            .await
+    }
+
+    /// Gets the accounts for a specific budget.
+    ///
+    /// # Arguments
+    /// * `budget_id` - The ID of the budget
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ynab_mcp::YnabClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = YnabClient::new("your-api-token".to_string());
+    /// let accounts = client.get_accounts("budget-123").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_accounts(&self, budget_id: &str) -> YnabResult<serde_json::Value> {
+        let path = format!("/budgets/{}/accounts", budget_id);
+        self.get_json_with_delta(&path, DeltaEndpoint::Records("accounts"))
+            .await
     }
 
     /// Gets the transactions for a specific budget.
@@ -175,12 +375,103 @@ impl YnabClient {
     /// ```
     pub async fn get_transactions(&self, budget_id: &str) -> YnabResult<serde_json::Value> {
         let path = format!("/budgets/{}/transactions", budget_id);
-        self.get_json(&path).await
+        self.get_json_with_delta(&path, DeltaEndpoint::Records("transactions"))
+            .await
+    }
+
+    /// Gets the scheduled transactions for a specific budget.
+    ///
+    /// # Arguments
+    /// * `budget_id` - The ID of the budget
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ynab_mcp::YnabClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = YnabClient::new("your-api-token".to_string());
+    /// let scheduled = client.get_scheduled_transactions("budget-123").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_scheduled_transactions(
+        &self,
+        budget_id: &str,
+    ) -> YnabResult<serde_json::Value> {
+        let path = format!("/budgets/{}/scheduled_transactions", budget_id);
+        self.get_json_with_delta(&path, DeltaEndpoint::Records("scheduled_transactions"))
+            .await
+    }
+
+    /// Gets the month summaries of a specific budget.
+    ///
+    /// # Arguments
+    /// * `budget_id` - The ID of the budget
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ynab_mcp::YnabClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = YnabClient::new("your-api-token".to_string());
+    /// let months = client.get_months("budget-123").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_months(&self, budget_id: &str) -> YnabResult<serde_json::Value> {
+        self.get_json(&format!("/budgets/{}/months", budget_id))
+            .await
+    }
+
+    /// Gets a single budget month with the amounts of every category.
+    ///
+    /// # Arguments
+    /// * `budget_id` - The ID of the budget
+    /// * `month` - First day of the month in `YYYY-MM-DD` format, or `current`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ynab_mcp::YnabClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = YnabClient::new("your-api-token".to_string());
+    /// let month = client.get_month("budget-123", "current").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_month(&self, budget_id: &str, month: &str) -> YnabResult<serde_json::Value> {
+        self.get_json(&format!("/budgets/{}/months/{}", budget_id, month))
+            .await
+    }
+
+    /// Gets the payees for a specific budget.
+    ///
+    /// Payees are always downloaded in full rather than through a delta
+    /// snapshot, so deleted payees stay in the response with `deleted: true`
+    /// and older transactions can still be matched to a name.
+    ///
+    /// # Arguments
+    /// * `budget_id` - The ID of the budget
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ynab_mcp::YnabClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = YnabClient::new("your-api-token".to_string());
+    /// let payees = client.get_payees("budget-123").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_payees(&self, budget_id: &str) -> YnabResult<serde_json::Value> {
+        self.get_json(&format!("/budgets/{}/payees", budget_id))
+            .await
     }
 
     /// Clears all cached API responses.
     ///
-    /// This is useful for testing or when you want to ensure fresh data.
+    /// This is useful for testing or when you want to ensure fresh data. Delta
+    /// snapshots are kept, so the next request only downloads what changed.
     pub fn clear_cache(&self) {
         if let Ok(mut cache) = self.cache.lock() {
             cache.clear();
@@ -284,6 +575,19 @@ impl YnabClient {
     }
 }
 
+/// Reads a `Retry-After` header given in seconds.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
 impl Clone for YnabClient {
     fn clone(&self) -> Self {
         Self {
@@ -291,6 +595,9 @@ impl Clone for YnabClient {
             base_url: self.base_url.clone(),
             client: self.client.clone(),
             cache: Arc::clone(&self.cache),
+            snapshots: Arc::clone(&self.snapshots),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            retry_policy: self.retry_policy.clone(),
         }
     }
 }
@@ -427,6 +734,55 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn should_get_months_list_and_month_detail() {
+        let mut server = mockito::Server::new_async().await;
+        let months = server
+            .mock("GET", "/budgets/budget-1/months")
+            .with_status(200)
+            .with_body(r#"{"data":{"months":[{"month":"2024-03-01","budgeted":1000}]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let month = server
+            .mock("GET", "/budgets/budget-1/months/current")
+            .with_status(200)
+            .with_body(r#"{"data":{"month":{"month":"2024-03-01","categories":[]}}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        let list = client.get_months("budget-1").await.unwrap();
+        let detail = client.get_month("budget-1", "current").await.unwrap();
+
+        months.assert_async().await;
+        month.assert_async().await;
+        assert_eq!(list["data"]["months"][0]["budgeted"], 1000);
+        assert_eq!(detail["data"]["month"]["month"], "2024-03-01");
+    }
+
+    #[tokio::test]
+    async fn should_get_payees_including_deleted_ones() {
+        let mut server = mockito::Server::new_async().await;
+        let payees = server
+            .mock("GET", "/budgets/budget-1/payees")
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":7,"payees":[{"id":"p-1","name":"Grocer","deleted":false},{"id":"p-2","name":"Old Gym","deleted":true}]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        let response = client.get_payees("budget-1").await.unwrap();
+        let cached = client.get_payees("budget-1").await.unwrap();
+
+        payees.assert_async().await;
+        assert_eq!(response["data"]["payees"].as_array().unwrap().len(), 2);
+        assert_eq!(response["data"]["payees"][1]["deleted"], true);
+        assert_eq!(cached, response);
+    }
+
     #[test]
     fn should_have_empty_cache_on_creation() {
         let client = YnabClient::new("test-token".to_string());
@@ -589,11 +945,11 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            YnabError::ApiError(msg) => {
-                assert!(msg.contains("404"));
+            YnabError::NotFound { id, .. } => {
+                assert_eq!(id, "404");
             }
             YnabError::HttpApiError(_) => {} // Network error is also acceptable
-            other => panic!("Expected ApiError or HttpApiError, got: {:?}", other),
+            other => panic!("Expected NotFound or HttpApiError, got: {:?}", other),
         }
     }
 
@@ -659,4 +1015,345 @@ mod tests {
             Err(other) => panic!("Unexpected error type: {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn should_request_only_changes_after_initial_sync() {
+        let mut server = mockito::Server::new_async().await;
+        let full = server
+            .mock("GET", "/budgets/budget-1/transactions")
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":100,"transactions":[{"id":"t-1","amount":-1000},{"id":"t-2","amount":-2000}]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let delta = server
+            .mock(
+                "GET",
+                "/budgets/budget-1/transactions?last_knowledge_of_server=100",
+            )
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":101,"transactions":[{"id":"t-1","amount":-1000,"deleted":true},{"id":"t-3","amount":-3000}]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        client.get_transactions("budget-1").await.unwrap();
+        client.clear_cache();
+        let refreshed = client.get_transactions("budget-1").await.unwrap();
+
+        full.assert_async().await;
+        delta.assert_async().await;
+        let ids: Vec<&str> = refreshed["data"]["transactions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["t-2", "t-3"]);
+        assert_eq!(refreshed["data"]["server_knowledge"], 101);
+    }
+
+    #[tokio::test]
+    async fn should_keep_delta_state_per_budget_and_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-1/categories")
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":5,"category_groups":[{"id":"g-1","categories":[{"id":"c-1"}]}]}}"#)
+            .create_async()
+            .await;
+        let other_budget = server
+            .mock("GET", "/budgets/budget-2/categories")
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":9,"category_groups":[]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let categories_delta = server
+            .mock(
+                "GET",
+                "/budgets/budget-1/categories?last_knowledge_of_server=5",
+            )
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":6,"category_groups":[]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        client.get_categories("budget-1").await.unwrap();
+        client.clear_cache();
+        client.get_categories("budget-2").await.unwrap();
+        let refreshed = client.get_categories("budget-1").await.unwrap();
+
+        other_budget.assert_async().await;
+        categories_delta.assert_async().await;
+        assert_eq!(
+            refreshed["data"]["category_groups"][0]["categories"][0]["id"],
+            "c-1"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_share_delta_snapshots_between_clones() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-1/accounts")
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":3,"accounts":[{"id":"a-1"}]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let delta = server
+            .mock(
+                "GET",
+                "/budgets/budget-1/accounts?last_knowledge_of_server=3",
+            )
+            .with_status(200)
+            .with_body(r#"{"data":{"server_knowledge":3,"accounts":[]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+        let cloned_client = client.clone();
+
+        client.get_accounts("budget-1").await.unwrap();
+        client.clear_cache();
+        let refreshed = cloned_client.get_accounts("budget-1").await.unwrap();
+
+        delta.assert_async().await;
+        assert_eq!(refreshed["data"]["accounts"][0]["id"], "a-1");
+    }
+
+    fn fast_retry_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::new(
+            max_retries,
+            Duration::from_millis(1),
+            Duration::from_millis(50),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_retry_throttled_request_after_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let throttled = server
+            .mock("GET", "/budgets")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(1)
+            .create_async()
+            .await;
+        let succeeded = server
+            .mock("GET", "/budgets")
+            .with_status(200)
+            .with_body(r#"{"data":{"budgets":[]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url())
+            .with_retry_policy(fast_retry_policy(3));
+
+        let result = client.get_budgets().await;
+
+        assert!(result.is_ok());
+        throttled.assert_async().await;
+        succeeded.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_report_rate_limit_when_retry_after_is_too_long() {
+        let mut server = mockito::Server::new_async().await;
+        let throttled = server
+            .mock("GET", "/budgets")
+            .with_status(429)
+            .with_header("Retry-After", "120")
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url())
+            .with_retry_policy(fast_retry_policy(3));
+
+        let result = client.get_budgets().await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::RateLimited {
+                remaining: 0,
+                retry_after_secs: 120,
+                id: "429".to_string(),
+                detail: "Too many requests".to_string()
+            }
+        );
+        throttled.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_retry_server_errors_with_backoff() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("GET", "/budgets")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets")
+            .with_status(200)
+            .with_body(r#"{"data":{"budgets":[]}}"#)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url())
+            .with_retry_policy(fast_retry_policy(3));
+
+        let result = client.get_budgets().await;
+
+        assert!(result.is_ok());
+        failing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_max_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("GET", "/budgets")
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url())
+            .with_retry_policy(fast_retry_policy(2));
+
+        let result = client.get_budgets().await;
+
+        assert!(matches!(result, Err(YnabError::ApiError(ref msg)) if msg.contains("500")));
+        failing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_client_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let not_found = server
+            .mock("GET", "/budgets")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url())
+            .with_retry_policy(fast_retry_policy(3));
+
+        assert!(client.get_budgets().await.is_err());
+        not_found.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_share_rate_limit_quota_between_clones() {
+        let mut server = mockito::Server::new_async().await;
+        let budgets = server
+            .mock("GET", "/budgets")
+            .with_status(200)
+            .with_body(r#"{"data":{"budgets":[]}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url())
+            .with_rate_limiter(Arc::new(RateLimiter::new(1, Duration::from_secs(3600))));
+        let cloned_client = client.clone();
+
+        client.get_budgets().await.unwrap();
+        let result = cloned_client.get_json("/user").await;
+
+        match result {
+            Err(YnabError::RateLimited {
+                remaining,
+                retry_after_secs,
+                ..
+            }) => {
+                assert_eq!(remaining, 0);
+                assert!(retry_after_secs > 3500);
+            }
+            other => panic!("Expected RateLimited, got: {:?}", other),
+        }
+        budgets.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_sync_quota_from_rate_limit_header() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets")
+            .with_status(200)
+            .with_header("X-Rate-Limit", "195/200")
+            .with_body(r#"{"data":{"budgets":[]}}"#)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        client.get_budgets().await.unwrap();
+
+        assert_eq!(client.remaining_requests(), 5);
+    }
+
+    #[tokio::test]
+    async fn should_map_unauthorized_response_body() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets")
+            .with_status(401)
+            .with_body(r#"{"error":{"id":"401","name":"unauthorized","detail":"Unauthorized"}}"#)
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("expired-token".to_string(), server.url());
+
+        let result = client.get_budgets().await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::unauthorized("401", "Unauthorized")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_unknown_budget_from_not_found_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/missing-budget/categories")
+            .with_status(404)
+            .with_body(
+                r#"{"error":{"id":"404.2","name":"resource_not_found","detail":"Resource not found"}}"#,
+            )
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        let result = client.get_categories("missing-budget").await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::invalid_budget_id("missing-budget")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_keep_detail_of_throttled_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets")
+            .with_status(429)
+            .with_header("Retry-After", "600")
+            .with_body(
+                r#"{"error":{"id":"429","name":"too_many_requests","detail":"Too many requests"}}"#,
+            )
+            .create_async()
+            .await;
+        let client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        let result = client.get_budgets().await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::RateLimited { retry_after_secs: 600, ref detail, .. })
+                if detail == "Too many requests"
+        ));
+    }
 }
@@ -1,5 +1,7 @@
 //! Account domain entity.
 
+use crate::domain::Money;
+
 /// Represents different types of accounts in YNAB.
 #[derive(Debug, Clone, PartialEq)]
 pub enum AccountType {
@@ -16,15 +18,78 @@ pub enum AccountType {
     PersonalLoan,
     MedicalDebt,
     OtherDebt,
+    /// A type this server does not recognise, such as one added to YNAB later,
+    /// keeping the name the API used for it.
+    Unknown(String),
+}
+
+impl AccountType {
+    /// Parses the account type name used by the YNAB API, such as `"creditCard"`.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::AccountType;
+    ///
+    /// assert_eq!(AccountType::from_api_name("creditCard"), Some(AccountType::CreditCard));
+    /// assert_eq!(AccountType::from_api_name("unknown"), None);
+    /// ```
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        let account_type = match name {
+            "checking" => AccountType::Checking,
+            "savings" => AccountType::Savings,
+            "creditCard" => AccountType::CreditCard,
+            "cash" => AccountType::Cash,
+            "lineOfCredit" => AccountType::LineOfCredit,
+            "otherAsset" => AccountType::OtherAsset,
+            "otherLiability" => AccountType::OtherLiability,
+            "mortgage" => AccountType::Mortgage,
+            "autoLoan" => AccountType::AutoLoan,
+            "studentLoan" => AccountType::StudentLoan,
+            "personalLoan" => AccountType::PersonalLoan,
+            "medicalDebt" => AccountType::MedicalDebt,
+            "otherDebt" => AccountType::OtherDebt,
+            _ => return None,
+        };
+        Some(account_type)
+    }
+
+    /// Returns the account type name used by the YNAB API.
+    pub fn api_name(&self) -> &str {
+        match self {
+            AccountType::Checking => "checking",
+            AccountType::Savings => "savings",
+            AccountType::CreditCard => "creditCard",
+            AccountType::Cash => "cash",
+            AccountType::LineOfCredit => "lineOfCredit",
+            AccountType::OtherAsset => "otherAsset",
+            AccountType::OtherLiability => "otherLiability",
+            AccountType::Mortgage => "mortgage",
+            AccountType::AutoLoan => "autoLoan",
+            AccountType::StudentLoan => "studentLoan",
+            AccountType::PersonalLoan => "personalLoan",
+            AccountType::MedicalDebt => "medicalDebt",
+            AccountType::OtherDebt => "otherDebt",
+            AccountType::Unknown(name) => name,
+        }
+    }
 }
 
 /// Represents a financial account in YNAB.
+///
+/// Balances are signed as YNAB reports them, so liability accounts with debt
+/// carry negative balances.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Account {
     id: String,
     name: String,
     account_type: AccountType,
     on_budget: bool,
+    balance: Money,
+    cleared_balance: Money,
+    uncleared_balance: Money,
+    closed: bool,
+    note: Option<String>,
+    last_reconciled_at: Option<String>,
 }
 
 impl Account {
@@ -51,9 +116,55 @@ impl Account {
             name,
             account_type,
             on_budget,
+            balance: Money::from_milliunits(0),
+            cleared_balance: Money::from_milliunits(0),
+            uncleared_balance: Money::from_milliunits(0),
+            closed: false,
+            note: None,
+            last_reconciled_at: None,
         }
     }
 
+    /// Sets the working, cleared and uncleared balances.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Account, AccountType, Money};
+    ///
+    /// let account = Account::new("acc-1".to_string(), "Checking".to_string(), AccountType::Checking, true)
+    ///     .with_balances(
+    ///         Money::from_milliunits(150_000),
+    ///         Money::from_milliunits(120_000),
+    ///         Money::from_milliunits(30_000),
+    ///     );
+    /// assert_eq!(account.balance().as_milliunits(), 150_000);
+    /// assert_eq!(account.uncleared_balance().as_milliunits(), 30_000);
+    /// ```
+    pub fn with_balances(mut self, balance: Money, cleared: Money, uncleared: Money) -> Self {
+        self.balance = balance;
+        self.cleared_balance = cleared;
+        self.uncleared_balance = uncleared;
+        self
+    }
+
+    /// Sets whether the account is closed.
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// Sets the account note.
+    pub fn with_note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets when the account was last reconciled, as reported by YNAB.
+    pub fn with_last_reconciled_at(mut self, last_reconciled_at: String) -> Self {
+        self.last_reconciled_at = Some(last_reconciled_at);
+        self
+    }
+
     /// Returns the account ID.
     pub fn id(&self) -> &str {
         &self.id
@@ -74,6 +185,36 @@ impl Account {
         self.on_budget
     }
 
+    /// Returns the working balance, including uncleared transactions.
+    pub fn balance(&self) -> Money {
+        self.balance
+    }
+
+    /// Returns the balance of cleared transactions.
+    pub fn cleared_balance(&self) -> Money {
+        self.cleared_balance
+    }
+
+    /// Returns the balance of uncleared transactions.
+    pub fn uncleared_balance(&self) -> Money {
+        self.uncleared_balance
+    }
+
+    /// Returns whether the account is closed.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the account note, if any.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns when the account was last reconciled, if ever.
+    pub fn last_reconciled_at(&self) -> Option<&str> {
+        self.last_reconciled_at.as_deref()
+    }
+
     /// Returns whether this account is a liability (debt) account.
     pub fn is_liability(&self) -> bool {
         matches!(
@@ -203,4 +344,71 @@ mod tests {
         assert_eq!(account1, account2);
         assert_ne!(account1, account3);
     }
+
+    #[test]
+    fn should_round_trip_api_account_type_names() {
+        let account_types = [
+            AccountType::Checking,
+            AccountType::Savings,
+            AccountType::CreditCard,
+            AccountType::Cash,
+            AccountType::LineOfCredit,
+            AccountType::OtherAsset,
+            AccountType::OtherLiability,
+            AccountType::Mortgage,
+            AccountType::AutoLoan,
+            AccountType::StudentLoan,
+            AccountType::PersonalLoan,
+            AccountType::MedicalDebt,
+            AccountType::OtherDebt,
+        ];
+
+        for account_type in account_types {
+            assert_eq!(
+                AccountType::from_api_name(account_type.api_name()),
+                Some(account_type)
+            );
+        }
+    }
+
+    #[test]
+    fn should_default_to_open_account_with_zero_balances() {
+        let account = Account::new(
+            "acc-1".to_string(),
+            "Checking".to_string(),
+            AccountType::Checking,
+            true,
+        );
+
+        assert_eq!(account.balance(), Money::from_milliunits(0));
+        assert_eq!(account.cleared_balance(), Money::from_milliunits(0));
+        assert!(!account.is_closed());
+        assert_eq!(account.note(), None);
+        assert_eq!(account.last_reconciled_at(), None);
+    }
+
+    #[test]
+    fn should_set_balances_and_details() {
+        let account = Account::new(
+            "acc-visa".to_string(),
+            "Visa".to_string(),
+            AccountType::CreditCard,
+            true,
+        )
+        .with_balances(
+            Money::from_milliunits(-250_000),
+            Money::from_milliunits(-200_000),
+            Money::from_milliunits(-50_000),
+        )
+        .with_closed(true)
+        .with_note("Paid off monthly".to_string())
+        .with_last_reconciled_at("2024-06-30T12:00:00Z".to_string());
+
+        assert_eq!(account.balance(), Money::from_milliunits(-250_000));
+        assert_eq!(account.cleared_balance(), Money::from_milliunits(-200_000));
+        assert_eq!(account.uncleared_balance(), Money::from_milliunits(-50_000));
+        assert!(account.is_closed());
+        assert_eq!(account.note(), Some("Paid off monthly"));
+        assert_eq!(account.last_reconciled_at(), Some("2024-06-30T12:00:00Z"));
+    }
 }
@@ -0,0 +1,305 @@
+//! Classification of transactions into income, spending and money moving
+//! between accounts.
+
+use crate::domain::{Account, Money, Payee, Transaction};
+use std::collections::HashMap;
+
+/// Name YNAB gives the category that holds income waiting to be assigned.
+pub const READY_TO_ASSIGN_CATEGORY: &str = "Inflow: Ready to Assign";
+
+/// What a transaction line means for the budget's income and expenses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetFlow {
+    /// Money categorized as "Inflow: Ready to Assign".
+    Income,
+    /// Any other categorized activity; outflows are spending and inflows are refunds.
+    Spending,
+    /// Money moved between two on-budget accounts, such as a credit card payment.
+    Transfer,
+    /// Activity on a tracking account, which sits outside the budget.
+    OffBudget,
+}
+
+impl BudgetFlow {
+    /// Returns whether the flow counts towards income or expenses.
+    pub fn is_counted(&self) -> bool {
+        matches!(self, Self::Income | Self::Spending)
+    }
+}
+
+/// Income and spending over a set of transaction lines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BudgetFlowTotals {
+    pub income: Money,
+    /// Net spending, as a positive value; refunds reduce it.
+    pub spending: Money,
+    /// Number of income and spending lines.
+    pub counted: usize,
+    /// Number of transfer and tracking account lines left out.
+    pub excluded: usize,
+}
+
+impl BudgetFlowTotals {
+    /// Returns income minus spending.
+    pub fn net(&self) -> Money {
+        Money::from_milliunits(self.income.as_milliunits() - self.spending.as_milliunits())
+    }
+}
+
+/// Classifies transactions using the budget's accounts and transfer payees.
+#[derive(Debug, Clone, Default)]
+pub struct BudgetFlowClassifier<'a> {
+    on_budget: HashMap<&'a str, bool>,
+    transfer_payees: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> BudgetFlowClassifier<'a> {
+    /// Creates a classifier for the accounts and payees of one budget.
+    ///
+    /// Accounts missing from `accounts` are treated as on-budget.
+    pub fn new(accounts: &'a [Account], payees: &'a [Payee]) -> Self {
+        Self {
+            on_budget: accounts
+                .iter()
+                .map(|account| (account.id(), account.is_on_budget()))
+                .collect(),
+            transfer_payees: payees
+                .iter()
+                .filter_map(|payee| Some((payee.id(), payee.transfer_account_id()?)))
+                .collect(),
+        }
+    }
+
+    /// Classifies one transaction line.
+    ///
+    /// Lines on tracking accounts are off-budget. A transfer, recognized by
+    /// its transfer account or its transfer payee, only moves money when both
+    /// accounts are on-budget; a transfer to a tracking account leaves the
+    /// budget and is classified by its category like any other line.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Account, AccountType, BudgetFlow, BudgetFlowClassifier, Money, Transaction};
+    ///
+    /// let accounts = vec![
+    ///     Account::new("checking".to_string(), "Checking".to_string(), AccountType::Checking, true),
+    ///     Account::new("visa".to_string(), "Visa".to_string(), AccountType::CreditCard, true),
+    /// ];
+    /// let classifier = BudgetFlowClassifier::new(&accounts, &[]);
+    /// let card_payment = Transaction::builder()
+    ///     .id("txn-1".to_string())
+    ///     .account_id("checking".to_string())
+    ///     .category_id("".to_string())
+    ///     .amount(Money::from_milliunits(-200000))
+    ///     .transfer_account_id("visa".to_string())
+    ///     .build();
+    ///
+    /// assert_eq!(classifier.classify(&card_payment), BudgetFlow::Transfer);
+    /// ```
+    pub fn classify(&self, transaction: &Transaction) -> BudgetFlow {
+        if !self.is_on_budget(transaction.account_id()) {
+            return BudgetFlow::OffBudget;
+        }
+        let transfer_account = transaction.transfer_account_id().or_else(|| {
+            transaction
+                .payee_id()
+                .and_then(|payee_id| self.transfer_payees.get(payee_id).copied())
+        });
+        if transfer_account.is_some_and(|account_id| self.is_on_budget(account_id)) {
+            return BudgetFlow::Transfer;
+        }
+        if transaction.category_name() == Some(READY_TO_ASSIGN_CATEGORY) {
+            BudgetFlow::Income
+        } else {
+            BudgetFlow::Spending
+        }
+    }
+
+    /// Totals income and spending over transaction lines, leaving out
+    /// transfers, tracking accounts and deleted lines.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{BudgetFlowClassifier, Money, Transaction, READY_TO_ASSIGN_CATEGORY};
+    ///
+    /// let line = |id: &str, amount: i64, category: &str| {
+    ///     Transaction::builder()
+    ///         .id(id.to_string())
+    ///         .account_id("checking".to_string())
+    ///         .category_id(id.to_string())
+    ///         .category_name(category.to_string())
+    ///         .amount(Money::from_milliunits(amount))
+    ///         .build()
+    /// };
+    /// let transactions = [
+    ///     line("paycheck", 300000, READY_TO_ASSIGN_CATEGORY),
+    ///     line("shoes", -80000, "Clothing"),
+    ///     line("refund", 20000, "Clothing"),
+    /// ];
+    ///
+    /// let totals = BudgetFlowClassifier::default().totals(&transactions);
+    ///
+    /// assert_eq!(totals.income, Money::from_milliunits(300000));
+    /// assert_eq!(totals.spending, Money::from_milliunits(60000));
+    /// assert_eq!(totals.net(), Money::from_milliunits(240000));
+    /// ```
+    pub fn totals<'t>(
+        &self,
+        transactions: impl IntoIterator<Item = &'t Transaction>,
+    ) -> BudgetFlowTotals {
+        let (mut income, mut spending) = (0, 0);
+        let (mut counted, mut excluded) = (0, 0);
+        for transaction in transactions {
+            if transaction.is_deleted() {
+                continue;
+            }
+            let amount = transaction.amount().as_milliunits();
+            match self.classify(transaction) {
+                BudgetFlow::Income => income += amount,
+                BudgetFlow::Spending => spending -= amount,
+                BudgetFlow::Transfer | BudgetFlow::OffBudget => {
+                    excluded += 1;
+                    continue;
+                }
+            }
+            counted += 1;
+        }
+        BudgetFlowTotals {
+            income: Money::from_milliunits(income),
+            spending: Money::from_milliunits(spending),
+            counted,
+            excluded,
+        }
+    }
+
+    fn is_on_budget(&self, account_id: &str) -> bool {
+        self.on_budget.get(account_id).copied().unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AccountType;
+
+    fn accounts() -> Vec<Account> {
+        vec![
+            Account::new(
+                "checking".to_string(),
+                "Checking".to_string(),
+                AccountType::Checking,
+                true,
+            ),
+            Account::new(
+                "visa".to_string(),
+                "Visa".to_string(),
+                AccountType::CreditCard,
+                true,
+            ),
+            Account::new(
+                "brokerage".to_string(),
+                "Brokerage".to_string(),
+                AccountType::OtherAsset,
+                false,
+            ),
+        ]
+    }
+
+    fn payees() -> Vec<Payee> {
+        vec![
+            Payee::new("payee-visa".to_string(), "Transfer : Visa".to_string())
+                .with_transfer_account_id("visa".to_string()),
+            Payee::new(
+                "payee-brokerage".to_string(),
+                "Transfer : Brokerage".to_string(),
+            )
+            .with_transfer_account_id("brokerage".to_string()),
+        ]
+    }
+
+    fn line(account_id: &str, amount: i64) -> crate::domain::TransactionBuilder {
+        Transaction::builder()
+            .id(format!("txn-{}-{}", account_id, amount))
+            .account_id(account_id.to_string())
+            .category_id("".to_string())
+            .amount(Money::from_milliunits(amount))
+    }
+
+    #[test]
+    fn should_treat_only_ready_to_assign_inflows_as_income() {
+        let (accounts, payees) = (accounts(), payees());
+        let classifier = BudgetFlowClassifier::new(&accounts, &payees);
+
+        let paycheck = line("checking", 500_000)
+            .category_name(READY_TO_ASSIGN_CATEGORY.to_string())
+            .build();
+        let refund = line("visa", 20_000)
+            .category_name("Clothing".to_string())
+            .build();
+
+        assert_eq!(classifier.classify(&paycheck), BudgetFlow::Income);
+        assert_eq!(classifier.classify(&refund), BudgetFlow::Spending);
+    }
+
+    #[test]
+    fn should_recognize_transfers_by_account_or_transfer_payee() {
+        let (accounts, payees) = (accounts(), payees());
+        let classifier = BudgetFlowClassifier::new(&accounts, &payees);
+
+        let by_account = line("checking", -200_000)
+            .transfer_account_id("visa".to_string())
+            .build();
+        let by_payee = line("checking", -200_000)
+            .payee_id("payee-visa".to_string())
+            .build();
+
+        assert_eq!(classifier.classify(&by_account), BudgetFlow::Transfer);
+        assert_eq!(classifier.classify(&by_payee), BudgetFlow::Transfer);
+    }
+
+    #[test]
+    fn should_count_transfers_to_tracking_accounts_by_category() {
+        let (accounts, payees) = (accounts(), payees());
+        let classifier = BudgetFlowClassifier::new(&accounts, &payees);
+
+        let investment = line("checking", -100_000)
+            .payee_id("payee-brokerage".to_string())
+            .category_name("Investing".to_string())
+            .build();
+        let tracking_side = line("brokerage", 100_000)
+            .transfer_account_id("checking".to_string())
+            .build();
+
+        assert_eq!(classifier.classify(&investment), BudgetFlow::Spending);
+        assert_eq!(classifier.classify(&tracking_side), BudgetFlow::OffBudget);
+        assert!(!BudgetFlow::OffBudget.is_counted());
+    }
+
+    #[test]
+    fn should_total_budget_flows_without_transfers_or_deleted_lines() {
+        let (accounts, payees) = (accounts(), payees());
+        let classifier = BudgetFlowClassifier::new(&accounts, &payees);
+        let transactions = vec![
+            line("checking", 500_000)
+                .category_name(READY_TO_ASSIGN_CATEGORY.to_string())
+                .build(),
+            line("visa", -120_000).build(),
+            line("checking", -120_000)
+                .transfer_account_id("visa".to_string())
+                .build(),
+            line("visa", 120_000)
+                .transfer_account_id("checking".to_string())
+                .build(),
+            line("brokerage", 7_000).build(),
+            line("checking", -9_000).deleted(true).build(),
+        ];
+
+        let totals = classifier.totals(&transactions);
+
+        assert_eq!(totals.income, Money::from_milliunits(500_000));
+        assert_eq!(totals.spending, Money::from_milliunits(120_000));
+        assert_eq!(totals.net(), Money::from_milliunits(380_000));
+        assert_eq!(totals.counted, 2);
+        assert_eq!(totals.excluded, 3);
+    }
+}
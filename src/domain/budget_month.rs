@@ -0,0 +1,214 @@
+//! Budget month domain entity.
+
+use crate::domain::{Category, Money};
+
+/// Represents one month of a YNAB budget with its totals and category amounts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetMonth {
+    month: String,
+    note: Option<String>,
+    income: Money,
+    budgeted: Money,
+    activity: Money,
+    to_be_budgeted: Money,
+    age_of_money: Option<i64>,
+    categories: Vec<Category>,
+}
+
+impl BudgetMonth {
+    /// Creates a BudgetMonth with zero totals and no categories.
+    ///
+    /// # Arguments
+    /// * `month` - First day of the month in `YYYY-MM-DD` format
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{BudgetMonth, Money};
+    ///
+    /// let month = BudgetMonth::new("2024-03-01".to_string());
+    /// assert_eq!(month.month(), "2024-03-01");
+    /// assert_eq!(month.to_be_budgeted(), Money::from_milliunits(0));
+    /// ```
+    pub fn new(month: String) -> Self {
+        Self {
+            month,
+            note: None,
+            income: Money::from_milliunits(0),
+            budgeted: Money::from_milliunits(0),
+            activity: Money::from_milliunits(0),
+            to_be_budgeted: Money::from_milliunits(0),
+            age_of_money: None,
+            categories: Vec::new(),
+        }
+    }
+
+    /// Sets the month's totals.
+    ///
+    /// # Arguments
+    /// * `income` - Income received in the month
+    /// * `budgeted` - Total assigned to categories in the month
+    /// * `activity` - Net spending across categories in the month
+    /// * `to_be_budgeted` - Amount left in Ready to Assign
+    pub fn with_totals(
+        mut self,
+        income: Money,
+        budgeted: Money,
+        activity: Money,
+        to_be_budgeted: Money,
+    ) -> Self {
+        self.income = income;
+        self.budgeted = budgeted;
+        self.activity = activity;
+        self.to_be_budgeted = to_be_budgeted;
+        self
+    }
+
+    /// Sets the month's note.
+    pub fn with_note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the age of money, in days.
+    pub fn with_age_of_money(mut self, days: i64) -> Self {
+        self.age_of_money = Some(days);
+        self
+    }
+
+    /// Sets the categories with their amounts for this month.
+    pub fn with_categories(mut self, categories: Vec<Category>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Returns the first day of the month in `YYYY-MM-DD` format.
+    pub fn month(&self) -> &str {
+        &self.month
+    }
+
+    /// Returns the month's note, if any.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns the income received in the month.
+    pub fn income(&self) -> Money {
+        self.income
+    }
+
+    /// Returns the total assigned to categories in the month.
+    pub fn budgeted(&self) -> Money {
+        self.budgeted
+    }
+
+    /// Returns the net spending across categories in the month.
+    pub fn activity(&self) -> Money {
+        self.activity
+    }
+
+    /// Returns the amount left in Ready to Assign.
+    pub fn to_be_budgeted(&self) -> Money {
+        self.to_be_budgeted
+    }
+
+    /// Returns the age of money in days, if YNAB has calculated it.
+    pub fn age_of_money(&self) -> Option<i64> {
+        self.age_of_money
+    }
+
+    /// Returns the categories with their amounts for this month.
+    ///
+    /// Month summaries from the months list carry no categories.
+    pub fn categories(&self) -> &[Category] {
+        &self.categories
+    }
+
+    /// Returns the categories whose spending exceeded what was available, largest first.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{BudgetMonth, Category, Money};
+    ///
+    /// let dining = Category::new("dining".to_string(), "Dining Out".to_string()).with_month_amounts(
+    ///     Money::from_milliunits(50000),
+    ///     Money::from_milliunits(-80000),
+    ///     Money::from_milliunits(-30000),
+    /// );
+    /// let rent = Category::new("rent".to_string(), "Rent".to_string());
+    /// let month = BudgetMonth::new("2024-03-01".to_string()).with_categories(vec![dining, rent]);
+    ///
+    /// let overspent = month.overspent_categories();
+    /// assert_eq!(overspent.len(), 1);
+    /// assert_eq!(overspent[0].name(), "Dining Out");
+    /// assert_eq!(month.total_overspent(), Money::from_milliunits(30000));
+    /// ```
+    pub fn overspent_categories(&self) -> Vec<&Category> {
+        let mut overspent: Vec<&Category> = self
+            .categories
+            .iter()
+            .filter(|category| category.is_overspent())
+            .collect();
+        overspent.sort_by_key(|category| category.balance());
+        overspent
+    }
+
+    /// Returns the total overspending across categories, as a positive value.
+    pub fn total_overspent(&self) -> Money {
+        self.categories
+            .iter()
+            .map(|category| category.overspent_amount())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(id: &str, budgeted: i64, activity: i64, balance: i64) -> Category {
+        Category::new(id.to_string(), id.to_string()).with_month_amounts(
+            Money::from_milliunits(budgeted),
+            Money::from_milliunits(activity),
+            Money::from_milliunits(balance),
+        )
+    }
+
+    #[test]
+    fn should_store_month_totals_and_details() {
+        let month = BudgetMonth::new("2024-03-01".to_string())
+            .with_totals(
+                Money::from_milliunits(5_000_000),
+                Money::from_milliunits(4_500_000),
+                Money::from_milliunits(-4_200_000),
+                Money::from_milliunits(500_000),
+            )
+            .with_note("Tax refund month".to_string())
+            .with_age_of_money(42);
+
+        assert_eq!(month.income(), Money::from_milliunits(5_000_000));
+        assert_eq!(month.budgeted(), Money::from_milliunits(4_500_000));
+        assert_eq!(month.activity(), Money::from_milliunits(-4_200_000));
+        assert_eq!(month.to_be_budgeted(), Money::from_milliunits(500_000));
+        assert_eq!(month.note(), Some("Tax refund month"));
+        assert_eq!(month.age_of_money(), Some(42));
+        assert!(month.categories().is_empty());
+    }
+
+    #[test]
+    fn should_list_overspent_categories_largest_first() {
+        let month = BudgetMonth::new("2024-03-01".to_string()).with_categories(vec![
+            category("groceries", 400_000, -380_000, 20_000),
+            category("dining", 100_000, -110_000, -10_000),
+            category("fuel", 80_000, -125_000, -45_000),
+        ]);
+
+        let ids: Vec<&str> = month
+            .overspent_categories()
+            .iter()
+            .map(|category| category.id())
+            .collect();
+
+        assert_eq!(ids, vec!["fuel", "dining"]);
+        assert_eq!(month.total_overspent(), Money::from_milliunits(55_000));
+    }
+}
@@ -0,0 +1,357 @@
+//! Validated calendar dates with day and month arithmetic.
+//!
+//! Dates are stored as a count of days since 1970-01-01 so they can be
+//! compared and stepped by days and months without a date library.
+
+use crate::domain::{YnabError, YnabResult};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A day in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    days: i64,
+}
+
+impl CalendarDate {
+    /// The earliest date with a four-digit year, 0000-01-01.
+    pub const MIN: Self = Self { days: -719_528 };
+
+    /// The latest date with a four-digit year, 9999-12-31.
+    pub const MAX: Self = Self { days: 2_932_896 };
+
+    /// Parses a date in the ISO 8601 format used by the YNAB API.
+    ///
+    /// Accepts `YYYY-MM-DD`, optionally followed by a `T` and a time of day,
+    /// which is ignored. Returns [`YnabError::InvalidDate`] for malformed
+    /// dates and days that do not exist in their month.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{CalendarDate, YnabError};
+    ///
+    /// let date = CalendarDate::parse("2024-02-29").unwrap();
+    /// assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+    /// assert_eq!(CalendarDate::parse("2024-06-30T12:00:00Z").unwrap().to_string(), "2024-06-30");
+    /// assert_eq!(CalendarDate::parse("2023-02-29"), Err(YnabError::invalid_date("2023-02-29")));
+    /// ```
+    pub fn parse(text: &str) -> YnabResult<Self> {
+        let date = match text.split_once('T') {
+            Some((date, _time)) => date,
+            None => text,
+        };
+        let bytes = date.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(YnabError::invalid_date(text));
+        }
+        let number = |range: std::ops::Range<usize>| -> Option<i64> {
+            let digits = date.get(range)?;
+            digits
+                .bytes()
+                .all(|byte| byte.is_ascii_digit())
+                .then(|| digits.parse().ok())?
+        };
+        match (number(0..4), number(5..7), number(8..10)) {
+            (Some(year), Some(month), Some(day)) => {
+                Self::from_ymd(year as i32, month as u32, day as u32)
+                    .map_err(|_| YnabError::invalid_date(text))
+            }
+            _ => Err(YnabError::invalid_date(text)),
+        }
+    }
+
+    /// Creates a date from its year, month (1-12) and day of the month.
+    ///
+    /// Returns [`YnabError::InvalidDate`] when the day does not exist.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> YnabResult<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return Err(YnabError::invalid_date(format!(
+                "{:04}-{:02}-{:02}",
+                year, month, day
+            )));
+        }
+
+        // Howard Hinnant's days_from_civil
+        let (month, day) = (month as i64, day as i64);
+        let year = if month <= 2 {
+            year as i64 - 1
+        } else {
+            year as i64
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let shifted_month = (month + 9) % 12;
+        let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Ok(Self {
+            days: era * 146_097 + day_of_era - 719_468,
+        })
+    }
+
+    /// Returns today's date in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or(0);
+        Self {
+            days: seconds.div_euclid(86_400),
+        }
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.civil().0
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u32 {
+        self.civil().1
+    }
+
+    /// Returns the day of the month, from 1.
+    pub fn day(&self) -> u32 {
+        self.civil().2
+    }
+
+    /// Returns the date `days` days later, or earlier when negative.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self {
+            days: self.days + days,
+        }
+    }
+
+    /// Returns the date `days` days later, or `None` when that falls outside
+    /// [`CalendarDate::MIN`]..=[`CalendarDate::MAX`].
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        let date = Self {
+            days: self.days.checked_add(days)?,
+        };
+        (Self::MIN..=Self::MAX).contains(&date).then_some(date)
+    }
+
+    /// Returns the date `months` months later, or `None` when that falls
+    /// outside [`CalendarDate::MIN`]..=[`CalendarDate::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::CalendarDate;
+    ///
+    /// let date = CalendarDate::parse("2024-01-31").unwrap();
+    /// assert_eq!(date.checked_add_months(1), Some(date.add_months(1)));
+    /// assert_eq!(date.checked_add_months(i64::MAX), None);
+    /// ```
+    pub fn checked_add_months(&self, months: i64) -> Option<Self> {
+        let (year, month, _) = self.civil();
+        let index = (year as i64 * 12 + (month as i64 - 1)).checked_add(months)?;
+        let (min_year, max_year) = (Self::MIN.year() as i64, Self::MAX.year() as i64);
+        (min_year * 12..(max_year + 1) * 12)
+            .contains(&index)
+            .then(|| self.add_months(months))
+    }
+
+    /// Returns the date `months` months later, or earlier when negative.
+    ///
+    /// The day of the month is kept, moving to the last day of shorter months.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::CalendarDate;
+    ///
+    /// let date = CalendarDate::parse("2024-01-31").unwrap();
+    /// assert_eq!(date.add_months(1).to_string(), "2024-02-29");
+    /// assert_eq!(date.add_months(-2).to_string(), "2023-11-30");
+    /// ```
+    pub fn add_months(&self, months: i64) -> Self {
+        self.add_months_on_day(months, self.day())
+    }
+
+    /// Returns the date `months` months later on `day`, or on the last day of
+    /// the month when the month is shorter.
+    pub(crate) fn add_months_on_day(&self, months: i64, day: u32) -> Self {
+        let (year, month, _) = self.civil();
+        let index = year as i64 * 12 + (month as i64 - 1) + months;
+        let (year, month) = (index.div_euclid(12) as i32, index.rem_euclid(12) as u32 + 1);
+        let day = day.clamp(1, days_in_month(year, month));
+        Self::from_ymd(year, month, day).expect("clamped day exists in its month")
+    }
+
+    /// Returns the number of days from this date to `other`, negative when
+    /// `other` is earlier.
+    pub fn days_until(&self, other: CalendarDate) -> i64 {
+        other.days - self.days
+    }
+
+    /// Returns the first day of the month.
+    pub fn first_of_month(&self) -> Self {
+        self.add_days(1 - self.day() as i64)
+    }
+
+    /// Returns the last day of the month.
+    pub fn last_of_month(&self) -> Self {
+        self.first_of_month()
+            .add_days(self.days_in_month() as i64 - 1)
+    }
+
+    /// Returns the Monday starting the week of this date.
+    pub fn start_of_week(&self) -> Self {
+        // 1970-01-01 was a Thursday
+        self.add_days(-(self.days + 3).rem_euclid(7))
+    }
+
+    /// Returns the number of days in the month.
+    pub fn days_in_month(&self) -> u32 {
+        let (year, month, _) = self.civil();
+        days_in_month(year, month)
+    }
+
+    /// Formats the month as `YYYY-MM`.
+    pub fn year_month(&self) -> String {
+        let (year, month, _) = self.civil();
+        format!("{:04}-{:02}", year, month)
+    }
+
+    // Howard Hinnant's civil_from_days
+    fn civil(&self) -> (i32, u32, u32) {
+        let days = self.days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year as i32, month as u32, day as u32)
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.civil();
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+impl FromStr for CalendarDate {
+    type Err = YnabError;
+
+    fn from_str(text: &str) -> YnabResult<Self> {
+        Self::parse(text)
+    }
+}
+
+/// Returns the number of days in a month.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> CalendarDate {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn should_round_trip_dates_through_day_counts() {
+        assert_eq!(date("1970-01-01").days, 0);
+        assert_eq!(date("2000-03-01").days, 11_017);
+        for text in ["1999-12-31", "2024-02-29", "2024-03-01", "2100-02-28"] {
+            assert_eq!(date(text).to_string(), text);
+        }
+        assert_eq!(date("2024-02-28").add_days(2), date("2024-03-01"));
+        assert_eq!(date("2024-02-28").days_until(date("2024-03-01")), 2);
+        assert_eq!(CalendarDate::MIN, date("0000-01-01"));
+        assert_eq!(CalendarDate::MAX, date("9999-12-31"));
+    }
+
+    #[test]
+    fn should_reject_malformed_and_impossible_dates() {
+        for text in [
+            "2024-2-01",
+            "2024-13-01",
+            "2023-02-29",
+            "2024-04-31",
+            "2024/01/01",
+            "2024-01-0x",
+            "",
+        ] {
+            assert_eq!(
+                CalendarDate::parse(text),
+                Err(YnabError::invalid_date(text)),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn should_compare_dates_chronologically() {
+        assert!(date("2024-01-31") < date("2024-02-01"));
+        assert!(date("2023-12-31") < date("2024-01-01"));
+        assert_eq!(
+            date("2024-01-31").max(date("2024-01-09")),
+            date("2024-01-31")
+        );
+    }
+
+    #[test]
+    fn should_add_months_clamping_to_month_end() {
+        assert_eq!(date("2024-01-31").add_months(1), date("2024-02-29"));
+        assert_eq!(
+            date("2024-02-29").add_months_on_day(1, 31),
+            date("2024-03-31")
+        );
+        assert_eq!(date("2024-11-15").add_months(3), date("2025-02-15"));
+        assert_eq!(date("2024-01-15").add_months(-1), date("2023-12-15"));
+    }
+
+    #[test]
+    fn should_refuse_arithmetic_beyond_four_digit_years() {
+        assert_eq!(
+            date("9999-12-30").checked_add_days(1),
+            Some(CalendarDate::MAX)
+        );
+        assert_eq!(CalendarDate::MAX.checked_add_days(1), None);
+        assert_eq!(CalendarDate::MIN.checked_add_days(-1), None);
+        assert_eq!(date("2024-08-14").checked_add_days(i64::MAX), None);
+        assert_eq!(
+            date("0000-02-29").checked_add_months(-1),
+            Some(CalendarDate::MIN.add_days(28))
+        );
+        assert_eq!(date("9999-12-01").checked_add_months(1), None);
+        assert_eq!(date("2024-08-14").checked_add_months(i64::MIN), None);
+    }
+
+    #[test]
+    fn should_find_month_and_week_boundaries() {
+        let leap_day = date("2024-02-29");
+        assert_eq!(leap_day.first_of_month(), date("2024-02-01"));
+        assert_eq!(date("2024-02-10").last_of_month(), leap_day);
+        assert_eq!(leap_day.year_month(), "2024-02");
+        // 2024-02-29 was a Thursday
+        assert_eq!(leap_day.start_of_week(), date("2024-02-26"));
+        assert_eq!(date("2024-02-26").start_of_week(), date("2024-02-26"));
+        assert_eq!(date("2024-03-03").start_of_week(), date("2024-02-26"));
+    }
+
+    #[test]
+    fn should_produce_valid_today() {
+        let today = CalendarDate::today();
+
+        assert_eq!(date(&today.to_string()), today);
+    }
+}
@@ -0,0 +1,329 @@
+//! Daily cash-flow forecast for on-budget accounts.
+
+use crate::domain::{
+    Account, CalendarDate, DateRange, Money, ScheduledTransaction, SpendingBaseline,
+    expand_occurrences,
+};
+use std::collections::HashMap;
+
+/// Projected balance of an account at the end of one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyBalance {
+    pub date: CalendarDate,
+    pub balance: Money,
+}
+
+/// Projected daily balances of one account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountForecast {
+    pub account_id: String,
+    pub name: String,
+    pub starting_balance: Money,
+    /// Expected discretionary outflow per day, as a positive value.
+    pub daily_discretionary: Money,
+    pub daily: Vec<DailyBalance>,
+    /// First date the balance is projected to drop below zero; always `None`
+    /// for liability accounts such as credit cards, whose balance is debt
+    /// rather than cash.
+    pub first_negative_date: Option<CalendarDate>,
+    pub lowest_balance: Money,
+}
+
+/// Projected daily balances of every open on-budget account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CashFlowForecast {
+    /// Projected days, from the first to the last.
+    pub range: DateRange,
+    pub accounts: Vec<AccountForecast>,
+}
+
+impl CashFlowForecast {
+    /// Projects daily balances of open on-budget accounts for `days` days from `start_date`.
+    ///
+    /// Each day applies the occurrences of scheduled transactions due that day,
+    /// crediting scheduled transfers to the receiving account, and deducts the
+    /// account's share of the discretionary `baselines` spread evenly over the
+    /// history window. Liability accounts are projected but never flagged as
+    /// going negative.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Account, AccountType, CalendarDate, CashFlowForecast, Frequency, Money, ScheduledTransaction};
+    ///
+    /// let checking = Account::new("acc-1".to_string(), "Checking".to_string(), AccountType::Checking, true)
+    ///     .with_balances(Money::from_milliunits(1000000), Money::from_milliunits(1000000), Money::from_milliunits(0));
+    /// let rent = ScheduledTransaction::new(
+    ///     "rent".to_string(),
+    ///     "acc-1".to_string(),
+    ///     Frequency::Monthly,
+    ///     CalendarDate::parse("2024-01-03").unwrap(),
+    ///     CalendarDate::parse("2024-03-03").unwrap(),
+    ///     Money::from_milliunits(-1500000),
+    /// );
+    ///
+    /// let start = CalendarDate::parse("2024-03-01").unwrap();
+    /// let forecast = CashFlowForecast::project(&[checking], &[rent], &[], start, 5);
+    ///
+    /// assert_eq!(forecast.range.end().to_string(), "2024-03-05");
+    /// assert_eq!(forecast.accounts[0].first_negative_date, Some(start.add_days(2)));
+    /// assert_eq!(forecast.accounts[0].lowest_balance, Money::from_milliunits(-500000));
+    /// ```
+    pub fn project(
+        accounts: &[Account],
+        scheduled_transactions: &[ScheduledTransaction],
+        baselines: &[SpendingBaseline],
+        start: CalendarDate,
+        days: i64,
+    ) -> Self {
+        let range = DateRange::new(start, start.add_days(days.max(1) - 1));
+        let accounts: Vec<&Account> = accounts
+            .iter()
+            .filter(|account| account.is_on_budget() && !account.is_closed())
+            .collect();
+
+        // Scheduled amount per account and day offset
+        let mut scheduled: HashMap<(&str, i64), i64> = HashMap::new();
+        for occurrence in expand_occurrences(scheduled_transactions, &range) {
+            let offset = start.days_until(occurrence.date);
+            let transaction = occurrence.scheduled_transaction;
+            let amount = transaction.amount().as_milliunits();
+            *scheduled
+                .entry((transaction.account_id(), offset))
+                .or_insert(0) += amount;
+            if let Some(receiving_account) = transaction.transfer_account_id() {
+                *scheduled.entry((receiving_account, offset)).or_insert(0) -= amount;
+            }
+        }
+
+        let forecasts = accounts
+            .into_iter()
+            .map(|account| {
+                let account_baselines: Vec<&SpendingBaseline> = baselines
+                    .iter()
+                    .filter(|baseline| baseline.account_id == account.id())
+                    .collect();
+                // Discretionary spend accrued by the end of day `offset`, without rounding drift
+                let discretionary_through = |offset: i64| -> i64 {
+                    account_baselines
+                        .iter()
+                        .map(|baseline| {
+                            baseline.total.as_milliunits() * (offset + 1) / baseline.days.max(1)
+                        })
+                        .sum()
+                };
+
+                let starting_balance = account.balance().as_milliunits();
+                let mut scheduled_total = 0;
+                let mut daily = Vec::new();
+                let mut first_negative_date = None;
+                let mut lowest_balance = starting_balance;
+                for (offset, date) in range.days().enumerate() {
+                    let offset = offset as i64;
+                    scheduled_total += scheduled.get(&(account.id(), offset)).copied().unwrap_or(0);
+                    let balance =
+                        starting_balance + scheduled_total - discretionary_through(offset);
+                    if balance < 0 && first_negative_date.is_none() && !account.is_liability() {
+                        first_negative_date = Some(date);
+                    }
+                    lowest_balance = lowest_balance.min(balance);
+                    daily.push(DailyBalance {
+                        date,
+                        balance: Money::from_milliunits(balance),
+                    });
+                }
+
+                AccountForecast {
+                    account_id: account.id().to_string(),
+                    name: account.name().to_string(),
+                    starting_balance: account.balance(),
+                    daily_discretionary: account_baselines
+                        .iter()
+                        .map(|baseline| baseline.daily_average())
+                        .sum(),
+                    daily,
+                    first_negative_date,
+                    lowest_balance: Money::from_milliunits(lowest_balance),
+                }
+            })
+            .collect();
+
+        Self {
+            range,
+            accounts: forecasts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{AccountType, Frequency};
+
+    fn account(id: &str, on_budget: bool, balance: i64) -> Account {
+        Account::new(
+            id.to_string(),
+            id.to_string(),
+            AccountType::Checking,
+            on_budget,
+        )
+        .with_balances(
+            Money::from_milliunits(balance),
+            Money::from_milliunits(balance),
+            Money::from_milliunits(0),
+        )
+    }
+
+    fn scheduled(
+        id: &str,
+        account_id: &str,
+        frequency: Frequency,
+        next: &str,
+        amount: i64,
+    ) -> ScheduledTransaction {
+        ScheduledTransaction::new(
+            id.to_string(),
+            account_id.to_string(),
+            frequency,
+            next.parse().unwrap(),
+            next.parse().unwrap(),
+            Money::from_milliunits(amount),
+        )
+    }
+
+    fn date(text: &str) -> CalendarDate {
+        text.parse().unwrap()
+    }
+
+    fn balances(forecast: &AccountForecast) -> Vec<i64> {
+        forecast
+            .daily
+            .iter()
+            .map(|day| day.balance.as_milliunits())
+            .collect()
+    }
+
+    #[test]
+    fn should_apply_scheduled_transactions_and_discretionary_spend() {
+        let accounts = vec![account("checking", true, 100_000)];
+        let scheduled_transactions = vec![
+            scheduled(
+                "paycheck",
+                "checking",
+                Frequency::Weekly,
+                "2024-03-03",
+                50_000,
+            ),
+            scheduled(
+                "phone",
+                "checking",
+                Frequency::Monthly,
+                "2024-03-02",
+                -30_000,
+            ),
+        ];
+        let baselines = vec![SpendingBaseline {
+            category_id: "groceries".to_string(),
+            account_id: "checking".to_string(),
+            total: Money::from_milliunits(300_000),
+            days: 30,
+        }];
+
+        let forecast = CashFlowForecast::project(
+            &accounts,
+            &scheduled_transactions,
+            &baselines,
+            date("2024-03-01"),
+            4,
+        );
+
+        let checking = &forecast.accounts[0];
+        assert_eq!(checking.daily_discretionary, Money::from_milliunits(10_000));
+        assert_eq!(balances(checking), vec![90_000, 50_000, 90_000, 80_000]);
+        assert_eq!(checking.daily[3].date, date("2024-03-04"));
+        assert_eq!(checking.first_negative_date, None);
+        assert_eq!(checking.lowest_balance, Money::from_milliunits(50_000));
+    }
+
+    #[test]
+    fn should_flag_first_negative_date_per_account() {
+        let accounts = vec![
+            account("checking", true, 20_000),
+            account("savings", true, 500_000),
+        ];
+        let baselines = vec![SpendingBaseline {
+            category_id: "dining".to_string(),
+            account_id: "checking".to_string(),
+            total: Money::from_milliunits(70_000),
+            days: 7,
+        }];
+
+        let forecast = CashFlowForecast::project(&accounts, &[], &baselines, date("2024-03-30"), 4);
+
+        assert_eq!(
+            forecast.accounts[0].first_negative_date,
+            Some(date("2024-04-01"))
+        );
+        assert_eq!(forecast.accounts[1].first_negative_date, None);
+        assert_eq!(forecast.range.end(), date("2024-04-02"));
+    }
+
+    #[test]
+    fn should_not_flag_liability_accounts_as_going_negative() {
+        let visa = Account::new(
+            "visa".to_string(),
+            "Visa".to_string(),
+            AccountType::CreditCard,
+            true,
+        )
+        .with_balances(
+            Money::from_milliunits(-250_000),
+            Money::from_milliunits(-250_000),
+            Money::from_milliunits(0),
+        );
+        let baselines = vec![SpendingBaseline {
+            category_id: "dining".to_string(),
+            account_id: "visa".to_string(),
+            total: Money::from_milliunits(70_000),
+            days: 7,
+        }];
+
+        let forecast = CashFlowForecast::project(&[visa], &[], &baselines, date("2024-03-01"), 3);
+
+        assert_eq!(forecast.accounts[0].first_negative_date, None);
+        assert_eq!(
+            forecast.accounts[0].lowest_balance,
+            Money::from_milliunits(-280_000)
+        );
+    }
+
+    #[test]
+    fn should_credit_scheduled_transfers_to_receiving_account() {
+        let accounts = vec![
+            account("checking", true, 100_000),
+            account("savings", true, 0),
+        ];
+        let transfer = scheduled("save", "checking", Frequency::Never, "2024-03-02", -40_000)
+            .with_transfer_account_id("savings".to_string());
+
+        let forecast =
+            CashFlowForecast::project(&accounts, &[transfer], &[], date("2024-03-01"), 2);
+
+        assert_eq!(balances(&forecast.accounts[0]), vec![100_000, 60_000]);
+        assert_eq!(balances(&forecast.accounts[1]), vec![0, 40_000]);
+    }
+
+    #[test]
+    fn should_only_forecast_open_on_budget_accounts() {
+        let closed = account("old", true, 0).with_closed(true);
+        let accounts = vec![
+            account("checking", true, 0),
+            account("brokerage", false, 0),
+            closed,
+        ];
+
+        let forecast = CashFlowForecast::project(&accounts, &[], &[], date("2024-03-01"), 30);
+
+        assert_eq!(forecast.accounts.len(), 1);
+        assert_eq!(forecast.accounts[0].daily.len(), 30);
+    }
+}
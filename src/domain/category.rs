@@ -1,13 +1,21 @@
 //! Category domain entity.
 
-use crate::domain::{DateRange, Money, Transaction};
+use crate::domain::{CategoryGoal, DateRange, Money, Transaction};
 
 /// Represents a budget category in YNAB.
+///
+/// The budgeted, activity and balance amounts belong to a single budget month:
+/// the current month for the categories endpoint, or the requested month for
+/// the months endpoint.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Category {
     id: String,
     name: String,
     group_id: Option<String>,
+    budgeted: Money,
+    activity: Money,
+    balance: Money,
+    goal: Option<CategoryGoal>,
 }
 
 impl Category {
@@ -26,6 +34,10 @@ impl Category {
             id,
             name,
             group_id: None,
+            budgeted: Money::from_milliunits(0),
+            activity: Money::from_milliunits(0),
+            balance: Money::from_milliunits(0),
+            goal: None,
         }
     }
 
@@ -44,12 +56,43 @@ impl Category {
     /// ```
     pub fn new_with_group(id: String, name: String, group_id: String) -> Self {
         Self {
-            id,
-            name,
             group_id: Some(group_id),
+            ..Self::new(id, name)
         }
     }
 
+    /// Sets the amounts of this category for one budget month.
+    ///
+    /// # Arguments
+    /// * `budgeted` - Amount assigned to the category in the month
+    /// * `activity` - Net spending in the month; outflows are negative
+    /// * `balance` - Amount available at the end of the month, including rollover
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Category, Money};
+    ///
+    /// let dining = Category::new("dining".to_string(), "Dining Out".to_string()).with_month_amounts(
+    ///     Money::from_milliunits(100000),
+    ///     Money::from_milliunits(-130000),
+    ///     Money::from_milliunits(-30000),
+    /// );
+    /// assert!(dining.is_overspent());
+    /// assert_eq!(dining.overspent_amount(), Money::from_milliunits(30000));
+    /// ```
+    pub fn with_month_amounts(mut self, budgeted: Money, activity: Money, balance: Money) -> Self {
+        self.budgeted = budgeted;
+        self.activity = activity;
+        self.balance = balance;
+        self
+    }
+
+    /// Sets the goal of this category.
+    pub fn with_goal(mut self, goal: CategoryGoal) -> Self {
+        self.goal = Some(goal);
+        self
+    }
+
     /// Returns the category ID.
     pub fn id(&self) -> &str {
         &self.id
@@ -65,7 +108,38 @@ impl Category {
         self.group_id.as_deref()
     }
 
-    /// Calculates the total spending for this category from a list of transactions.
+    /// Returns the amount assigned to this category in the month.
+    pub fn budgeted(&self) -> Money {
+        self.budgeted
+    }
+
+    /// Returns the net spending in this category in the month.
+    pub fn activity(&self) -> Money {
+        self.activity
+    }
+
+    /// Returns the amount available in this category at the end of the month.
+    pub fn balance(&self) -> Money {
+        self.balance
+    }
+
+    /// Returns the goal of this category, if one is set.
+    pub fn goal(&self) -> Option<&CategoryGoal> {
+        self.goal.as_ref()
+    }
+
+    /// Returns whether more was spent than was available in the category.
+    pub fn is_overspent(&self) -> bool {
+        self.balance.as_milliunits() < 0
+    }
+
+    /// Returns how much more was spent than was available, as a positive value.
+    pub fn overspent_amount(&self) -> Money {
+        Money::from_milliunits((-self.balance.as_milliunits()).max(0))
+    }
+
+    /// Calculates the total spending for this category from a list of transactions,
+    /// ignoring deleted ones. Split transactions count through their lines.
     ///
     /// # Example
     /// ```
@@ -81,13 +155,17 @@ impl Category {
     pub fn calculate_spending(&self, transactions: &[Transaction]) -> Money {
         transactions
             .iter()
-            .filter(|t| t.category_id() == self.id)
+            .flat_map(Transaction::lines)
+            .filter(|t| t.category_id() == self.id && !t.is_deleted())
             .map(|t| t.amount())
             .sum()
     }
 
     /// Calculates the total spending for this category with optional date filtering.
     ///
+    /// Like [`Self::calculate_spending`], deleted transactions are ignored and
+    /// split transactions count through their lines.
+    ///
     /// # Example
     /// ```
     /// use ynab_mcp::{Category, Transaction, Money, DateRange};
@@ -95,9 +173,9 @@ impl Category {
     /// let category = Category::new("groceries".to_string(), "Groceries".to_string());
     /// let transactions = vec![
     ///     Transaction::new_with_date("txn-1".to_string(), "acc-test".to_string(), "groceries".to_string(),
-    ///                               Money::from_milliunits(-5000), "2024-01-15".to_string()),
+    ///                               Money::from_milliunits(-5000), "2024-01-15".parse().unwrap()),
     /// ];
-    /// let date_range = Some(DateRange::new("2024-01-01".to_string(), "2024-01-31".to_string()));
+    /// let date_range = Some(DateRange::parse("2024-01-01", "2024-01-31").unwrap());
     /// let spending = category.calculate_spending_with_date_filter(&transactions, date_range);
     /// assert_eq!(spending, Money::from_milliunits(-5000));
     /// ```
@@ -108,7 +186,8 @@ impl Category {
     ) -> Money {
         transactions
             .iter()
-            .filter(|t| t.category_id() == self.id)
+            .flat_map(Transaction::lines)
+            .filter(|t| t.category_id() == self.id && !t.is_deleted())
             .filter(|t| {
                 if let Some(ref range) = date_range {
                     if let Some(date) = t.date() {
@@ -138,6 +217,78 @@ mod tests {
         assert_eq!(category.name(), "Test Category");
     }
 
+    #[test]
+    fn should_ignore_deleted_transactions_in_category_spending() {
+        let category = Category::new("groceries".to_string(), "Groceries".to_string());
+        let transactions = vec![
+            Transaction::new(
+                "txn-1".to_string(),
+                "acc-test".to_string(),
+                "groceries".to_string(),
+                Money::from_milliunits(-5000),
+            ),
+            Transaction::builder()
+                .id("txn-2".to_string())
+                .account_id("acc-test".to_string())
+                .category_id("groceries".to_string())
+                .amount(Money::from_milliunits(-9000))
+                .deleted(true)
+                .build(),
+        ];
+
+        assert_eq!(
+            category.calculate_spending(&transactions),
+            Money::from_milliunits(-5000)
+        );
+        assert_eq!(
+            category.calculate_spending_with_date_filter(&transactions, None),
+            Money::from_milliunits(-5000)
+        );
+    }
+
+    #[test]
+    fn should_attribute_split_transactions_to_their_line_categories() {
+        let groceries = Category::new("groceries".to_string(), "Groceries".to_string());
+        let split_placeholder = Category::new("split".to_string(), "Split".to_string());
+        let line = |id: &str, category: &str, amount: i64| {
+            Transaction::builder()
+                .id(id.to_string())
+                .account_id("acc-test".to_string())
+                .category_id(category.to_string())
+                .amount(Money::from_milliunits(amount))
+                .date("2024-03-02".parse().unwrap())
+                .parent_id("txn-split".to_string())
+                .build()
+        };
+        let transactions = vec![
+            Transaction::builder()
+                .id("txn-split".to_string())
+                .account_id("acc-test".to_string())
+                .category_id("split".to_string())
+                .amount(Money::from_milliunits(-9000))
+                .date("2024-03-02".parse().unwrap())
+                .subtransactions(vec![
+                    line("sub-1", "groceries", -6000),
+                    line("sub-2", "household", -3000),
+                ])
+                .build(),
+        ];
+        let march = Some(DateRange::parse("2024-03-01", "2024-03-31").unwrap());
+
+        assert_eq!(
+            groceries.calculate_spending(&transactions),
+            Money::from_milliunits(-6000)
+        );
+        assert_eq!(
+            groceries.calculate_spending_with_date_filter(&transactions, march),
+            Money::from_milliunits(-6000)
+        );
+        assert_eq!(
+            split_placeholder.calculate_spending(&transactions),
+            Money::from_milliunits(0)
+        );
+    }
+
     #[test]
     fn should_calculate_category_spending_for_single_transaction() {
         let category = Category::new("groceries".to_string(), "Groceries".to_string());
@@ -192,28 +343,25 @@ mod tests {
                 "acc-test".to_string(),
                 "groceries".to_string(),
                 Money::from_milliunits(-3000),
-                "2024-01-15".to_string(),
+                "2024-01-15".parse().unwrap(),
             ),
             Transaction::new_with_date(
                 "txn-2".to_string(),
                 "acc-test".to_string(),
                 "groceries".to_string(),
                 Money::from_milliunits(-2000),
-                "2024-01-25".to_string(),
+                "2024-01-25".parse().unwrap(),
             ),
             Transaction::new_with_date(
                 "txn-3".to_string(),
                 "acc-test".to_string(),
                 "groceries".to_string(),
                 Money::from_milliunits(-1000),
-                "2024-02-05".to_string(), // Outside date range
+                "2024-02-05".parse().unwrap(), // Outside date range
             ),
         ];
 
-        let date_range = Some(crate::domain::DateRange::new(
-            "2024-01-01".to_string(),
-            "2024-01-31".to_string(),
-        ));
+        let date_range = Some(crate::domain::DateRange::parse("2024-01-01", "2024-01-31").unwrap());
 
         let spending = category.calculate_spending_with_date_filter(&transactions, date_range);
 
@@ -230,7 +378,7 @@ mod tests {
                 "acc-test".to_string(),
                 "groceries".to_string(),
                 Money::from_milliunits(-3000),
-                "2024-01-15".to_string(),
+                "2024-01-15".parse().unwrap(),
             ),
             Transaction::new(
                 "txn-2".to_string(),
@@ -240,10 +388,7 @@ mod tests {
             ),
         ];
 
-        let date_range = Some(crate::domain::DateRange::new(
-            "2024-01-01".to_string(),
-            "2024-01-31".to_string(),
-        ));
+        let date_range = Some(crate::domain::DateRange::parse("2024-01-01", "2024-01-31").unwrap());
 
         let spending = category.calculate_spending_with_date_filter(&transactions, date_range);
 
@@ -260,7 +405,7 @@ mod tests {
                 "acc-test".to_string(),
                 "groceries".to_string(),
                 Money::from_milliunits(-3000),
-                "2024-01-15".to_string(),
+                "2024-01-15".parse().unwrap(),
             ),
             Transaction::new(
                 "txn-2".to_string(),
@@ -295,4 +440,36 @@ mod tests {
 
         assert_eq!(category.group_id(), None);
     }
+
+    #[test]
+    fn should_default_month_amounts_to_zero() {
+        let category = Category::new("rent".to_string(), "Rent".to_string());
+
+        assert_eq!(category.budgeted(), Money::from_milliunits(0));
+        assert_eq!(category.activity(), Money::from_milliunits(0));
+        assert_eq!(category.balance(), Money::from_milliunits(0));
+        assert!(!category.is_overspent());
+    }
+
+    #[test]
+    fn should_report_overspending_only_for_negative_balance() {
+        let funded = Category::new("groceries".to_string(), "Groceries".to_string())
+            .with_month_amounts(
+                Money::from_milliunits(400_000),
+                Money::from_milliunits(-450_000),
+                Money::from_milliunits(20_000),
+            );
+        let overspent = Category::new("dining".to_string(), "Dining Out".to_string())
+            .with_month_amounts(
+                Money::from_milliunits(100_000),
+                Money::from_milliunits(-125_000),
+                Money::from_milliunits(-25_000),
+            );
+
+        assert!(!funded.is_overspent());
+        assert_eq!(funded.overspent_amount(), Money::from_milliunits(0));
+        assert!(overspent.is_overspent());
+        assert_eq!(overspent.overspent_amount(), Money::from_milliunits(25_000));
+        assert_eq!(overspent.budgeted(), Money::from_milliunits(100_000));
+    }
 }
@@ -1,40 +1,249 @@
 //! Date range value object for filtering transactions by date.
 
-/// Represents a date range for filtering transactions.
-#[derive(Debug, Clone, PartialEq)]
+use crate::domain::{CalendarDate, YnabError, YnabResult};
+
+/// Represents an inclusive range of calendar dates.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DateRange {
-    start: String, // Using String for simplicity, following TDD
-    end: String,
+    start: CalendarDate,
+    end: CalendarDate,
 }
 
 impl DateRange {
-    /// Creates a new DateRange.
+    /// Creates a new DateRange from its first and last day.
     ///
     /// # Example
     /// ```
-    /// use ynab_mcp::DateRange;
+    /// use ynab_mcp::{CalendarDate, DateRange};
     ///
-    /// let range = DateRange::new("2024-01-01".to_string(), "2024-01-31".to_string());
-    /// assert_eq!(range.start(), "2024-01-01");
-    /// assert_eq!(range.end(), "2024-01-31");
+    /// let start = CalendarDate::parse("2024-01-01").unwrap();
+    /// let end = CalendarDate::parse("2024-01-31").unwrap();
+    /// let range = DateRange::new(start, end);
+    /// assert_eq!(range.start().to_string(), "2024-01-01");
+    /// assert_eq!(range.day_count(), 31);
     /// ```
-    pub fn new(start: String, end: String) -> Self {
+    pub fn new(start: CalendarDate, end: CalendarDate) -> Self {
         Self { start, end }
     }
 
+    /// Creates a new DateRange from dates in the ISO 8601 format used by YNAB.
+    ///
+    /// Returns [`YnabError::InvalidDate`](crate::domain::YnabError::InvalidDate)
+    /// when either date is malformed.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::DateRange;
+    ///
+    /// let range = DateRange::parse("2024-01-01", "2024-01-31").unwrap();
+    /// assert!(range.contains("2024-01-15".parse().unwrap()));
+    /// assert!(DateRange::parse("2024-01-01", "2024-01-32").is_err());
+    /// ```
+    pub fn parse(start: &str, end: &str) -> YnabResult<Self> {
+        Ok(Self::new(
+            CalendarDate::parse(start)?,
+            CalendarDate::parse(end)?,
+        ))
+    }
+
+    /// Creates a DateRange covering the calendar month of `date`.
+    pub fn month_of(date: CalendarDate) -> Self {
+        Self::new(date.first_of_month(), date.last_of_month())
+    }
+
+    /// Resolves a period expression into a DateRange relative to `today`.
+    ///
+    /// Understands:
+    /// - `today`, `yesterday`
+    /// - `this_week`, `last_week`, `this_month`, `last_month`, `this_quarter`,
+    ///   `last_quarter`, `this_year` and `last_year`, covering whole calendar
+    ///   periods with weeks starting on Monday
+    /// - `mtd` and `ytd`, from the start of the month or year up to today
+    /// - `last_<n>_days`, `last_<n>_weeks` and `last_<n>_months`, ending today,
+    ///   and `next_<n>_days`, `next_<n>_weeks` and `next_<n>_months`, starting today
+    /// - `2024`, `2024-Q3`, `2024-07` and `2024-07-04` for a fixed year,
+    ///   quarter, month or day
+    ///
+    /// Expressions are case-insensitive and spaces may stand in for
+    /// underscores, so `"Last Quarter"` works too. Returns
+    /// [`YnabError::InvalidDate`] for anything else.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{CalendarDate, DateRange};
+    ///
+    /// let today = CalendarDate::parse("2024-08-14").unwrap();
+    /// let resolve = |expression: &str| {
+    ///     let range = DateRange::resolve(expression, today).unwrap();
+    ///     format!("{}..{}", range.start(), range.end())
+    /// };
+    ///
+    /// assert_eq!(resolve("last_month"), "2024-07-01..2024-07-31");
+    /// assert_eq!(resolve("last_90_days"), "2024-05-17..2024-08-14");
+    /// assert_eq!(resolve("ytd"), "2024-01-01..2024-08-14");
+    /// assert_eq!(resolve("2024-Q3"), "2024-07-01..2024-09-30");
+    /// assert!(DateRange::resolve("someday", today).is_err());
+    /// ```
+    pub fn resolve(expression: &str, today: CalendarDate) -> YnabResult<Self> {
+        let normalized = expression
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join("_")
+            .to_ascii_lowercase();
+        let range = match normalized.as_str() {
+            "today" => Self::new(today, today),
+            "yesterday" => Self::new(today.add_days(-1), today.add_days(-1)),
+            "this_week" => Self::week_of(today),
+            "last_week" => Self::week_of(today.add_days(-7)),
+            "this_month" => Self::month_of(today),
+            "last_month" => Self::month_of(today.first_of_month().add_months(-1)),
+            "this_quarter" => Self::quarter_of(today),
+            "last_quarter" => Self::quarter_of(today.first_of_month().add_months(-3)),
+            "this_year" => Self::year_of(today),
+            "last_year" => Self::year_of(today.first_of_month().add_months(-12)),
+            "mtd" => Self::new(today.first_of_month(), today),
+            "ytd" => Self::new(Self::year_of(today).start, today),
+            other => Self::rolling(other, today)
+                .or_else(|| Self::period(other))
+                .ok_or_else(|| YnabError::invalid_date(expression))?,
+        };
+        Ok(range)
+    }
+
     /// Returns the start date.
-    pub fn start(&self) -> &str {
-        &self.start
+    pub fn start(&self) -> CalendarDate {
+        self.start
     }
 
     /// Returns the end date.
-    pub fn end(&self) -> &str {
-        &self.end
+    pub fn end(&self) -> CalendarDate {
+        self.end
     }
 
     /// Checks if a date falls within this range (inclusive).
-    pub fn contains(&self, date: &str) -> bool {
-        date >= self.start.as_str() && date <= self.end.as_str()
+    pub fn contains(&self, date: CalendarDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+
+    /// Returns the number of days in the range, or zero when it ends before it starts.
+    pub fn day_count(&self) -> i64 {
+        (self.start.days_until(self.end) + 1).max(0)
+    }
+
+    /// Iterates over every day in the range.
+    pub fn days(&self) -> impl Iterator<Item = CalendarDate> + '_ {
+        (0..self.day_count()).map(|offset| self.start.add_days(offset))
+    }
+
+    /// Splits the range into Monday-to-Sunday weeks, trimming the first and
+    /// last week to the range.
+    pub fn weeks(&self) -> impl Iterator<Item = DateRange> + '_ {
+        self.periods(|date| date.start_of_week().add_days(7))
+    }
+
+    /// Splits the range into calendar months, trimming the first and last
+    /// month to the range.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::DateRange;
+    ///
+    /// let range = DateRange::parse("2024-01-20", "2024-03-10").unwrap();
+    /// let months: Vec<String> = range
+    ///     .months()
+    ///     .map(|month| format!("{}..{}", month.start(), month.end()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     months,
+    ///     vec!["2024-01-20..2024-01-31", "2024-02-01..2024-02-29", "2024-03-01..2024-03-10"]
+    /// );
+    /// ```
+    pub fn months(&self) -> impl Iterator<Item = DateRange> + '_ {
+        self.periods(|date| date.first_of_month().add_months(1))
+    }
+
+    /// Covers the Monday-to-Sunday week of `date`.
+    fn week_of(date: CalendarDate) -> Self {
+        let monday = date.start_of_week();
+        Self::new(monday, monday.add_days(6))
+    }
+
+    /// Covers the calendar quarter of `date`.
+    fn quarter_of(date: CalendarDate) -> Self {
+        let first = date
+            .first_of_month()
+            .add_months(-(((date.month() - 1) % 3) as i64));
+        Self::new(first, first.add_months(2).last_of_month())
+    }
+
+    /// Covers the calendar year of `date`.
+    fn year_of(date: CalendarDate) -> Self {
+        let first = date.first_of_month().add_months(1 - date.month() as i64);
+        Self::new(first, first.add_months(11).last_of_month())
+    }
+
+    /// Resolves `last_<n>_<unit>` and `next_<n>_<unit>` expressions.
+    fn rolling(expression: &str, today: CalendarDate) -> Option<Self> {
+        let (direction, rest) = expression.split_once('_')?;
+        let (count, unit) = rest.split_once('_')?;
+        let count: i64 = count.parse().ok().filter(|count| *count > 0)?;
+        // Counts reaching past CalendarDate::MIN or MAX resolve to nothing
+        let after = |date: CalendarDate, count: i64| match unit {
+            "day" | "days" => date.checked_add_days(count),
+            "week" | "weeks" => date.checked_add_days(count.checked_mul(7)?),
+            "month" | "months" => date.checked_add_months(count),
+            _ => None,
+        };
+        match direction {
+            "last" => Some(Self::new(after(today, -count)?.add_days(1), today)),
+            "next" => Some(Self::new(today, after(today, count)?.add_days(-1))),
+            _ => None,
+        }
+    }
+
+    /// Resolves `YYYY`, `YYYY-QN`, `YYYY-MM` and `YYYY-MM-DD` expressions.
+    fn period(expression: &str) -> Option<Self> {
+        match expression.len() {
+            4 => {
+                let year = CalendarDate::parse(&format!("{}-01-01", expression)).ok()?;
+                Some(Self::year_of(year))
+            }
+            7 if expression.as_bytes()[5] == b'q' => {
+                let quarter = match expression.get(4..)? {
+                    "-q1" => "01",
+                    "-q2" => "04",
+                    "-q3" => "07",
+                    "-q4" => "10",
+                    _ => return None,
+                };
+                let first =
+                    CalendarDate::parse(&format!("{}-{}-01", expression.get(..4)?, quarter))
+                        .ok()?;
+                Some(Self::quarter_of(first))
+            }
+            7 => {
+                let first = CalendarDate::parse(&format!("{}-01", expression)).ok()?;
+                Some(Self::month_of(first))
+            }
+            _ => {
+                let day = CalendarDate::parse(expression).ok()?;
+                Some(Self::new(day, day))
+            }
+        }
+    }
+
+    /// Splits the range into consecutive periods, each starting where
+    /// `next_start` says the previous one ends.
+    fn periods(
+        &self,
+        next_start: fn(&CalendarDate) -> CalendarDate,
+    ) -> impl Iterator<Item = DateRange> + '_ {
+        std::iter::successors(
+            (self.start <= self.end).then_some(self.start),
+            move |start| Some(next_start(start)).filter(|next| *next <= self.end),
+        )
+        .map(move |start| DateRange::new(start, next_start(&start).add_days(-1).min(self.end)))
     }
 }
 
@@ -42,22 +251,165 @@ impl DateRange {
 mod tests {
     use super::*;
 
+    fn date(text: &str) -> CalendarDate {
+        text.parse().unwrap()
+    }
+
     #[test]
     fn should_create_date_range() {
-        let range = DateRange::new("2024-01-01".to_string(), "2024-01-31".to_string());
+        let range = DateRange::parse("2024-01-01", "2024-01-31").unwrap();
 
-        assert_eq!(range.start(), "2024-01-01");
-        assert_eq!(range.end(), "2024-01-31");
+        assert_eq!(range.start(), date("2024-01-01"));
+        assert_eq!(range.end(), date("2024-01-31"));
     }
 
     #[test]
     fn should_check_if_date_is_within_range() {
-        let range = DateRange::new("2024-01-01".to_string(), "2024-01-31".to_string());
+        let range = DateRange::parse("2024-01-01", "2024-01-31").unwrap();
+
+        assert!(range.contains(date("2024-01-15")));
+        assert!(range.contains(date("2024-01-01"))); // Inclusive start
+        assert!(range.contains(date("2024-01-31"))); // Inclusive end
+        assert!(!range.contains(date("2023-12-31"))); // Before range
+        assert!(!range.contains(date("2024-02-01"))); // After range
+    }
+
+    #[test]
+    fn should_reject_malformed_dates() {
+        assert_eq!(
+            DateRange::parse("2024-01-01", "Jan 31"),
+            Err(YnabError::invalid_date("Jan 31"))
+        );
+        assert_eq!(
+            DateRange::parse("2024-02-30", "2024-03-01"),
+            Err(YnabError::invalid_date("2024-02-30"))
+        );
+    }
+
+    #[test]
+    fn should_iterate_over_days() {
+        let range = DateRange::parse("2024-02-28", "2024-03-01").unwrap();
+
+        let days: Vec<String> = range.days().map(|day| day.to_string()).collect();
+
+        assert_eq!(days, vec!["2024-02-28", "2024-02-29", "2024-03-01"]);
+        assert_eq!(
+            DateRange::parse("2024-03-01", "2024-02-01")
+                .unwrap()
+                .days()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn should_split_into_weeks_starting_on_monday() {
+        // 2024-03-06 is a Wednesday
+        let range = DateRange::parse("2024-03-06", "2024-03-18").unwrap();
+
+        let weeks: Vec<(String, String)> = range
+            .weeks()
+            .map(|week| (week.start().to_string(), week.end().to_string()))
+            .collect();
+
+        assert_eq!(
+            weeks,
+            vec![
+                ("2024-03-06".to_string(), "2024-03-10".to_string()),
+                ("2024-03-11".to_string(), "2024-03-17".to_string()),
+                ("2024-03-18".to_string(), "2024-03-18".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_cover_calendar_month_of_date() {
+        let range = DateRange::month_of(date("2023-02-14"));
+
+        assert_eq!(range.start(), date("2023-02-01"));
+        assert_eq!(range.end(), date("2023-02-28"));
+        assert_eq!(range.months().count(), 1);
+    }
+
+    fn resolved(expression: &str) -> (String, String) {
+        // 2024-08-14 is a Wednesday
+        let range = DateRange::resolve(expression, date("2024-08-14")).unwrap();
+        (range.start().to_string(), range.end().to_string())
+    }
+
+    fn span(start: &str, end: &str) -> (String, String) {
+        (start.to_string(), end.to_string())
+    }
+
+    #[test]
+    fn should_resolve_calendar_periods_relative_to_today() {
+        assert_eq!(resolved("today"), span("2024-08-14", "2024-08-14"));
+        assert_eq!(resolved("yesterday"), span("2024-08-13", "2024-08-13"));
+        assert_eq!(resolved("this_week"), span("2024-08-12", "2024-08-18"));
+        assert_eq!(resolved("last_week"), span("2024-08-05", "2024-08-11"));
+        assert_eq!(resolved("this_month"), span("2024-08-01", "2024-08-31"));
+        assert_eq!(resolved("last_month"), span("2024-07-01", "2024-07-31"));
+        assert_eq!(resolved("this_quarter"), span("2024-07-01", "2024-09-30"));
+        assert_eq!(resolved("last_quarter"), span("2024-04-01", "2024-06-30"));
+        assert_eq!(resolved("this_year"), span("2024-01-01", "2024-12-31"));
+        assert_eq!(resolved("last_year"), span("2023-01-01", "2023-12-31"));
+        assert_eq!(resolved("mtd"), span("2024-08-01", "2024-08-14"));
+        assert_eq!(resolved("ytd"), span("2024-01-01", "2024-08-14"));
+    }
+
+    #[test]
+    fn should_resolve_rolling_windows_ending_or_starting_today() {
+        assert_eq!(resolved("last_90_days"), span("2024-05-17", "2024-08-14"));
+        assert_eq!(resolved("last_1_day"), span("2024-08-14", "2024-08-14"));
+        assert_eq!(resolved("last_2_weeks"), span("2024-08-01", "2024-08-14"));
+        assert_eq!(resolved("last_6_months"), span("2024-02-15", "2024-08-14"));
+        assert_eq!(resolved("next_30_days"), span("2024-08-14", "2024-09-12"));
+        assert_eq!(resolved("next_1_month"), span("2024-08-14", "2024-09-13"));
+    }
+
+    #[test]
+    fn should_resolve_fixed_years_quarters_months_and_days() {
+        assert_eq!(resolved("2023"), span("2023-01-01", "2023-12-31"));
+        assert_eq!(resolved("2024-Q1"), span("2024-01-01", "2024-03-31"));
+        assert_eq!(resolved("2023-q4"), span("2023-10-01", "2023-12-31"));
+        assert_eq!(resolved("2024-02"), span("2024-02-01", "2024-02-29"));
+        assert_eq!(resolved("2024-02-10"), span("2024-02-10", "2024-02-10"));
+    }
+
+    #[test]
+    fn should_accept_natural_spelling_of_expressions() {
+        assert_eq!(resolved("Last Quarter"), resolved("last_quarter"));
+        assert_eq!(resolved("  last 90 days "), resolved("last_90_days"));
+        assert_eq!(resolved("YTD"), resolved("ytd"));
+    }
+
+    #[test]
+    fn should_reject_unknown_expressions() {
+        let today = date("2024-08-14");
 
-        assert!(range.contains("2024-01-15"));
-        assert!(range.contains("2024-01-01")); // Inclusive start
-        assert!(range.contains("2024-01-31")); // Inclusive end
-        assert!(!range.contains("2023-12-31")); // Before range
-        assert!(!range.contains("2024-02-01")); // After range
+        for expression in [
+            "someday",
+            "last_0_days",
+            "last_x_days",
+            "last_3_fortnights",
+            "previous_3_days",
+            "2024-Q5",
+            "2024-13",
+            "abcéq1",
+            "",
+            "next_9223372036854775807_days",
+            "last_2000000000000000000_weeks",
+            "last_9223372036854775807_months",
+            "next_99999999999999999999_days",
+            "last_3652500_days",
+            "next_96000_months",
+        ] {
+            assert_eq!(
+                DateRange::resolve(expression, today),
+                Err(YnabError::invalid_date(expression)),
+                "{}",
+                expression
+            );
+        }
     }
 }
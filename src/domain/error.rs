@@ -23,6 +23,10 @@ pub enum YnabError {
     #[error("Transaction not found: {0}")]
     TransactionNotFound(String),
 
+    /// MCP resource not found.
+    #[error("Resource not found: {0}")]
+    ResourceNotFound(String),
+
     /// Invalid money amount.
     #[error("Invalid money amount: {0}")]
     InvalidAmount(String),
@@ -31,6 +35,37 @@ pub enum YnabError {
     #[error("Invalid date format: {0}")]
     InvalidDate(String),
 
+    /// A tool argument does not match the tool's input schema.
+    #[error("Invalid argument `{field}`: {message}")]
+    InvalidArgument { field: String, message: String },
+
+    /// The YNAB API rejected the access token (HTTP 401).
+    #[error("YNAB access token was rejected ({id}): {detail}")]
+    Unauthorized { id: String, detail: String },
+
+    /// The access token may not perform the request (HTTP 403).
+    #[error("YNAB request is not permitted ({id}): {detail}")]
+    Forbidden { id: String, detail: String },
+
+    /// The requested YNAB resource does not exist (HTTP 404).
+    #[error("YNAB resource not found ({id}): {detail}")]
+    NotFound { id: String, detail: String },
+
+    /// The request conflicts with existing YNAB data (HTTP 409).
+    #[error("YNAB request conflicts with existing data ({id}): {detail}")]
+    Conflict { id: String, detail: String },
+
+    /// The YNAB API rate limit was reached (HTTP 429, or the local quota is empty).
+    #[error(
+        "YNAB rate limit reached: {remaining} requests remaining, retry in {retry_after_secs} seconds"
+    )]
+    RateLimited {
+        remaining: u32,
+        retry_after_secs: u64,
+        id: String,
+        detail: String,
+    },
+
     /// HTTP API request failed.
     #[error("API request failed: {0}")]
     HttpApiError(#[from] reqwest::Error),
@@ -52,8 +87,78 @@ impl PartialEq for YnabError {
             (YnabError::AccountNotFound(a), YnabError::AccountNotFound(b)) => a == b,
             (YnabError::PayeeNotFound(a), YnabError::PayeeNotFound(b)) => a == b,
             (YnabError::TransactionNotFound(a), YnabError::TransactionNotFound(b)) => a == b,
+            (YnabError::ResourceNotFound(a), YnabError::ResourceNotFound(b)) => a == b,
             (YnabError::InvalidAmount(a), YnabError::InvalidAmount(b)) => a == b,
             (YnabError::InvalidDate(a), YnabError::InvalidDate(b)) => a == b,
+            (
+                YnabError::InvalidArgument {
+                    field: field_a,
+                    message: message_a,
+                },
+                YnabError::InvalidArgument {
+                    field: field_b,
+                    message: message_b,
+                },
+            ) => field_a == field_b && message_a == message_b,
+            (
+                YnabError::Unauthorized {
+                    id: id_a,
+                    detail: detail_a,
+                },
+                YnabError::Unauthorized {
+                    id: id_b,
+                    detail: detail_b,
+                },
+            )
+            | (
+                YnabError::Forbidden {
+                    id: id_a,
+                    detail: detail_a,
+                },
+                YnabError::Forbidden {
+                    id: id_b,
+                    detail: detail_b,
+                },
+            )
+            | (
+                YnabError::NotFound {
+                    id: id_a,
+                    detail: detail_a,
+                },
+                YnabError::NotFound {
+                    id: id_b,
+                    detail: detail_b,
+                },
+            )
+            | (
+                YnabError::Conflict {
+                    id: id_a,
+                    detail: detail_a,
+                },
+                YnabError::Conflict {
+                    id: id_b,
+                    detail: detail_b,
+                },
+            ) => id_a == id_b && detail_a == detail_b,
+            (
+                YnabError::RateLimited {
+                    remaining: remaining_a,
+                    retry_after_secs: retry_a,
+                    id: id_a,
+                    detail: detail_a,
+                },
+                YnabError::RateLimited {
+                    remaining: remaining_b,
+                    retry_after_secs: retry_b,
+                    id: id_b,
+                    detail: detail_b,
+                },
+            ) => {
+                remaining_a == remaining_b
+                    && retry_a == retry_b
+                    && id_a == id_b
+                    && detail_a == detail_b
+            }
             (YnabError::ApiError(a), YnabError::ApiError(b)) => a == b,
             // HttpApiError and IoError cannot be compared due to external error types
             (YnabError::HttpApiError(_), YnabError::HttpApiError(_)) => false,
@@ -89,6 +194,11 @@ impl YnabError {
         Self::TransactionNotFound(id.into())
     }
 
+    /// Creates a new ResourceNotFound error.
+    pub fn resource_not_found<S: Into<String>>(uri: S) -> Self {
+        Self::ResourceNotFound(uri.into())
+    }
+
     /// Creates a new InvalidAmount error.
     pub fn invalid_amount<S: Into<String>>(message: S) -> Self {
         Self::InvalidAmount(message.into())
@@ -99,10 +209,149 @@ impl YnabError {
         Self::InvalidDate(date.into())
     }
 
+    /// Creates a new InvalidArgument error for the named field.
+    pub fn invalid_argument<F: Into<String>, M: Into<String>>(field: F, message: M) -> Self {
+        Self::InvalidArgument {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a new Unauthorized error from a YNAB error id and detail.
+    pub fn unauthorized<I: Into<String>, D: Into<String>>(id: I, detail: D) -> Self {
+        Self::Unauthorized {
+            id: id.into(),
+            detail: detail.into(),
+        }
+    }
+
+    /// Creates a new Forbidden error from a YNAB error id and detail.
+    pub fn forbidden<I: Into<String>, D: Into<String>>(id: I, detail: D) -> Self {
+        Self::Forbidden {
+            id: id.into(),
+            detail: detail.into(),
+        }
+    }
+
+    /// Creates a new NotFound error from a YNAB error id and detail.
+    pub fn not_found<I: Into<String>, D: Into<String>>(id: I, detail: D) -> Self {
+        Self::NotFound {
+            id: id.into(),
+            detail: detail.into(),
+        }
+    }
+
+    /// Creates a new Conflict error from a YNAB error id and detail.
+    pub fn conflict<I: Into<String>, D: Into<String>>(id: I, detail: D) -> Self {
+        Self::Conflict {
+            id: id.into(),
+            detail: detail.into(),
+        }
+    }
+
+    /// Creates a new RateLimited error, rounding the wait up to whole seconds.
+    ///
+    /// The error carries YNAB's own `429` id and detail; use
+    /// [`YnabError::with_api_detail`] to replace them with those of a response.
+    pub fn rate_limited(remaining: u32, retry_after: std::time::Duration) -> Self {
+        let retry_after_secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+        Self::RateLimited {
+            remaining,
+            retry_after_secs,
+            id: "429".to_string(),
+            detail: "Too many requests".to_string(),
+        }
+    }
+
+    /// Replaces the YNAB error id and detail of an error that carries them.
+    ///
+    /// Other variants are returned unchanged.
+    pub fn with_api_detail<I: Into<String>, D: Into<String>>(mut self, id: I, detail: D) -> Self {
+        if let Self::Unauthorized {
+            id: error_id,
+            detail: error_detail,
+        }
+        | Self::Forbidden {
+            id: error_id,
+            detail: error_detail,
+        }
+        | Self::NotFound {
+            id: error_id,
+            detail: error_detail,
+        }
+        | Self::Conflict {
+            id: error_id,
+            detail: error_detail,
+        }
+        | Self::RateLimited {
+            id: error_id,
+            detail: error_detail,
+            ..
+        } = &mut self
+        {
+            *error_id = id.into();
+            *error_detail = detail.into();
+        }
+        self
+    }
+
     /// Creates a new ApiError.
     pub fn api_error<S: Into<String>>(message: S) -> Self {
         Self::ApiError(message.into())
     }
+
+    /// Returns a stable snake_case name of the error variant.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::YnabError;
+    ///
+    /// assert_eq!(YnabError::category_not_found("c-1").kind(), "category_not_found");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBudgetId(_) => "invalid_budget_id",
+            Self::CategoryNotFound(_) => "category_not_found",
+            Self::AccountNotFound(_) => "account_not_found",
+            Self::PayeeNotFound(_) => "payee_not_found",
+            Self::TransactionNotFound(_) => "transaction_not_found",
+            Self::ResourceNotFound(_) => "resource_not_found",
+            Self::InvalidAmount(_) => "invalid_amount",
+            Self::InvalidDate(_) => "invalid_date",
+            Self::InvalidArgument { .. } => "invalid_argument",
+            Self::Unauthorized { .. } => "unauthorized",
+            Self::Forbidden { .. } => "forbidden",
+            Self::NotFound { .. } => "not_found",
+            Self::Conflict { .. } => "conflict",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::HttpApiError(_) => "http_error",
+            Self::IoError(_) => "io_error",
+            Self::ApiError(_) => "api_error",
+        }
+    }
+
+    /// Returns the name of the argument or field the error is about, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::YnabError;
+    ///
+    /// assert_eq!(YnabError::invalid_argument("months", "must be at least 1").field(), Some("months"));
+    /// assert_eq!(YnabError::invalid_budget_id("b-1").field(), Some("budget_id"));
+    /// assert_eq!(YnabError::api_error("timeout").field(), None);
+    /// ```
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::InvalidArgument { field, .. } => Some(field),
+            Self::InvalidBudgetId(_) => Some("budget_id"),
+            Self::CategoryNotFound(_) => Some("category_id"),
+            Self::AccountNotFound(_) => Some("account_id"),
+            Self::PayeeNotFound(_) => Some("payee_id"),
+            Self::TransactionNotFound(_) => Some("transaction_id"),
+            Self::ResourceNotFound(_) => Some("uri"),
+            _ => None,
+        }
+    }
 }
 
 /// Result type for YNAB operations.
@@ -167,6 +416,23 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid date format: 2024-13-45");
     }
 
+    #[test]
+    fn should_create_invalid_argument_error() {
+        let error = YnabError::invalid_argument("months", "must be at least 1");
+
+        assert_eq!(
+            error,
+            YnabError::InvalidArgument {
+                field: "months".to_string(),
+                message: "must be at least 1".to_string()
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument `months`: must be at least 1"
+        );
+    }
+
     #[test]
     fn should_create_api_error() {
         let error = YnabError::api_error("Connection timeout");
@@ -192,6 +458,75 @@ mod tests {
         assert_eq!(error.to_string(), "Transaction not found: txn-123");
     }
 
+    #[test]
+    fn should_create_resource_not_found_error() {
+        let error = YnabError::resource_not_found("ynab://payees");
+
+        assert_eq!(
+            error,
+            YnabError::ResourceNotFound("ynab://payees".to_string())
+        );
+        assert_eq!(error.to_string(), "Resource not found: ynab://payees");
+    }
+
+    #[test]
+    fn should_create_rate_limited_error() {
+        let error = YnabError::rate_limited(3, std::time::Duration::from_millis(17_200));
+
+        assert_eq!(
+            error,
+            YnabError::RateLimited {
+                remaining: 3,
+                retry_after_secs: 18,
+                id: "429".to_string(),
+                detail: "Too many requests".to_string()
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "YNAB rate limit reached: 3 requests remaining, retry in 18 seconds"
+        );
+    }
+
+    #[test]
+    fn should_create_api_status_errors_with_id_and_detail() {
+        let unauthorized = YnabError::unauthorized("401", "Unauthorized");
+        let not_found = YnabError::not_found("404.1", "The requested URI does not exist");
+
+        assert_eq!(
+            unauthorized,
+            YnabError::Unauthorized {
+                id: "401".to_string(),
+                detail: "Unauthorized".to_string()
+            }
+        );
+        assert_ne!(unauthorized, YnabError::forbidden("401", "Unauthorized"));
+        assert_eq!(
+            not_found.to_string(),
+            "YNAB resource not found (404.1): The requested URI does not exist"
+        );
+        assert_eq!(
+            YnabError::conflict("409", "Duplicate import id").to_string(),
+            "YNAB request conflicts with existing data (409): Duplicate import id"
+        );
+    }
+
+    #[test]
+    fn should_replace_api_detail_of_rate_limited_error() {
+        let error = YnabError::rate_limited(0, std::time::Duration::from_secs(60))
+            .with_api_detail("429", "Too many requests (200/hour)");
+
+        assert!(matches!(
+            error,
+            YnabError::RateLimited { retry_after_secs: 60, ref detail, .. }
+                if detail == "Too many requests (200/hour)"
+        ));
+        assert_eq!(
+            YnabError::api_error("boom").with_api_detail("500", "ignored"),
+            YnabError::api_error("boom")
+        );
+    }
+
     #[test]
     fn should_handle_io_error_conversion() {
         use std::io::{Error, ErrorKind};
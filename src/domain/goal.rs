@@ -0,0 +1,282 @@
+//! Category goals and progress towards them.
+
+use crate::domain::{CalendarDate, Category, Money};
+
+/// Kind of goal set on a YNAB category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalType {
+    /// Save up to a target balance, with no deadline.
+    TargetBalance,
+    /// Save up to a target balance by a target month.
+    TargetBalanceByDate,
+    /// Assign a fixed amount every month.
+    MonthlyFunding,
+    /// Plan spending of a recurring amount.
+    Need,
+    /// Pay a fixed amount towards a debt account every month.
+    Debt,
+}
+
+impl GoalType {
+    /// Parses the `goal_type` code used by the YNAB API.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::GoalType;
+    ///
+    /// assert_eq!(GoalType::from_api_name("TBD"), Some(GoalType::TargetBalanceByDate));
+    /// assert_eq!(GoalType::from_api_name("XYZ"), None);
+    /// ```
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        match name {
+            "TB" => Some(Self::TargetBalance),
+            "TBD" => Some(Self::TargetBalanceByDate),
+            "MF" => Some(Self::MonthlyFunding),
+            "NEED" => Some(Self::Need),
+            "DEBT" => Some(Self::Debt),
+            _ => None,
+        }
+    }
+
+    /// Returns the `goal_type` code used by the YNAB API.
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            Self::TargetBalance => "TB",
+            Self::TargetBalanceByDate => "TBD",
+            Self::MonthlyFunding => "MF",
+            Self::Need => "NEED",
+            Self::Debt => "DEBT",
+        }
+    }
+
+    /// Returns whether the goal asks for the same amount every month rather
+    /// than building towards an overall target.
+    pub fn is_recurring(&self) -> bool {
+        matches!(self, Self::MonthlyFunding | Self::Need | Self::Debt)
+    }
+}
+
+/// A goal set on a category, as reported for one budget month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryGoal {
+    pub goal_type: GoalType,
+    /// Target amount; per month for recurring goals.
+    pub target: Money,
+    /// First day of the month the target should be reached by.
+    pub target_month: Option<CalendarDate>,
+    /// Percentage of the target already funded, from 0 to 100.
+    pub percentage_complete: Option<i64>,
+    /// Amount still to assign this month to stay on track.
+    pub under_funded: Option<Money>,
+}
+
+/// Progress of one category towards its goal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalProgress {
+    pub category_id: String,
+    pub name: String,
+    pub goal: CategoryGoal,
+    /// Amount still needed to reach an overall target; zero for recurring goals.
+    pub remaining: Money,
+    /// Average amount assigned per month over the recent months.
+    pub funding_pace: Money,
+    /// First day of the month the target is reached at the current pace.
+    ///
+    /// `None` for recurring goals and for targets that receive no funding.
+    pub projected_completion: Option<CalendarDate>,
+}
+
+impl GoalProgress {
+    /// Calculates progress for a category with a goal.
+    ///
+    /// The funding pace is the average of `recent_budgeted`, the amounts assigned
+    /// to the category in recent months. The remaining amount of an overall
+    /// target is the target minus the category balance, and the projected
+    /// completion is the month it is covered at that pace, counting from the
+    /// month after `current_month`. Returns `None` for categories without a goal.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{CalendarDate, Category, CategoryGoal, GoalProgress, GoalType, Money};
+    ///
+    /// let vacation = Category::new("vacation".to_string(), "Vacation".to_string())
+    ///     .with_month_amounts(Money::from_milliunits(100000), Money::from_milliunits(0), Money::from_milliunits(400000))
+    ///     .with_goal(CategoryGoal {
+    ///         goal_type: GoalType::TargetBalance,
+    ///         target: Money::from_milliunits(1000000),
+    ///         target_month: None,
+    ///         percentage_complete: Some(40),
+    ///         under_funded: None,
+    ///     });
+    /// let recent = [Money::from_milliunits(100000), Money::from_milliunits(200000)];
+    ///
+    /// let march = CalendarDate::parse("2024-03-01").unwrap();
+    /// let progress = GoalProgress::calculate(&vacation, &recent, march).unwrap();
+    ///
+    /// assert_eq!(progress.remaining, Money::from_milliunits(600000));
+    /// assert_eq!(progress.funding_pace, Money::from_milliunits(150000));
+    /// assert_eq!(progress.projected_completion, Some(march.add_months(4)));
+    /// ```
+    pub fn calculate(
+        category: &Category,
+        recent_budgeted: &[Money],
+        current_month: CalendarDate,
+    ) -> Option<Self> {
+        let goal = category.goal()?.clone();
+        let funding_pace = if recent_budgeted.is_empty() {
+            Money::from_milliunits(0)
+        } else {
+            Money::from_milliunits(
+                recent_budgeted
+                    .iter()
+                    .copied()
+                    .sum::<Money>()
+                    .as_milliunits()
+                    / recent_budgeted.len() as i64,
+            )
+        };
+
+        let remaining = if goal.goal_type.is_recurring() {
+            0
+        } else {
+            (goal.target.as_milliunits() - category.balance().as_milliunits()).max(0)
+        };
+        let pace = funding_pace.as_milliunits();
+        let projected_completion = if goal.goal_type.is_recurring() {
+            None
+        } else if remaining == 0 {
+            Some(current_month.first_of_month())
+        } else if pace > 0 {
+            let months_needed = (remaining + pace - 1) / pace;
+            Some(current_month.first_of_month().add_months(months_needed))
+        } else {
+            None
+        };
+
+        Some(Self {
+            category_id: category.id().to_string(),
+            name: category.name().to_string(),
+            goal,
+            remaining: Money::from_milliunits(remaining),
+            funding_pace,
+            projected_completion,
+        })
+    }
+
+    /// Returns whether the projected completion falls on or before the target month.
+    ///
+    /// `None` when the goal has no target month or no projected completion.
+    pub fn on_track(&self) -> Option<bool> {
+        let target = self.goal.target_month?.first_of_month();
+        let projected = self.projected_completion?;
+        Some(projected <= target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category_with_goal(goal_type: GoalType, target: i64, balance: i64) -> Category {
+        Category::new("cat-1".to_string(), "Goal".to_string())
+            .with_month_amounts(
+                Money::from_milliunits(0),
+                Money::from_milliunits(0),
+                Money::from_milliunits(balance),
+            )
+            .with_goal(CategoryGoal {
+                goal_type,
+                target: Money::from_milliunits(target),
+                target_month: "2024-06-01".parse().ok(),
+                percentage_complete: Some(50),
+                under_funded: Some(Money::from_milliunits(25_000)),
+            })
+    }
+
+    fn march() -> CalendarDate {
+        "2024-03-01".parse().unwrap()
+    }
+
+    fn pace(amounts: &[i64]) -> Vec<Money> {
+        amounts
+            .iter()
+            .copied()
+            .map(Money::from_milliunits)
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_every_api_goal_type() {
+        for name in ["TB", "TBD", "MF", "NEED", "DEBT"] {
+            assert_eq!(GoalType::from_api_name(name).unwrap().api_name(), name);
+        }
+        assert!(GoalType::MonthlyFunding.is_recurring());
+        assert!(GoalType::Debt.is_recurring());
+        assert!(!GoalType::TargetBalanceByDate.is_recurring());
+    }
+
+    #[test]
+    fn should_project_completion_from_funding_pace() {
+        let category = category_with_goal(GoalType::TargetBalanceByDate, 500_000, 200_000);
+
+        let progress =
+            GoalProgress::calculate(&category, &pace(&[100_000, 50_000]), march()).unwrap();
+
+        assert_eq!(progress.remaining, Money::from_milliunits(300_000));
+        assert_eq!(progress.funding_pace, Money::from_milliunits(75_000));
+        assert_eq!(progress.projected_completion, "2024-07-01".parse().ok());
+        assert_eq!(progress.on_track(), Some(false));
+    }
+
+    #[test]
+    fn should_not_project_completion_without_funding() {
+        let category = category_with_goal(GoalType::TargetBalance, 500_000, 200_000);
+
+        let progress = GoalProgress::calculate(&category, &pace(&[0, 0]), march()).unwrap();
+
+        assert_eq!(progress.projected_completion, None);
+        assert_eq!(progress.on_track(), None);
+    }
+
+    #[test]
+    fn should_complete_reached_targets_this_month() {
+        let category = category_with_goal(GoalType::TargetBalance, 500_000, 650_000);
+
+        let progress = GoalProgress::calculate(&category, &[], march()).unwrap();
+
+        assert_eq!(progress.remaining, Money::from_milliunits(0));
+        assert_eq!(progress.projected_completion, Some(march()));
+        assert_eq!(progress.on_track(), Some(true));
+    }
+
+    #[test]
+    fn should_leave_recurring_goals_without_projection() {
+        let category = category_with_goal(GoalType::MonthlyFunding, 100_000, 0);
+
+        let progress = GoalProgress::calculate(&category, &pace(&[100_000]), march()).unwrap();
+
+        assert_eq!(progress.remaining, Money::from_milliunits(0));
+        assert_eq!(progress.projected_completion, None);
+        assert_eq!(
+            progress.goal.under_funded,
+            Some(Money::from_milliunits(25_000))
+        );
+    }
+
+    #[test]
+    fn should_treat_debt_goals_as_monthly_payments() {
+        let category = category_with_goal(GoalType::Debt, 150_000, -2_000_000);
+
+        let progress = GoalProgress::calculate(&category, &pace(&[150_000]), march()).unwrap();
+
+        assert_eq!(progress.remaining, Money::from_milliunits(0));
+        assert_eq!(progress.projected_completion, None);
+    }
+
+    #[test]
+    fn should_skip_categories_without_goal() {
+        let category = Category::new("cat-1".to_string(), "Plain".to_string());
+
+        assert_eq!(GoalProgress::calculate(&category, &[], march()), None);
+    }
+}
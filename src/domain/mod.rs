@@ -5,24 +5,44 @@
 
 pub mod account;
 pub mod budget;
+pub mod budget_flow;
+pub mod budget_month;
+pub mod calendar_date;
+pub mod cash_flow;
 pub mod category;
 pub mod category_group;
 pub mod date_range;
 pub mod error;
+pub mod goal;
 pub mod money;
+pub mod net_worth;
 pub mod payee;
+pub mod payee_spending;
+pub mod scheduled_transaction;
+pub mod spending_trend;
 pub mod transaction;
+pub mod transaction_filter;
 pub mod transaction_query;
 pub mod transaction_service;
 
 pub use account::*;
 pub use budget::*;
+pub use budget_flow::*;
+pub use budget_month::*;
+pub use calendar_date::*;
+pub use cash_flow::*;
 pub use category::*;
 pub use category_group::*;
 pub use date_range::*;
 pub use error::*;
+pub use goal::*;
 pub use money::*;
+pub use net_worth::*;
 pub use payee::*;
+pub use payee_spending::*;
+pub use scheduled_transaction::*;
+pub use spending_trend::*;
 pub use transaction::*;
+pub use transaction_filter::*;
 pub use transaction_query::*;
 pub use transaction_service::*;
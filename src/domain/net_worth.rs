@@ -0,0 +1,329 @@
+//! Net worth across all accounts, with monthly history.
+
+use crate::domain::{Account, CalendarDate, Money, Transaction};
+use std::collections::HashMap;
+
+/// One account's share of net worth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountContribution {
+    pub account_id: String,
+    pub name: String,
+    pub on_budget: bool,
+    pub is_liability: bool,
+    /// Signed balance; debts are negative and reduce net worth.
+    pub balance: Money,
+}
+
+/// Net worth at the end of one month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetWorthSnapshot {
+    /// Month in `YYYY-MM` format.
+    pub month: String,
+    pub assets: Money,
+    /// Amount owed on liability accounts, as a positive value.
+    pub liabilities: Money,
+    pub net_worth: Money,
+    /// Change from the previous month, or `None` for the first month.
+    pub change: Option<Money>,
+}
+
+/// Current net worth, per-account contributions and monthly history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetWorthReport {
+    pub assets: Money,
+    /// Amount owed on liability accounts, as a positive value.
+    pub liabilities: Money,
+    pub net_worth: Money,
+    pub contributions: Vec<AccountContribution>,
+    /// Month-end snapshots, oldest first.
+    pub history: Vec<NetWorthSnapshot>,
+}
+
+impl NetWorthReport {
+    /// Calculates net worth from current balances of on-budget and tracking accounts.
+    ///
+    /// History is rebuilt by replaying transactions backwards from the current
+    /// balances: an account's balance at the end of a month is its current
+    /// balance minus every transaction dated after that month. The history
+    /// covers `months` months ending with the month of `today`, including
+    /// months without activity. Deleted transactions, transactions of unknown accounts and
+    /// transactions without a date are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Account, AccountType, CalendarDate, Money, NetWorthReport, Transaction};
+    ///
+    /// let checking = Account::new("acc-1".to_string(), "Checking".to_string(), AccountType::Checking, true)
+    ///     .with_balances(Money::from_milliunits(5000), Money::from_milliunits(5000), Money::from_milliunits(0));
+    /// let paycheck = Transaction::new_with_date(
+    ///     "txn-1".to_string(),
+    ///     "acc-1".to_string(),
+    ///     "income".to_string(),
+    ///     Money::from_milliunits(2000),
+    ///     "2024-02-15".parse().unwrap(),
+    /// );
+    ///
+    /// let today = CalendarDate::parse("2024-02-20").unwrap();
+    /// let report = NetWorthReport::calculate(&[checking], &[paycheck], today, 2);
+    ///
+    /// assert_eq!(report.net_worth, Money::from_milliunits(5000));
+    /// assert_eq!(report.history[0].month, "2024-01");
+    /// assert_eq!(report.history[0].net_worth, Money::from_milliunits(3000));
+    /// assert_eq!(report.history[1].change, Some(Money::from_milliunits(2000)));
+    /// ```
+    pub fn calculate(
+        accounts: &[Account],
+        transactions: &[Transaction],
+        today: CalendarDate,
+        months: usize,
+    ) -> Self {
+        let contributions: Vec<AccountContribution> = accounts
+            .iter()
+            .map(|account| AccountContribution {
+                account_id: account.id().to_string(),
+                name: account.name().to_string(),
+                on_budget: account.is_on_budget(),
+                is_liability: account.is_liability(),
+                balance: account.balance(),
+            })
+            .collect();
+        let (assets, liabilities) = totals(
+            contributions
+                .iter()
+                .map(|contribution| (contribution.is_liability, contribution.balance)),
+        );
+
+        Self {
+            assets,
+            liabilities,
+            net_worth: Money::from_milliunits(assets.as_milliunits() - liabilities.as_milliunits()),
+            history: history(accounts, transactions, today.first_of_month(), months),
+            contributions,
+        }
+    }
+}
+
+/// Sums asset balances and the amount owed on liabilities.
+fn totals(balances: impl Iterator<Item = (bool, Money)>) -> (Money, Money) {
+    let (mut assets, mut owed) = (0, 0);
+    for (is_liability, balance) in balances {
+        if is_liability {
+            owed -= balance.as_milliunits();
+        } else {
+            assets += balance.as_milliunits();
+        }
+    }
+    (Money::from_milliunits(assets), Money::from_milliunits(owed))
+}
+
+/// Rebuilds month-end net worth by undoing transactions from `latest` backwards.
+fn history(
+    accounts: &[Account],
+    transactions: &[Transaction],
+    latest: CalendarDate,
+    months: usize,
+) -> Vec<NetWorthSnapshot> {
+    let mut balances: HashMap<&str, i64> = accounts
+        .iter()
+        .map(|account| (account.id(), account.balance().as_milliunits()))
+        .collect();
+    let is_liability: HashMap<&str, bool> = accounts
+        .iter()
+        .map(|account| (account.id(), account.is_liability()))
+        .collect();
+
+    // Net amount per account and month
+    let mut activity: HashMap<(&str, CalendarDate), i64> = HashMap::new();
+    for transaction in transactions
+        .iter()
+        .filter(|transaction| !transaction.is_deleted())
+    {
+        let Some(month) = transaction.date().map(|date| date.first_of_month()) else {
+            continue;
+        };
+        if balances.contains_key(transaction.account_id()) {
+            *activity
+                .entry((transaction.account_id(), month))
+                .or_insert(0) += transaction.amount().as_milliunits();
+        }
+    }
+    // Future-dated transactions are already in the current balances
+    for ((id, month), amount) in &activity {
+        if let Some(balance) = balances.get_mut(id).filter(|_| *month > latest) {
+            *balance -= amount;
+        }
+    }
+
+    let mut snapshots = Vec::new();
+    let mut month = latest;
+    for _ in 0..months {
+        let (assets, liabilities) = totals(
+            balances
+                .iter()
+                .map(|(id, balance)| (is_liability[id], Money::from_milliunits(*balance))),
+        );
+        snapshots.push(NetWorthSnapshot {
+            month: month.year_month(),
+            assets,
+            liabilities,
+            net_worth: Money::from_milliunits(assets.as_milliunits() - liabilities.as_milliunits()),
+            change: None,
+        });
+
+        // Step back to the end of the previous month
+        for (id, balance) in balances.iter_mut() {
+            *balance -= activity.get(&(*id, month)).copied().unwrap_or(0);
+        }
+        month = month.add_months(-1);
+    }
+
+    snapshots.reverse();
+    for index in 1..snapshots.len() {
+        let previous = snapshots[index - 1].net_worth.as_milliunits();
+        let current = snapshots[index].net_worth.as_milliunits();
+        snapshots[index].change = Some(Money::from_milliunits(current - previous));
+    }
+    snapshots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AccountType;
+
+    fn account(id: &str, account_type: AccountType, on_budget: bool, balance: i64) -> Account {
+        Account::new(id.to_string(), id.to_string(), account_type, on_budget).with_balances(
+            Money::from_milliunits(balance),
+            Money::from_milliunits(balance),
+            Money::from_milliunits(0),
+        )
+    }
+
+    fn date(text: &str) -> CalendarDate {
+        text.parse().unwrap()
+    }
+
+    fn transaction(account_id: &str, amount: i64, date: &str) -> Transaction {
+        Transaction::new_with_date(
+            format!("txn-{}-{}", account_id, date),
+            account_id.to_string(),
+            "cat".to_string(),
+            Money::from_milliunits(amount),
+            date.parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn should_subtract_liabilities_from_assets_across_budget_and_tracking_accounts() {
+        let accounts = vec![
+            account("checking", AccountType::Checking, true, 300_000),
+            account("brokerage", AccountType::OtherAsset, false, 1_000_000),
+            account("visa", AccountType::CreditCard, true, -50_000),
+            account("mortgage", AccountType::Mortgage, false, -800_000),
+        ];
+
+        let report = NetWorthReport::calculate(&accounts, &[], date("2024-06-15"), 12);
+
+        assert_eq!(report.assets, Money::from_milliunits(1_300_000));
+        assert_eq!(report.liabilities, Money::from_milliunits(850_000));
+        assert_eq!(report.net_worth, Money::from_milliunits(450_000));
+        assert_eq!(report.contributions.len(), 4);
+        assert!(report.contributions[3].is_liability);
+        assert!(!report.contributions[3].on_budget);
+        assert_eq!(
+            report.contributions[3].balance,
+            Money::from_milliunits(-800_000)
+        );
+        assert_eq!(report.history.len(), 12);
+        assert!(
+            report
+                .history
+                .iter()
+                .all(|snapshot| snapshot.net_worth == report.net_worth)
+        );
+    }
+
+    #[test]
+    fn should_replay_transactions_backwards_into_monthly_history() {
+        let accounts = vec![
+            account("checking", AccountType::Checking, true, 10_000),
+            account("visa", AccountType::CreditCard, true, -3_000),
+        ];
+        let transactions = vec![
+            transaction("checking", 4_000, "2024-03-01"),
+            transaction("checking", -1_000, "2024-02-10"),
+            transaction("visa", -2_000, "2024-03-20"),
+            transaction("unknown", 99_999, "2024-03-05"),
+        ];
+
+        let report = NetWorthReport::calculate(&accounts, &transactions, date("2024-03-31"), 3);
+
+        let months: Vec<&str> = report.history.iter().map(|s| s.month.as_str()).collect();
+        assert_eq!(months, vec!["2024-01", "2024-02", "2024-03"]);
+        let net: Vec<i64> = report
+            .history
+            .iter()
+            .map(|s| s.net_worth.as_milliunits())
+            .collect();
+        // March: 10000 - 3000; February undoes +4000 and -2000; January undoes -1000
+        assert_eq!(net, vec![6_000, 5_000, 7_000]);
+        assert_eq!(report.history[0].change, None);
+        assert_eq!(
+            report.history[2].change,
+            Some(Money::from_milliunits(2_000))
+        );
+        assert_eq!(report.history[1].liabilities, Money::from_milliunits(1_000));
+    }
+
+    #[test]
+    fn should_step_back_across_year_boundary() {
+        let accounts = vec![account("checking", AccountType::Checking, true, 10_000)];
+        let transactions = vec![transaction("checking", 2_000, "2024-01-31")];
+
+        let report = NetWorthReport::calculate(&accounts, &transactions, date("2024-01-31"), 2);
+
+        let months: Vec<&str> = report.history.iter().map(|s| s.month.as_str()).collect();
+        assert_eq!(months, vec!["2023-12", "2024-01"]);
+        assert_eq!(report.history[0].net_worth, Money::from_milliunits(8_000));
+    }
+
+    #[test]
+    fn should_not_replay_deleted_transactions() {
+        let accounts = vec![account("checking", AccountType::Checking, true, 10_000)];
+        let deleted = Transaction::builder()
+            .id("txn-deleted".to_string())
+            .account_id("checking".to_string())
+            .category_id("cat".to_string())
+            .amount(Money::from_milliunits(5_000))
+            .date("2024-02-10".parse().unwrap())
+            .deleted(true)
+            .build();
+        let transactions = vec![transaction("checking", 2_000, "2024-02-01"), deleted];
+
+        let report = NetWorthReport::calculate(&accounts, &transactions, date("2024-02-29"), 2);
+
+        assert_eq!(report.history[0].month, "2024-01");
+        assert_eq!(report.history[0].net_worth, Money::from_milliunits(8_000));
+    }
+
+    #[test]
+    fn should_end_history_with_the_current_month() {
+        let accounts = vec![account("checking", AccountType::Checking, true, 10_000)];
+        let transactions = vec![
+            transaction("checking", 2_000, "2024-03-10"),
+            transaction("checking", 1_000, "2024-06-02"),
+        ];
+
+        let report = NetWorthReport::calculate(&accounts, &transactions, date("2024-05-20"), 3);
+
+        let months: Vec<&str> = report.history.iter().map(|s| s.month.as_str()).collect();
+        assert_eq!(months, vec!["2024-03", "2024-04", "2024-05"]);
+        let net: Vec<i64> = report
+            .history
+            .iter()
+            .map(|s| s.net_worth.as_milliunits())
+            .collect();
+        // May leaves out the future-dated June deposit
+        assert_eq!(net, vec![9_000, 9_000, 9_000]);
+    }
+}
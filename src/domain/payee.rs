@@ -5,6 +5,8 @@
 pub struct Payee {
     id: String,
     name: String,
+    transfer_account_id: Option<String>,
+    deleted: bool,
 }
 
 impl Payee {
@@ -19,7 +21,36 @@ impl Payee {
     /// assert_eq!(payee.name(), "Whole Foods");
     /// ```
     pub fn new(id: String, name: String) -> Self {
-        Self { id, name }
+        Self {
+            id,
+            name,
+            transfer_account_id: None,
+            deleted: false,
+        }
+    }
+
+    /// Marks this payee as the transfer payee of an account.
+    ///
+    /// YNAB creates one transfer payee per account, named "Transfer : Account".
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::Payee;
+    ///
+    /// let payee = Payee::new("payee-1".to_string(), "Transfer : Savings".to_string())
+    ///     .with_transfer_account_id("acc-2".to_string());
+    /// assert!(payee.is_transfer());
+    /// assert_eq!(payee.transfer_account_id(), Some("acc-2"));
+    /// ```
+    pub fn with_transfer_account_id(mut self, transfer_account_id: String) -> Self {
+        self.transfer_account_id = Some(transfer_account_id);
+        self
+    }
+
+    /// Sets whether the payee has been deleted in YNAB.
+    pub fn with_deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
     }
 
     /// Returns the payee ID.
@@ -31,6 +62,21 @@ impl Payee {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Returns the account this payee transfers to, if it is a transfer payee.
+    pub fn transfer_account_id(&self) -> Option<&str> {
+        self.transfer_account_id.as_deref()
+    }
+
+    /// Returns whether this payee represents a transfer between accounts.
+    pub fn is_transfer(&self) -> bool {
+        self.transfer_account_id.is_some()
+    }
+
+    /// Returns whether the payee has been deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
 }
 
 #[cfg(test)]
@@ -63,4 +109,24 @@ mod tests {
         assert_eq!(payee1, payee2);
         assert_ne!(payee1, payee3);
     }
+
+    #[test]
+    fn should_default_to_active_non_transfer_payee() {
+        let payee = Payee::new("payee-1".to_string(), "Target".to_string());
+
+        assert!(!payee.is_transfer());
+        assert!(!payee.is_deleted());
+        assert_eq!(payee.transfer_account_id(), None);
+    }
+
+    #[test]
+    fn should_record_transfer_account_and_deleted_flag() {
+        let payee = Payee::new("payee-2".to_string(), "Transfer : Savings".to_string())
+            .with_transfer_account_id("acc-9".to_string())
+            .with_deleted(true);
+
+        assert!(payee.is_transfer());
+        assert_eq!(payee.transfer_account_id(), Some("acc-9"));
+        assert!(payee.is_deleted());
+    }
 }
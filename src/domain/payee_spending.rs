@@ -0,0 +1,291 @@
+//! Spending per payee, for ranking merchants.
+
+use crate::domain::{CalendarDate, DateRange, Money, Payee, Transaction};
+use std::collections::{HashMap, HashSet};
+
+/// Order in which [`PayeeSpending::rank`] returns payees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayeeRanking {
+    /// Largest total spend first.
+    Spend,
+    /// Most visits first.
+    Visits,
+    /// Largest average ticket first.
+    AverageTicket,
+}
+
+impl PayeeRanking {
+    /// Parses a ranking from its tool argument name: `spend`, `visits` or `average_ticket`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "spend" => Some(Self::Spend),
+            "visits" => Some(Self::Visits),
+            "average_ticket" => Some(Self::AverageTicket),
+            _ => None,
+        }
+    }
+}
+
+/// How much was spent at one payee.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayeeSpending {
+    pub payee_id: String,
+    pub name: String,
+    /// Total outflow, as a positive value.
+    pub total_spent: Money,
+    /// Number of outflow transactions.
+    pub visits: usize,
+    /// Total spent divided by visits, rounded towards zero.
+    pub average_ticket: Money,
+    /// Date of the earliest visit.
+    pub first_visit: Option<CalendarDate>,
+    /// Date of the latest visit.
+    pub last_visit: Option<CalendarDate>,
+}
+
+impl PayeeSpending {
+    /// Totals outflows per payee and returns the payees in ranking order.
+    ///
+    /// Only outflows count as visits; refunds and income are ignored. Transfer
+    /// payees are skipped because moving money between accounts is not spending
+    /// at a merchant. When `range` is given, transactions without a date or
+    /// outside the range are ignored. Split transactions count through their
+    /// lines, and lines of one split at the same payee make a single visit.
+    /// Ties are broken by total spend, then name.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Money, Payee, PayeeRanking, PayeeSpending, Transaction};
+    ///
+    /// let payees = vec![Payee::new("p-1".to_string(), "Grocer".to_string())];
+    /// let shop = |id: &str, amount: i64| {
+    ///     Transaction::builder()
+    ///         .id(id.to_string())
+    ///         .account_id("acc-1".to_string())
+    ///         .category_id("cat-1".to_string())
+    ///         .payee_id("p-1".to_string())
+    ///         .amount(Money::from_milliunits(amount))
+    ///         .build()
+    /// };
+    ///
+    /// let ranked = PayeeSpending::rank(&[shop("t-1", -30000), shop("t-2", -10000)], &payees, None, PayeeRanking::Spend);
+    ///
+    /// assert_eq!(ranked[0].name, "Grocer");
+    /// assert_eq!(ranked[0].visits, 2);
+    /// assert_eq!(ranked[0].average_ticket, Money::from_milliunits(20000));
+    /// ```
+    pub fn rank(
+        transactions: &[Transaction],
+        payees: &[Payee],
+        range: Option<&DateRange>,
+        ranking: PayeeRanking,
+    ) -> Vec<Self> {
+        let payees_by_id: HashMap<&str, &Payee> =
+            payees.iter().map(|payee| (payee.id(), payee)).collect();
+
+        let mut spending: HashMap<&str, Self> = HashMap::new();
+        let mut visited: HashSet<(&str, &str)> = HashSet::new();
+        for transaction in transactions.iter().flat_map(Transaction::lines) {
+            let amount = transaction.amount().as_milliunits();
+            let Some(payee_id) = transaction.payee_id() else {
+                continue;
+            };
+            if amount >= 0 || transaction.is_deleted() {
+                continue;
+            }
+            if let Some(range) = range
+                && !transaction.date().is_some_and(|date| range.contains(date))
+            {
+                continue;
+            }
+            let payee = payees_by_id.get(payee_id);
+            if payee.is_some_and(|payee| payee.is_transfer()) {
+                continue;
+            }
+
+            let entry = spending.entry(payee_id).or_insert_with(|| Self {
+                payee_id: payee_id.to_string(),
+                name: payee.map_or(payee_id, |payee| payee.name()).to_string(),
+                total_spent: Money::from_milliunits(0),
+                visits: 0,
+                average_ticket: Money::from_milliunits(0),
+                first_visit: None,
+                last_visit: None,
+            });
+            entry.total_spent = Money::from_milliunits(entry.total_spent.as_milliunits() - amount);
+            let visit = transaction.parent_id().unwrap_or(transaction.id());
+            if visited.insert((payee_id, visit)) {
+                entry.visits += 1;
+            }
+            if let Some(date) = transaction.date() {
+                entry.first_visit = Some(entry.first_visit.map_or(date, |first| first.min(date)));
+                entry.last_visit = Some(entry.last_visit.map_or(date, |last| last.max(date)));
+            }
+        }
+
+        let mut ranked: Vec<Self> = spending
+            .into_values()
+            .map(|mut entry| {
+                entry.average_ticket =
+                    Money::from_milliunits(entry.total_spent.as_milliunits() / entry.visits as i64);
+                entry
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            let primary = match ranking {
+                PayeeRanking::Spend => b.total_spent.cmp(&a.total_spent),
+                PayeeRanking::Visits => b.visits.cmp(&a.visits),
+                PayeeRanking::AverageTicket => b.average_ticket.cmp(&a.average_ticket),
+            };
+            primary
+                .then_with(|| b.total_spent.cmp(&a.total_spent))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visit(payee_id: &str, amount: i64, date: &str) -> Transaction {
+        Transaction::builder()
+            .id(format!("txn-{}-{}", payee_id, date))
+            .account_id("acc-1".to_string())
+            .category_id("cat-1".to_string())
+            .payee_id(payee_id.to_string())
+            .amount(Money::from_milliunits(amount))
+            .date(date.parse().unwrap())
+            .build()
+    }
+
+    fn payees() -> Vec<Payee> {
+        vec![
+            Payee::new("grocer".to_string(), "Grocer".to_string()),
+            Payee::new("cafe".to_string(), "Cafe".to_string()),
+            Payee::new("jeweller".to_string(), "Jeweller".to_string()),
+            Payee::new("savings".to_string(), "Transfer : Savings".to_string())
+                .with_transfer_account_id("acc-2".to_string()),
+        ]
+    }
+
+    fn transactions() -> Vec<Transaction> {
+        vec![
+            visit("grocer", -60_000, "2024-01-05"),
+            visit("grocer", -40_000, "2024-01-19"),
+            visit("cafe", -4_000, "2024-01-02"),
+            visit("cafe", -5_000, "2024-01-09"),
+            visit("cafe", -6_000, "2024-01-16"),
+            visit("jeweller", -90_000, "2024-01-20"),
+            visit("grocer", 10_000, "2024-01-21"),
+            visit("savings", -500_000, "2024-01-25"),
+        ]
+    }
+
+    fn names(ranked: &[PayeeSpending]) -> Vec<&str> {
+        ranked.iter().map(|entry| entry.name.as_str()).collect()
+    }
+
+    #[test]
+    fn should_rank_by_spend_visits_and_average_ticket() {
+        let by_spend = PayeeSpending::rank(&transactions(), &payees(), None, PayeeRanking::Spend);
+        let by_visits = PayeeSpending::rank(&transactions(), &payees(), None, PayeeRanking::Visits);
+        let by_average = PayeeSpending::rank(
+            &transactions(),
+            &payees(),
+            None,
+            PayeeRanking::AverageTicket,
+        );
+
+        assert_eq!(names(&by_spend), vec!["Grocer", "Jeweller", "Cafe"]);
+        assert_eq!(names(&by_visits), vec!["Cafe", "Grocer", "Jeweller"]);
+        assert_eq!(names(&by_average), vec!["Jeweller", "Grocer", "Cafe"]);
+    }
+
+    #[test]
+    fn should_total_outflows_and_ignore_refunds_and_transfers() {
+        let ranked = PayeeSpending::rank(&transactions(), &payees(), None, PayeeRanking::Spend);
+
+        let grocer = &ranked[0];
+        assert_eq!(grocer.total_spent, Money::from_milliunits(100_000));
+        assert_eq!(grocer.visits, 2);
+        assert_eq!(grocer.average_ticket, Money::from_milliunits(50_000));
+        assert_eq!(grocer.first_visit, "2024-01-05".parse().ok());
+        assert_eq!(grocer.last_visit, "2024-01-19".parse().ok());
+        assert!(ranked.iter().all(|entry| entry.payee_id != "savings"));
+    }
+
+    #[test]
+    fn should_only_count_visits_within_range() {
+        let range = DateRange::parse("2024-01-08", "2024-01-18").unwrap();
+
+        let ranked = PayeeSpending::rank(
+            &transactions(),
+            &payees(),
+            Some(&range),
+            PayeeRanking::Spend,
+        );
+
+        assert_eq!(names(&ranked), vec!["Cafe"]);
+        assert_eq!(ranked[0].visits, 2);
+    }
+
+    #[test]
+    fn should_count_split_lines_at_one_payee_as_one_visit() {
+        let line = |id: &str, payee_id: &str, amount: i64| {
+            Transaction::builder()
+                .id(id.to_string())
+                .account_id("acc-1".to_string())
+                .category_id("cat-1".to_string())
+                .payee_id(payee_id.to_string())
+                .amount(Money::from_milliunits(amount))
+                .parent_id("txn-split".to_string())
+                .build()
+        };
+        let split = Transaction::builder()
+            .id("txn-split".to_string())
+            .account_id("acc-1".to_string())
+            .category_id("split".to_string())
+            .payee_id("grocer".to_string())
+            .amount(Money::from_milliunits(-100_000))
+            .subtransactions(vec![
+                line("sub-1", "grocer", -30_000),
+                line("sub-2", "grocer", -20_000),
+                line("sub-3", "savings", -50_000),
+            ])
+            .build();
+
+        let ranked = PayeeSpending::rank(&[split], &payees(), None, PayeeRanking::Spend);
+
+        assert_eq!(names(&ranked), vec!["Grocer"]);
+        assert_eq!(ranked[0].total_spent, Money::from_milliunits(50_000));
+        assert_eq!(ranked[0].visits, 1);
+    }
+
+    #[test]
+    fn should_fall_back_to_payee_id_for_unknown_payees() {
+        let ranked = PayeeSpending::rank(
+            &[visit("unknown", -1_000, "2024-01-01")],
+            &[],
+            None,
+            PayeeRanking::Spend,
+        );
+
+        assert_eq!(ranked[0].name, "unknown");
+    }
+
+    #[test]
+    fn should_parse_ranking_names() {
+        assert_eq!(PayeeRanking::from_name("spend"), Some(PayeeRanking::Spend));
+        assert_eq!(
+            PayeeRanking::from_name("visits"),
+            Some(PayeeRanking::Visits)
+        );
+        assert_eq!(
+            PayeeRanking::from_name("average_ticket"),
+            Some(PayeeRanking::AverageTicket)
+        );
+        assert_eq!(PayeeRanking::from_name("frequency"), None);
+    }
+}
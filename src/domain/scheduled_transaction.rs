@@ -0,0 +1,543 @@
+//! Scheduled transaction domain entity and expansion of its recurrences.
+
+use crate::domain::{CalendarDate, DateRange, Money};
+
+/// How often a scheduled transaction repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Never,
+    Daily,
+    Weekly,
+    EveryOtherWeek,
+    TwiceAMonth,
+    Every4Weeks,
+    Monthly,
+    EveryOtherMonth,
+    Every3Months,
+    Every4Months,
+    TwiceAYear,
+    Yearly,
+    EveryOtherYear,
+}
+
+/// Step between two occurrences of a recurrence.
+enum Step {
+    Days(i64),
+    Months(i64),
+}
+
+impl Frequency {
+    /// Parses the `frequency` name used by the YNAB API.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::Frequency;
+    ///
+    /// assert_eq!(Frequency::from_api_name("everyOtherWeek"), Some(Frequency::EveryOtherWeek));
+    /// assert_eq!(Frequency::from_api_name("fortnightly"), None);
+    /// ```
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        match name {
+            "never" => Some(Self::Never),
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            "everyOtherWeek" => Some(Self::EveryOtherWeek),
+            "twiceAMonth" => Some(Self::TwiceAMonth),
+            "every4Weeks" => Some(Self::Every4Weeks),
+            "monthly" => Some(Self::Monthly),
+            "everyOtherMonth" => Some(Self::EveryOtherMonth),
+            "every3Months" => Some(Self::Every3Months),
+            "every4Months" => Some(Self::Every4Months),
+            "twiceAYear" => Some(Self::TwiceAYear),
+            "yearly" => Some(Self::Yearly),
+            "everyOtherYear" => Some(Self::EveryOtherYear),
+            _ => None,
+        }
+    }
+
+    /// Returns the `frequency` name used by the YNAB API.
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            Self::Never => "never",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::EveryOtherWeek => "everyOtherWeek",
+            Self::TwiceAMonth => "twiceAMonth",
+            Self::Every4Weeks => "every4Weeks",
+            Self::Monthly => "monthly",
+            Self::EveryOtherMonth => "everyOtherMonth",
+            Self::Every3Months => "every3Months",
+            Self::Every4Months => "every4Months",
+            Self::TwiceAYear => "twiceAYear",
+            Self::Yearly => "yearly",
+            Self::EveryOtherYear => "everyOtherYear",
+        }
+    }
+
+    /// Returns the step between occurrences, or `None` for one-off transactions.
+    ///
+    /// Twice a month is handled separately since its two occurrences are not
+    /// evenly spaced.
+    fn step(&self) -> Option<Step> {
+        match self {
+            Self::Never | Self::TwiceAMonth => None,
+            Self::Daily => Some(Step::Days(1)),
+            Self::Weekly => Some(Step::Days(7)),
+            Self::EveryOtherWeek => Some(Step::Days(14)),
+            Self::Every4Weeks => Some(Step::Days(28)),
+            Self::Monthly => Some(Step::Months(1)),
+            Self::EveryOtherMonth => Some(Step::Months(2)),
+            Self::Every3Months => Some(Step::Months(3)),
+            Self::Every4Months => Some(Step::Months(4)),
+            Self::TwiceAYear => Some(Step::Months(6)),
+            Self::Yearly => Some(Step::Months(12)),
+            Self::EveryOtherYear => Some(Step::Months(24)),
+        }
+    }
+}
+
+/// Represents a scheduled (recurring or future) transaction in YNAB.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledTransaction {
+    id: String,
+    account_id: String,
+    frequency: Frequency,
+    date_first: CalendarDate,
+    date_next: CalendarDate,
+    amount: Money,
+    payee_id: Option<String>,
+    payee_name: Option<String>,
+    category_id: Option<String>,
+    category_name: Option<String>,
+    transfer_account_id: Option<String>,
+    memo: Option<String>,
+    deleted: bool,
+}
+
+impl ScheduledTransaction {
+    /// Creates a new ScheduledTransaction.
+    ///
+    /// # Arguments
+    /// * `date_first` - Date of the first occurrence, which fixes the day of
+    ///   the month for monthly recurrences
+    /// * `date_next` - Date of the next occurrence
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{CalendarDate, Frequency, Money, ScheduledTransaction};
+    ///
+    /// let rent = ScheduledTransaction::new(
+    ///     "st-1".to_string(),
+    ///     "acc-1".to_string(),
+    ///     Frequency::Monthly,
+    ///     CalendarDate::parse("2024-01-01").unwrap(),
+    ///     CalendarDate::parse("2024-03-01").unwrap(),
+    ///     Money::from_milliunits(-1500000),
+    /// );
+    /// assert_eq!(rent.date_next().to_string(), "2024-03-01");
+    /// ```
+    pub fn new(
+        id: String,
+        account_id: String,
+        frequency: Frequency,
+        date_first: CalendarDate,
+        date_next: CalendarDate,
+        amount: Money,
+    ) -> Self {
+        Self {
+            id,
+            account_id,
+            frequency,
+            date_first,
+            date_next,
+            amount,
+            payee_id: None,
+            payee_name: None,
+            category_id: None,
+            category_name: None,
+            transfer_account_id: None,
+            memo: None,
+            deleted: false,
+        }
+    }
+
+    /// Sets the payee.
+    pub fn with_payee(mut self, payee_id: String, payee_name: Option<String>) -> Self {
+        self.payee_id = Some(payee_id);
+        self.payee_name = payee_name;
+        self
+    }
+
+    /// Sets the category.
+    pub fn with_category(mut self, category_id: String, category_name: Option<String>) -> Self {
+        self.category_id = Some(category_id);
+        self.category_name = category_name;
+        self
+    }
+
+    /// Marks this as a transfer to another account.
+    pub fn with_transfer_account_id(mut self, transfer_account_id: String) -> Self {
+        self.transfer_account_id = Some(transfer_account_id);
+        self
+    }
+
+    /// Sets the memo.
+    pub fn with_memo(mut self, memo: String) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Sets whether the scheduled transaction has been deleted in YNAB.
+    pub fn with_deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+
+    /// Returns the scheduled transaction ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the account the transaction is scheduled in.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// Returns how often the transaction repeats.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the date of the first occurrence.
+    pub fn date_first(&self) -> CalendarDate {
+        self.date_first
+    }
+
+    /// Returns the date of the next occurrence.
+    pub fn date_next(&self) -> CalendarDate {
+        self.date_next
+    }
+
+    /// Returns the amount of each occurrence; outflows are negative.
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    /// Returns the payee ID, if any.
+    pub fn payee_id(&self) -> Option<&str> {
+        self.payee_id.as_deref()
+    }
+
+    /// Returns the payee name, if known.
+    pub fn payee_name(&self) -> Option<&str> {
+        self.payee_name.as_deref()
+    }
+
+    /// Returns the category ID, if any.
+    pub fn category_id(&self) -> Option<&str> {
+        self.category_id.as_deref()
+    }
+
+    /// Returns the category name, if known.
+    pub fn category_name(&self) -> Option<&str> {
+        self.category_name.as_deref()
+    }
+
+    /// Returns the account this transaction transfers to, if it is a transfer.
+    pub fn transfer_account_id(&self) -> Option<&str> {
+        self.transfer_account_id.as_deref()
+    }
+
+    /// Returns the memo, if any.
+    pub fn memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+
+    /// Returns whether the scheduled transaction has been deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Returns the dates of every occurrence from `date_next` onwards that
+    /// fall within `range`, in order.
+    ///
+    /// Monthly and longer recurrences keep the day of the month of
+    /// `date_first`, moving to the last day of shorter months. Twice a month
+    /// recurs on that day and the day 15 days apart from it.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{CalendarDate, DateRange, Frequency, Money, ScheduledTransaction};
+    ///
+    /// let due = CalendarDate::parse("2024-01-31").unwrap();
+    /// let card = ScheduledTransaction::new(
+    ///     "st-1".to_string(),
+    ///     "acc-1".to_string(),
+    ///     Frequency::Monthly,
+    ///     due,
+    ///     due,
+    ///     Money::from_milliunits(-50000),
+    /// );
+    ///
+    /// let range = DateRange::parse("2024-01-01", "2024-04-30").unwrap();
+    /// let dates: Vec<String> = card.occurrences(&range).iter().map(|date| date.to_string()).collect();
+    /// assert_eq!(dates, vec!["2024-01-31", "2024-02-29", "2024-03-31", "2024-04-30"]);
+    /// ```
+    pub fn occurrences(&self, range: &DateRange) -> Vec<CalendarDate> {
+        let (next, until) = (self.date_next, range.end());
+        let anchor_day = self.date_first.day();
+
+        let mut dates = Vec::new();
+        match self.frequency.step() {
+            None if self.frequency == Frequency::TwiceAMonth => {
+                let other_day = if anchor_day > 15 {
+                    anchor_day - 15
+                } else {
+                    anchor_day + 15
+                };
+                for offset in 0.. {
+                    let mut month_dates =
+                        [anchor_day, other_day].map(|day| next.add_months_on_day(offset, day));
+                    month_dates.sort_unstable();
+                    if month_dates[0] > until {
+                        break;
+                    }
+                    dates.extend(month_dates.into_iter().filter(|date| *date >= next));
+                }
+            }
+            None => dates.push(next),
+            Some(Step::Days(days)) => {
+                let mut date = next;
+                while date <= until {
+                    dates.push(date);
+                    date = date.add_days(days);
+                }
+            }
+            Some(Step::Months(months)) => {
+                for count in 0.. {
+                    let date = next.add_months_on_day(count * months, anchor_day);
+                    if date > until {
+                        break;
+                    }
+                    dates.push(date);
+                }
+            }
+        }
+
+        dates.retain(|date| range.contains(*date));
+        dates
+    }
+}
+
+/// One occurrence of a scheduled transaction on a specific date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence<'a> {
+    pub date: CalendarDate,
+    pub scheduled_transaction: &'a ScheduledTransaction,
+}
+
+/// Expands every scheduled transaction that is not deleted into its occurrences
+/// within `range`, ordered by date and then by amount.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::{CalendarDate, DateRange, Frequency, Money, ScheduledTransaction, expand_occurrences};
+///
+/// let rent = ScheduledTransaction::new(
+///     "st-1".to_string(),
+///     "acc-1".to_string(),
+///     Frequency::Monthly,
+///     CalendarDate::parse("2024-01-01").unwrap(),
+///     CalendarDate::parse("2024-03-01").unwrap(),
+///     Money::from_milliunits(-1500000),
+/// );
+///
+/// let scheduled_transactions = vec![rent];
+/// let range = DateRange::parse("2024-03-01", "2024-04-30").unwrap();
+/// let occurrences = expand_occurrences(&scheduled_transactions, &range);
+/// assert_eq!(occurrences.len(), 2);
+/// assert_eq!(occurrences[1].date.to_string(), "2024-04-01");
+/// ```
+pub fn expand_occurrences<'a>(
+    scheduled_transactions: &'a [ScheduledTransaction],
+    range: &DateRange,
+) -> Vec<Occurrence<'a>> {
+    let mut occurrences: Vec<Occurrence<'a>> = scheduled_transactions
+        .iter()
+        .filter(|scheduled_transaction| !scheduled_transaction.is_deleted())
+        .flat_map(|scheduled_transaction| {
+            scheduled_transaction
+                .occurrences(range)
+                .into_iter()
+                .map(move |date| Occurrence {
+                    date,
+                    scheduled_transaction,
+                })
+        })
+        .collect();
+    occurrences.sort_by(|a, b| {
+        a.date.cmp(&b.date).then_with(|| {
+            a.scheduled_transaction
+                .amount()
+                .cmp(&b.scheduled_transaction.amount())
+        })
+    });
+    occurrences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduled(frequency: Frequency, date_first: &str, date_next: &str) -> ScheduledTransaction {
+        ScheduledTransaction::new(
+            "st-1".to_string(),
+            "acc-1".to_string(),
+            frequency,
+            date_first.parse().unwrap(),
+            date_next.parse().unwrap(),
+            Money::from_milliunits(-10_000),
+        )
+    }
+
+    fn dates_between(scheduled: &ScheduledTransaction, from: &str, until: &str) -> Vec<String> {
+        let range = DateRange::parse(from, until).unwrap();
+        scheduled
+            .occurrences(&range)
+            .iter()
+            .map(|date| date.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_every_api_frequency() {
+        for name in [
+            "never",
+            "daily",
+            "weekly",
+            "everyOtherWeek",
+            "twiceAMonth",
+            "every4Weeks",
+            "monthly",
+            "everyOtherMonth",
+            "every3Months",
+            "every4Months",
+            "twiceAYear",
+            "yearly",
+            "everyOtherYear",
+        ] {
+            assert_eq!(Frequency::from_api_name(name).unwrap().api_name(), name);
+        }
+    }
+
+    #[test]
+    fn should_expand_day_based_recurrences() {
+        let weekly = scheduled(Frequency::Weekly, "2024-01-03", "2024-03-06");
+        let biweekly = scheduled(Frequency::EveryOtherWeek, "2024-01-03", "2024-03-06");
+
+        assert_eq!(
+            dates_between(&weekly, "2024-03-01", "2024-03-31"),
+            vec!["2024-03-06", "2024-03-13", "2024-03-20", "2024-03-27"]
+        );
+        assert_eq!(
+            dates_between(&biweekly, "2024-03-10", "2024-04-10"),
+            vec!["2024-03-20", "2024-04-03"]
+        );
+    }
+
+    #[test]
+    fn should_expand_month_based_recurrences_on_anchor_day() {
+        let quarterly = scheduled(Frequency::Every3Months, "2023-08-31", "2024-02-29");
+        let yearly = scheduled(Frequency::Yearly, "2020-02-29", "2024-02-29");
+
+        assert_eq!(
+            dates_between(&quarterly, "2024-01-01", "2024-12-31"),
+            vec!["2024-02-29", "2024-05-31", "2024-08-31", "2024-11-30"]
+        );
+        assert_eq!(
+            dates_between(&yearly, "2024-01-01", "2026-12-31"),
+            vec!["2024-02-29", "2025-02-28", "2026-02-28"]
+        );
+    }
+
+    #[test]
+    fn should_expand_twice_a_month() {
+        let payday = scheduled(Frequency::TwiceAMonth, "2024-01-01", "2024-01-16");
+
+        assert_eq!(
+            dates_between(&payday, "2024-01-01", "2024-02-29"),
+            vec!["2024-01-16", "2024-02-01", "2024-02-16"]
+        );
+    }
+
+    #[test]
+    fn should_occur_once_when_never_repeating() {
+        let one_off = scheduled(Frequency::Never, "2024-03-05", "2024-03-05");
+
+        assert_eq!(
+            dates_between(&one_off, "2024-03-01", "2024-12-31"),
+            vec!["2024-03-05"]
+        );
+        assert!(dates_between(&one_off, "2024-04-01", "2024-12-31").is_empty());
+    }
+
+    #[test]
+    fn should_return_no_occurrences_before_date_next() {
+        let later = scheduled(Frequency::Daily, "2024-01-01", "2024-03-01");
+
+        assert!(dates_between(&later, "2024-01-01", "2024-02-29").is_empty());
+    }
+
+    #[test]
+    fn should_carry_optional_details() {
+        let transfer = scheduled(Frequency::Monthly, "2024-01-01", "2024-02-01")
+            .with_payee("p-1".to_string(), Some("Transfer : Savings".to_string()))
+            .with_category("c-1".to_string(), None)
+            .with_transfer_account_id("acc-2".to_string())
+            .with_memo("Savings".to_string())
+            .with_deleted(true);
+
+        assert_eq!(transfer.payee_name(), Some("Transfer : Savings"));
+        assert_eq!(transfer.category_id(), Some("c-1"));
+        assert_eq!(transfer.category_name(), None);
+        assert_eq!(transfer.transfer_account_id(), Some("acc-2"));
+        assert_eq!(transfer.memo(), Some("Savings"));
+        assert!(transfer.is_deleted());
+    }
+
+    #[test]
+    fn should_expand_all_scheduled_transactions_in_date_order() {
+        let rent = ScheduledTransaction::new(
+            "rent".to_string(),
+            "acc-1".to_string(),
+            Frequency::Monthly,
+            "2024-01-01".parse().unwrap(),
+            "2024-03-01".parse().unwrap(),
+            Money::from_milliunits(-1_500_000),
+        );
+        let gym = scheduled(Frequency::Weekly, "2024-02-26", "2024-03-04");
+        let cancelled = scheduled(Frequency::Daily, "2024-03-01", "2024-03-01").with_deleted(true);
+        let scheduled_transactions = vec![gym, rent, cancelled];
+
+        let range = DateRange::parse("2024-03-01", "2024-03-14").unwrap();
+        let occurrences = expand_occurrences(&scheduled_transactions, &range);
+
+        let dates: Vec<(String, &str)> = occurrences
+            .iter()
+            .map(|occurrence| {
+                (
+                    occurrence.date.to_string(),
+                    occurrence.scheduled_transaction.id(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                ("2024-03-01".to_string(), "rent"),
+                ("2024-03-04".to_string(), "st-1"),
+                ("2024-03-11".to_string(), "st-1")
+            ]
+        );
+    }
+}
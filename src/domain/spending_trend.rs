@@ -0,0 +1,381 @@
+//! Month-by-month spending per category and the direction it is heading.
+
+use crate::domain::{
+    BudgetFlow, BudgetFlowClassifier, CalendarDate, DateRange, Money, Transaction,
+};
+use std::collections::HashMap;
+
+/// Share of a category's average monthly spending its slope must exceed,
+/// in either direction, for the category to count as trending.
+pub const TREND_THRESHOLD_PERCENT: i64 = 5;
+
+/// Direction spending in a category is heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendDirection {
+    Up,
+    Down,
+    Flat,
+}
+
+impl TrendDirection {
+    /// Returns the lowercase name used in tool output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Up => "up",
+            Self::Down => "down",
+            Self::Flat => "flat",
+        }
+    }
+}
+
+/// Spending in one category during one month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthlySpending {
+    pub month: DateRange,
+    /// Total outflow, as a positive value.
+    pub amount: Money,
+    /// Number of outflow transactions.
+    pub transaction_count: usize,
+}
+
+/// Monthly spending in one category over the analyzed months.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryTrend {
+    pub category_id: String,
+    /// One entry per analyzed month, oldest first, including months without spending.
+    pub months: Vec<MonthlySpending>,
+    pub total: Money,
+    /// Total divided by the number of analyzed months, rounded towards zero.
+    pub average: Money,
+    /// Least-squares slope of the monthly amounts, in milliunits per month.
+    pub slope: f64,
+    /// Change from the first to the last analyzed month, in percent.
+    ///
+    /// `None` when nothing was spent in the first month.
+    pub percent_change: Option<f64>,
+}
+
+impl CategoryTrend {
+    /// Returns the direction of the trend.
+    ///
+    /// Spending is trending up when the slope is above
+    /// [`TREND_THRESHOLD_PERCENT`] percent of the average monthly amount, and
+    /// down when it is below the negative of that; otherwise it is flat.
+    pub fn direction(&self) -> TrendDirection {
+        let threshold =
+            self.average.as_milliunits() as f64 * TREND_THRESHOLD_PERCENT as f64 / 100.0;
+        if self.slope > threshold {
+            TrendDirection::Up
+        } else if self.slope < -threshold {
+            TrendDirection::Down
+        } else {
+            TrendDirection::Flat
+        }
+    }
+}
+
+/// Spending per category, bucketed into calendar months.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpendingTrends {
+    /// Analyzed calendar months, oldest first.
+    pub months: Vec<DateRange>,
+    /// Categories with spending in the analyzed months, ordered by ID.
+    pub categories: Vec<CategoryTrend>,
+}
+
+impl SpendingTrends {
+    /// Buckets outflows by the month of their date over the `months` months
+    /// ending with the month of `current`.
+    ///
+    /// Only outflows `classifier` counts as spending are analyzed, and split
+    /// transactions count through their lines, so credit card payments,
+    /// transfers between accounts and tracking account activity are left out.
+    /// Deleted transactions and transactions without a date or outside the
+    /// analyzed months are ignored. When `categories` is not empty, only those
+    /// category IDs are analyzed.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{BudgetFlowClassifier, CalendarDate, Money, SpendingTrends, Transaction, TrendDirection};
+    ///
+    /// let shop = |id: &str, amount: i64, date: &str| {
+    ///     Transaction::new_with_date(id.to_string(), "acc-1".to_string(), "groceries".to_string(),
+    ///                                Money::from_milliunits(amount), date.parse().unwrap())
+    /// };
+    /// let (january, february) = (shop("t-1", -40000, "2024-01-10"), shop("t-2", -60000, "2024-02-10"));
+    /// let current = CalendarDate::parse("2024-02-15").unwrap();
+    ///
+    /// let classifier = BudgetFlowClassifier::default();
+    /// let trends = SpendingTrends::calculate(&[&january, &february], &classifier, current, 2, &[]);
+    ///
+    /// assert_eq!(trends.months[0].start().year_month(), "2024-01");
+    /// assert_eq!(trends.categories[0].slope, 20000.0);
+    /// assert_eq!(trends.categories[0].percent_change, Some(50.0));
+    /// assert_eq!(trends.categories[0].direction(), TrendDirection::Up);
+    /// ```
+    pub fn calculate(
+        transactions: &[&Transaction],
+        classifier: &BudgetFlowClassifier,
+        current: CalendarDate,
+        months: usize,
+        categories: &[String],
+    ) -> Self {
+        let month_count = months.max(1);
+        let analyzed: Vec<DateRange> = (0..month_count as i64)
+            .rev()
+            .map(|offset| DateRange::month_of(current.first_of_month().add_months(-offset)))
+            .collect();
+        let month_index: HashMap<CalendarDate, usize> = analyzed
+            .iter()
+            .enumerate()
+            .map(|(index, month)| (month.start(), index))
+            .collect();
+
+        let mut buckets: HashMap<&str, Vec<(i64, usize)>> = HashMap::new();
+        for transaction in transactions
+            .iter()
+            .flat_map(|transaction| transaction.lines())
+        {
+            let amount = transaction.amount().as_milliunits();
+            let category_id = transaction.category_id();
+            let is_transfer = transaction.transfer_account_id().is_some() && category_id.is_empty();
+            if amount >= 0
+                || transaction.is_deleted()
+                || is_transfer
+                || classifier.classify(transaction) != BudgetFlow::Spending
+                || (!categories.is_empty() && !categories.iter().any(|id| id == category_id))
+            {
+                continue;
+            }
+            let Some(index) = transaction
+                .date()
+                .and_then(|date| month_index.get(&date.first_of_month()))
+            else {
+                continue;
+            };
+            let bucket = &mut buckets
+                .entry(category_id)
+                .or_insert_with(|| vec![(0, 0); month_count])[*index];
+            bucket.0 -= amount;
+            bucket.1 += 1;
+        }
+
+        let mut trends: Vec<CategoryTrend> = buckets
+            .into_iter()
+            .map(|(category_id, buckets)| {
+                let amounts: Vec<i64> = buckets.iter().map(|(amount, _)| *amount).collect();
+                let total: i64 = amounts.iter().sum();
+                let first = amounts[0];
+                let last = amounts[amounts.len() - 1];
+                CategoryTrend {
+                    category_id: category_id.to_string(),
+                    months: analyzed
+                        .iter()
+                        .zip(&buckets)
+                        .map(|(month, (amount, count))| MonthlySpending {
+                            month: *month,
+                            amount: Money::from_milliunits(*amount),
+                            transaction_count: *count,
+                        })
+                        .collect(),
+                    total: Money::from_milliunits(total),
+                    average: Money::from_milliunits(total / month_count as i64),
+                    slope: least_squares_slope(&amounts),
+                    percent_change: (first != 0)
+                        .then(|| (last - first) as f64 * 100.0 / first as f64),
+                }
+            })
+            .collect();
+        trends.sort_by(|a, b| a.category_id.cmp(&b.category_id));
+
+        Self {
+            months: analyzed,
+            categories: trends,
+        }
+    }
+
+    /// Returns the IDs of categories trending in `direction`, steepest slope first.
+    pub fn trending(&self, direction: TrendDirection) -> Vec<&str> {
+        let mut matching: Vec<&CategoryTrend> = self
+            .categories
+            .iter()
+            .filter(|trend| trend.direction() == direction)
+            .collect();
+        matching.sort_by(|a, b| b.slope.abs().total_cmp(&a.slope.abs()));
+        matching
+            .into_iter()
+            .map(|trend| trend.category_id.as_str())
+            .collect()
+    }
+
+    /// Returns the total spending across all categories.
+    pub fn total(&self) -> Money {
+        self.categories.iter().map(|trend| trend.total).sum()
+    }
+}
+
+/// Fits `amounts` against their month index and returns the slope of the line.
+fn least_squares_slope(amounts: &[i64]) -> f64 {
+    let count = amounts.len() as f64;
+    if amounts.len() < 2 {
+        return 0.0;
+    }
+    let mean_x = (count - 1.0) / 2.0;
+    let mean_y = amounts.iter().sum::<i64>() as f64 / count;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (index, amount) in amounts.iter().enumerate() {
+        let dx = index as f64 - mean_x;
+        covariance += dx * (*amount as f64 - mean_y);
+        variance += dx * dx;
+    }
+    covariance / variance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spend(id: &str, category: &str, amount: i64, date: &str) -> Transaction {
+        Transaction::new_with_date(
+            id.to_string(),
+            "acc-1".to_string(),
+            category.to_string(),
+            Money::from_milliunits(amount),
+            date.parse().unwrap(),
+        )
+    }
+
+    fn month(text: &str) -> CalendarDate {
+        format!("{}-01", text).parse().unwrap()
+    }
+
+    #[test]
+    fn should_bucket_outflows_by_transaction_month() {
+        let transactions = [
+            spend("t-1", "groceries", -10_000, "2024-01-03"),
+            spend("t-2", "groceries", -15_000, "2024-01-28"),
+            spend("t-3", "groceries", -30_000, "2024-03-02"),
+            spend("t-4", "groceries", 5_000, "2024-03-04"),
+            spend("t-5", "groceries", -99_000, "2023-12-31"),
+        ];
+        let refs: Vec<&Transaction> = transactions.iter().collect();
+
+        let trends = SpendingTrends::calculate(
+            &refs,
+            &BudgetFlowClassifier::default(),
+            month("2024-03"),
+            3,
+            &[],
+        );
+
+        let months: Vec<String> = trends
+            .months
+            .iter()
+            .map(|month| month.start().year_month())
+            .collect();
+        assert_eq!(months, vec!["2024-01", "2024-02", "2024-03"]);
+        assert_eq!(trends.months[1].end(), "2024-02-29".parse().unwrap());
+        let groceries = &trends.categories[0];
+        let amounts: Vec<(i64, usize)> = groceries
+            .months
+            .iter()
+            .map(|month| (month.amount.as_milliunits(), month.transaction_count))
+            .collect();
+        assert_eq!(amounts, vec![(25_000, 2), (0, 0), (30_000, 1)]);
+        assert_eq!(groceries.total, Money::from_milliunits(55_000));
+        assert_eq!(groceries.average, Money::from_milliunits(18_333));
+        assert_eq!(groceries.slope, 2_500.0);
+        assert_eq!(groceries.percent_change, Some(20.0));
+    }
+
+    #[test]
+    fn should_split_categories_by_trend_direction() {
+        let transactions = [
+            spend("t-1", "dining", -10_000, "2024-01-10"),
+            spend("t-2", "dining", -20_000, "2024-02-10"),
+            spend("t-3", "fuel", -50_000, "2024-01-10"),
+            spend("t-4", "fuel", -20_000, "2024-02-10"),
+            spend("t-5", "rent", -100_000, "2024-01-01"),
+            spend("t-6", "rent", -101_000, "2024-02-01"),
+        ];
+        let refs: Vec<&Transaction> = transactions.iter().collect();
+
+        let trends = SpendingTrends::calculate(
+            &refs,
+            &BudgetFlowClassifier::default(),
+            "2024-02-20".parse().unwrap(),
+            2,
+            &[],
+        );
+
+        assert_eq!(trends.trending(TrendDirection::Up), vec!["dining"]);
+        assert_eq!(trends.trending(TrendDirection::Down), vec!["fuel"]);
+        assert_eq!(trends.trending(TrendDirection::Flat), vec!["rent"]);
+        assert_eq!(trends.total(), Money::from_milliunits(301_000));
+    }
+
+    #[test]
+    fn should_leave_percent_change_empty_without_first_month_spending() {
+        let transactions = [spend("t-1", "gifts", -40_000, "2024-02-10")];
+        let refs: Vec<&Transaction> = transactions.iter().collect();
+
+        let trends = SpendingTrends::calculate(
+            &refs,
+            &BudgetFlowClassifier::default(),
+            month("2024-02"),
+            2,
+            &[],
+        );
+
+        assert_eq!(trends.categories[0].percent_change, None);
+        assert_eq!(trends.categories[0].direction(), TrendDirection::Up);
+    }
+
+    #[test]
+    fn should_leave_transfers_out_of_spending() {
+        let card_payment = Transaction::builder()
+            .id("t-2".to_string())
+            .account_id("acc-1".to_string())
+            .category_id("".to_string())
+            .amount(Money::from_milliunits(-80_000))
+            .date("2024-02-12".parse().unwrap())
+            .transfer_account_id("visa".to_string())
+            .build();
+        let transactions = [spend("t-1", "dining", -10_000, "2024-02-10"), card_payment];
+        let refs: Vec<&Transaction> = transactions.iter().collect();
+
+        let trends = SpendingTrends::calculate(
+            &refs,
+            &BudgetFlowClassifier::default(),
+            month("2024-02"),
+            1,
+            &[],
+        );
+
+        assert_eq!(trends.categories.len(), 1);
+        assert_eq!(trends.categories[0].category_id, "dining");
+        assert_eq!(trends.total(), Money::from_milliunits(10_000));
+    }
+
+    #[test]
+    fn should_only_analyze_requested_categories() {
+        let transactions = [
+            spend("t-1", "dining", -10_000, "2024-02-10"),
+            spend("t-2", "fuel", -20_000, "2024-02-10"),
+        ];
+        let refs: Vec<&Transaction> = transactions.iter().collect();
+
+        let trends = SpendingTrends::calculate(
+            &refs,
+            &BudgetFlowClassifier::default(),
+            month("2024-02"),
+            1,
+            &["fuel".to_string()],
+        );
+
+        assert_eq!(trends.categories.len(), 1);
+        assert_eq!(trends.categories[0].category_id, "fuel");
+        assert_eq!(trends.categories[0].slope, 0.0);
+        assert_eq!(trends.trending(TrendDirection::Flat), vec!["fuel"]);
+    }
+}
@@ -1,6 +1,44 @@
 //! Transaction domain entity.
 
-use crate::domain::Money;
+use crate::domain::{CalendarDate, Money};
+
+/// Whether a transaction has cleared the bank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClearedStatus {
+    #[default]
+    Uncleared,
+    Cleared,
+    Reconciled,
+}
+
+impl ClearedStatus {
+    /// Parses the `cleared` value used by the YNAB API.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::ClearedStatus;
+    ///
+    /// assert_eq!(ClearedStatus::from_api_name("reconciled"), Some(ClearedStatus::Reconciled));
+    /// assert_eq!(ClearedStatus::from_api_name("pending"), None);
+    /// ```
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        match name {
+            "uncleared" => Some(Self::Uncleared),
+            "cleared" => Some(Self::Cleared),
+            "reconciled" => Some(Self::Reconciled),
+            _ => None,
+        }
+    }
+
+    /// Returns the `cleared` value used by the YNAB API.
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            Self::Uncleared => "uncleared",
+            Self::Cleared => "cleared",
+            Self::Reconciled => "reconciled",
+        }
+    }
+}
 
 /// Represents a financial transaction in YNAB.
 #[derive(Debug, Clone, PartialEq)]
@@ -10,8 +48,20 @@ pub struct Transaction {
     category_id: String,
     payee_id: Option<String>,
     amount: Money,
-    date: Option<String>,
+    date: Option<CalendarDate>,
     description: Option<String>,
+    cleared: ClearedStatus,
+    approved: bool,
+    flag_color: Option<String>,
+    account_name: Option<String>,
+    payee_name: Option<String>,
+    category_name: Option<String>,
+    transfer_account_id: Option<String>,
+    import_id: Option<String>,
+    matched_transaction_id: Option<String>,
+    deleted: bool,
+    parent_id: Option<String>,
+    subtransactions: Vec<Transaction>,
 }
 
 impl Transaction {
@@ -51,48 +101,44 @@ impl Transaction {
     /// assert_eq!(transaction.category_id(), "groceries");
     /// ```
     pub fn new(id: String, account_id: String, category_id: String, amount: Money) -> Self {
-        Self {
-            id,
-            account_id,
-            category_id,
-            payee_id: None,
-            amount,
-            date: None,
-            description: None,
-        }
+        Self::builder()
+            .id(id)
+            .account_id(account_id)
+            .category_id(category_id)
+            .amount(amount)
+            .build()
     }
 
     /// Creates a new Transaction with a date (legacy constructor).
     ///
     /// # Example
     /// ```
-    /// use ynab_mcp::{Transaction, Money};
+    /// use ynab_mcp::{CalendarDate, Transaction, Money};
     ///
+    /// let date = CalendarDate::parse("2024-01-15").unwrap();
     /// let transaction = Transaction::new_with_date(
     ///     "txn-123".to_string(),
     ///     "acc-123".to_string(),
     ///     "groceries".to_string(),
     ///     Money::from_milliunits(-5000),
-    ///     "2024-01-15".to_string(),
+    ///     date,
     /// );
-    /// assert_eq!(transaction.date(), Some("2024-01-15"));
+    /// assert_eq!(transaction.date(), Some(date));
     /// ```
     pub fn new_with_date(
         id: String,
         account_id: String,
         category_id: String,
         amount: Money,
-        date: String,
+        date: CalendarDate,
     ) -> Self {
-        Self {
-            id,
-            account_id,
-            category_id,
-            payee_id: None,
-            amount,
-            date: Some(date),
-            description: None,
-        }
+        Self::builder()
+            .id(id)
+            .account_id(account_id)
+            .category_id(category_id)
+            .amount(amount)
+            .date(date)
+            .build()
     }
 
     /// Creates a new Transaction with a description (legacy constructor).
@@ -117,15 +163,13 @@ impl Transaction {
         amount: Money,
         description: String,
     ) -> Self {
-        Self {
-            id,
-            account_id,
-            category_id,
-            payee_id: None,
-            amount,
-            date: None,
-            description: Some(description),
-        }
+        Self::builder()
+            .id(id)
+            .account_id(account_id)
+            .category_id(category_id)
+            .amount(amount)
+            .description(description)
+            .build()
     }
 
     /// Returns the transaction ID.
@@ -154,14 +198,119 @@ impl Transaction {
     }
 
     /// Returns the transaction date if available.
-    pub fn date(&self) -> Option<&str> {
-        self.date.as_deref()
+    pub fn date(&self) -> Option<CalendarDate> {
+        self.date
     }
 
     /// Returns the transaction description if available.
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    /// Returns whether the transaction has cleared or been reconciled.
+    pub fn cleared(&self) -> ClearedStatus {
+        self.cleared
+    }
+
+    /// Returns whether the transaction has been approved.
+    pub fn is_approved(&self) -> bool {
+        self.approved
+    }
+
+    /// Returns the flag color, such as `red`, if the transaction is flagged.
+    pub fn flag_color(&self) -> Option<&str> {
+        self.flag_color.as_deref()
+    }
+
+    /// Returns the name of the account if known.
+    pub fn account_name(&self) -> Option<&str> {
+        self.account_name.as_deref()
+    }
+
+    /// Returns the name of the payee if known.
+    pub fn payee_name(&self) -> Option<&str> {
+        self.payee_name.as_deref()
+    }
+
+    /// Returns the name of the category if known.
+    pub fn category_name(&self) -> Option<&str> {
+        self.category_name.as_deref()
+    }
+
+    /// Returns the other account of a transfer.
+    pub fn transfer_account_id(&self) -> Option<&str> {
+        self.transfer_account_id.as_deref()
+    }
+
+    /// Returns the ID of the file or bank import the transaction came from.
+    pub fn import_id(&self) -> Option<&str> {
+        self.import_id.as_deref()
+    }
+
+    /// Returns the ID of the transaction this one was matched with.
+    pub fn matched_transaction_id(&self) -> Option<&str> {
+        self.matched_transaction_id.as_deref()
+    }
+
+    /// Returns whether the transaction was deleted.
+    ///
+    /// Delta sync drops deleted transactions from its snapshot, so the API
+    /// never hands them out; any built by other means never count towards
+    /// totals and are left out of searches.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Returns the ID of the split transaction this line belongs to, if it is a subtransaction.
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+
+    /// Returns the lines of a split transaction, empty for unsplit transactions.
+    pub fn subtransactions(&self) -> &[Transaction] {
+        &self.subtransactions
+    }
+
+    /// Returns whether the transaction is split across several categories.
+    pub fn is_split(&self) -> bool {
+        !self.subtransactions.is_empty()
+    }
+
+    /// Returns the lines that carry the transaction's categories: its
+    /// subtransactions when it is split, otherwise the transaction itself.
+    ///
+    /// Spending by category should always be totalled over lines, since the
+    /// category of a split transaction is only a placeholder.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Money, Transaction};
+    ///
+    /// let line = |id: &str, category: &str, amount: i64| {
+    ///     Transaction::builder()
+    ///         .id(id.to_string())
+    ///         .account_id("acc-1".to_string())
+    ///         .category_id(category.to_string())
+    ///         .amount(Money::from_milliunits(amount))
+    ///         .parent_id("txn-1".to_string())
+    ///         .build()
+    /// };
+    /// let split = Transaction::builder()
+    ///     .id("txn-1".to_string())
+    ///     .account_id("acc-1".to_string())
+    ///     .category_id("split".to_string())
+    ///     .amount(Money::from_milliunits(-9000))
+    ///     .subtransactions(vec![line("sub-1", "groceries", -6000), line("sub-2", "household", -3000)])
+    ///     .build();
+    ///
+    /// let categories: Vec<&str> = split.lines().map(|line| line.category_id()).collect();
+    /// assert_eq!(categories, vec!["groceries", "household"]);
+    /// assert_eq!(split.subtransactions()[0].parent_id(), Some("txn-1"));
+    /// ```
+    pub fn lines(&self) -> impl Iterator<Item = &Transaction> {
+        let own_line = (!self.is_split()).then_some(self);
+        own_line.into_iter().chain(&self.subtransactions)
+    }
 }
 
 /// Builder for constructing Transaction objects.
@@ -172,8 +321,20 @@ pub struct TransactionBuilder {
     category_id: Option<String>,
     payee_id: Option<String>,
     amount: Option<Money>,
-    date: Option<String>,
+    date: Option<CalendarDate>,
     description: Option<String>,
+    cleared: ClearedStatus,
+    approved: bool,
+    flag_color: Option<String>,
+    account_name: Option<String>,
+    payee_name: Option<String>,
+    category_name: Option<String>,
+    transfer_account_id: Option<String>,
+    import_id: Option<String>,
+    matched_transaction_id: Option<String>,
+    deleted: bool,
+    parent_id: Option<String>,
+    subtransactions: Vec<Transaction>,
 }
 
 impl TransactionBuilder {
@@ -206,7 +367,7 @@ impl TransactionBuilder {
         self
     }
 
-    pub fn date(mut self, date: String) -> Self {
+    pub fn date(mut self, date: CalendarDate) -> Self {
         self.date = Some(date);
         self
     }
@@ -216,6 +377,66 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn cleared(mut self, cleared: ClearedStatus) -> Self {
+        self.cleared = cleared;
+        self
+    }
+
+    pub fn approved(mut self, approved: bool) -> Self {
+        self.approved = approved;
+        self
+    }
+
+    pub fn flag_color(mut self, flag_color: String) -> Self {
+        self.flag_color = Some(flag_color);
+        self
+    }
+
+    pub fn account_name(mut self, account_name: String) -> Self {
+        self.account_name = Some(account_name);
+        self
+    }
+
+    pub fn payee_name(mut self, payee_name: String) -> Self {
+        self.payee_name = Some(payee_name);
+        self
+    }
+
+    pub fn category_name(mut self, category_name: String) -> Self {
+        self.category_name = Some(category_name);
+        self
+    }
+
+    pub fn transfer_account_id(mut self, transfer_account_id: String) -> Self {
+        self.transfer_account_id = Some(transfer_account_id);
+        self
+    }
+
+    pub fn import_id(mut self, import_id: String) -> Self {
+        self.import_id = Some(import_id);
+        self
+    }
+
+    pub fn matched_transaction_id(mut self, matched_transaction_id: String) -> Self {
+        self.matched_transaction_id = Some(matched_transaction_id);
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+
+    pub fn parent_id(mut self, parent_id: String) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    pub fn subtransactions(mut self, subtransactions: Vec<Transaction>) -> Self {
+        self.subtransactions = subtransactions;
+        self
+    }
+
     pub fn build(self) -> Transaction {
         Transaction {
             id: self.id.expect("Transaction ID is required"),
@@ -225,6 +446,18 @@ impl TransactionBuilder {
             amount: self.amount.expect("Transaction amount is required"),
             date: self.date,
             description: self.description,
+            cleared: self.cleared,
+            approved: self.approved,
+            flag_color: self.flag_color,
+            account_name: self.account_name,
+            payee_name: self.payee_name,
+            category_name: self.category_name,
+            transfer_account_id: self.transfer_account_id,
+            import_id: self.import_id,
+            matched_transaction_id: self.matched_transaction_id,
+            deleted: self.deleted,
+            parent_id: self.parent_id,
+            subtransactions: self.subtransactions,
         }
     }
 }
@@ -270,7 +503,7 @@ mod tests {
             .category_id("groceries".to_string())
             .payee_id("payee-wholefood".to_string())
             .amount(Money::from_milliunits(-7500))
-            .date("2024-01-15".to_string())
+            .date("2024-01-15".parse().unwrap())
             .description("Whole Foods Market".to_string())
             .build();
 
@@ -279,7 +512,7 @@ mod tests {
         assert_eq!(transaction.category_id(), "groceries");
         assert_eq!(transaction.payee_id(), Some("payee-wholefood"));
         assert_eq!(transaction.amount(), Money::from_milliunits(-7500));
-        assert_eq!(transaction.date(), Some("2024-01-15"));
+        assert_eq!(transaction.date().unwrap().to_string(), "2024-01-15");
         assert_eq!(transaction.description(), Some("Whole Foods Market"));
     }
 
@@ -298,5 +531,79 @@ mod tests {
         assert_eq!(transaction.payee_id(), None);
         assert_eq!(transaction.date(), None);
         assert_eq!(transaction.description(), None);
+        assert_eq!(transaction.cleared(), ClearedStatus::Uncleared);
+        assert!(!transaction.is_approved());
+        assert!(!transaction.is_deleted());
+    }
+
+    #[test]
+    fn should_carry_ynab_status_and_reference_fields() {
+        let transaction = Transaction::builder()
+            .id("txn-789".to_string())
+            .account_id("acc-checking".to_string())
+            .category_id("".to_string())
+            .amount(Money::from_milliunits(-50000))
+            .cleared(ClearedStatus::Reconciled)
+            .approved(true)
+            .flag_color("red".to_string())
+            .account_name("Checking".to_string())
+            .payee_name("Transfer : Savings".to_string())
+            .category_name("Inflow: Ready to Assign".to_string())
+            .transfer_account_id("acc-savings".to_string())
+            .import_id("YNAB:-50000:2024-01-15:1".to_string())
+            .matched_transaction_id("txn-imported".to_string())
+            .deleted(true)
+            .build();
+
+        assert_eq!(transaction.cleared(), ClearedStatus::Reconciled);
+        assert_eq!(transaction.cleared().api_name(), "reconciled");
+        assert!(transaction.is_approved());
+        assert_eq!(transaction.flag_color(), Some("red"));
+        assert_eq!(transaction.account_name(), Some("Checking"));
+        assert_eq!(transaction.payee_name(), Some("Transfer : Savings"));
+        assert_eq!(transaction.category_name(), Some("Inflow: Ready to Assign"));
+        assert_eq!(transaction.transfer_account_id(), Some("acc-savings"));
+        assert_eq!(transaction.import_id(), Some("YNAB:-50000:2024-01-15:1"));
+        assert_eq!(transaction.matched_transaction_id(), Some("txn-imported"));
+        assert!(transaction.is_deleted());
+    }
+
+    #[test]
+    fn should_yield_itself_as_only_line_when_not_split() {
+        let transaction = Transaction::new(
+            "txn-1".to_string(),
+            "acc-1".to_string(),
+            "groceries".to_string(),
+            Money::from_milliunits(-5000),
+        );
+
+        let lines: Vec<&Transaction> = transaction.lines().collect();
+
+        assert!(!transaction.is_split());
+        assert_eq!(lines, vec![&transaction]);
+        assert_eq!(transaction.parent_id(), None);
+    }
+
+    #[test]
+    fn should_yield_subtransactions_as_lines_of_split_transaction() {
+        let line = Transaction::builder()
+            .id("sub-1".to_string())
+            .account_id("acc-1".to_string())
+            .category_id("groceries".to_string())
+            .amount(Money::from_milliunits(-5000))
+            .parent_id("txn-1".to_string())
+            .build();
+        let split = Transaction::builder()
+            .id("txn-1".to_string())
+            .account_id("acc-1".to_string())
+            .category_id("split".to_string())
+            .amount(Money::from_milliunits(-5000))
+            .subtransactions(vec![line.clone()])
+            .build();
+
+        let lines: Vec<&Transaction> = split.lines().collect();
+
+        assert!(split.is_split());
+        assert_eq!(lines, vec![&line]);
     }
 }
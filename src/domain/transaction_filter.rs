@@ -0,0 +1,277 @@
+//! Filter expressions over transactions, combinable with AND, OR and NOT.
+
+use crate::domain::{ClearedStatus, DateRange, Money, Transaction};
+
+/// Name YNAB gives the category of transactions waiting to be categorized.
+const UNCATEGORIZED_CATEGORY: &str = "Uncategorized";
+
+/// Whether money enters or leaves an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Inflow,
+    Outflow,
+}
+
+impl Direction {
+    /// Parses a direction from its tool argument name: `inflow` or `outflow`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "inflow" => Some(Self::Inflow),
+            "outflow" => Some(Self::Outflow),
+            _ => None,
+        }
+    }
+}
+
+/// A condition on a transaction.
+///
+/// Conditions are grouped with [`TransactionFilter::All`],
+/// [`TransactionFilter::Any`] and [`TransactionFilter::Not`] into
+/// expressions of any depth.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::{ClearedStatus, Direction, Money, Transaction, TransactionFilter};
+///
+/// // Uncleared outflows on the Visa over $50, not at Amazon
+/// let filter = TransactionFilter::AccountIds(vec!["visa".to_string()])
+///     .and(TransactionFilter::Cleared(vec![ClearedStatus::Uncleared]))
+///     .and(TransactionFilter::Direction(Direction::Outflow))
+///     .and(TransactionFilter::MaxAmount(Money::from_milliunits(-50000)))
+///     .and(TransactionFilter::PayeeName("amazon".to_string()).negated());
+///
+/// let purchase = |payee: &str| {
+///     Transaction::builder()
+///         .id("txn-1".to_string())
+///         .account_id("visa".to_string())
+///         .category_id("electronics".to_string())
+///         .payee_name(payee.to_string())
+///         .amount(Money::from_milliunits(-120000))
+///         .build()
+/// };
+///
+/// assert!(filter.matches(&purchase("Best Buy")));
+/// assert!(!filter.matches(&purchase("Amazon.com")));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionFilter {
+    /// Matches when every filter matches; an empty list matches everything.
+    All(Vec<TransactionFilter>),
+    /// Matches when any filter matches; an empty list matches nothing.
+    Any(Vec<TransactionFilter>),
+    /// Matches when the filter does not.
+    Not(Box<TransactionFilter>),
+    /// Dated within the range; undated transactions never match.
+    DateRange(DateRange),
+    /// In one of the accounts.
+    AccountIds(Vec<String>),
+    /// Paid to or received from one of the payees.
+    PayeeIds(Vec<String>),
+    /// Payee name containing the text, ignoring case.
+    PayeeName(String),
+    /// In one of the categories.
+    CategoryIds(Vec<String>),
+    /// Memo containing the text, ignoring case.
+    Memo(String),
+    /// In one of the cleared states.
+    Cleared(Vec<ClearedStatus>),
+    /// Approved, or waiting for approval when `false`.
+    Approved(bool),
+    /// Flagged with one of the colors, ignoring case; an empty list matches
+    /// transactions without a flag.
+    FlagColors(Vec<String>),
+    /// Moving money in the direction; zero amounts match neither.
+    Direction(Direction),
+    /// Neither categorized nor a transfer between accounts.
+    Uncategorized,
+    /// Amount at or above the minimum; outflows are negative.
+    MinAmount(Money),
+    /// Amount at or below the maximum; outflows are negative.
+    MaxAmount(Money),
+}
+
+impl TransactionFilter {
+    /// Combines two filters so both must match, flattening nested `All` groups.
+    pub fn and(self, other: TransactionFilter) -> Self {
+        match self {
+            Self::All(mut filters) => {
+                filters.push(other);
+                Self::All(filters)
+            }
+            filter => Self::All(vec![filter, other]),
+        }
+    }
+
+    /// Combines two filters so either may match, flattening nested `Any` groups.
+    pub fn or(self, other: TransactionFilter) -> Self {
+        match self {
+            Self::Any(mut filters) => {
+                filters.push(other);
+                Self::Any(filters)
+            }
+            filter => Self::Any(vec![filter, other]),
+        }
+    }
+
+    /// Returns a filter matching exactly the transactions this one does not.
+    pub fn negated(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    /// Checks whether a transaction matches the filter.
+    pub fn matches(&self, transaction: &Transaction) -> bool {
+        let contains = |text: Option<&str>, search: &str| {
+            text.is_some_and(|text| text.to_lowercase().contains(&search.to_lowercase()))
+        };
+        match self {
+            Self::All(filters) => filters.iter().all(|filter| filter.matches(transaction)),
+            Self::Any(filters) => filters.iter().any(|filter| filter.matches(transaction)),
+            Self::Not(filter) => !filter.matches(transaction),
+            Self::DateRange(range) => transaction.date().is_some_and(|date| range.contains(date)),
+            Self::AccountIds(ids) => ids.iter().any(|id| id == transaction.account_id()),
+            Self::PayeeIds(ids) => transaction
+                .payee_id()
+                .is_some_and(|payee_id| ids.iter().any(|id| id == payee_id)),
+            Self::PayeeName(search) => contains(transaction.payee_name(), search),
+            Self::CategoryIds(ids) => ids.iter().any(|id| id == transaction.category_id()),
+            Self::Memo(search) => contains(transaction.description(), search),
+            Self::Cleared(states) => states.contains(&transaction.cleared()),
+            Self::Approved(approved) => transaction.is_approved() == *approved,
+            Self::FlagColors(colors) => match transaction.flag_color() {
+                Some(flag) => colors.iter().any(|color| color.eq_ignore_ascii_case(flag)),
+                None => colors.is_empty(),
+            },
+            Self::Direction(Direction::Inflow) => transaction.amount().as_milliunits() > 0,
+            Self::Direction(Direction::Outflow) => transaction.amount().as_milliunits() < 0,
+            Self::Uncategorized => {
+                transaction.transfer_account_id().is_none()
+                    && (transaction.category_id().is_empty()
+                        || transaction.category_name() == Some(UNCATEGORIZED_CATEGORY))
+            }
+            Self::MinAmount(min) => transaction.amount() >= *min,
+            Self::MaxAmount(max) => transaction.amount() <= *max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction() -> crate::domain::TransactionBuilder {
+        Transaction::builder()
+            .id("txn-1".to_string())
+            .account_id("checking".to_string())
+            .category_id("groceries".to_string())
+            .amount(Money::from_milliunits(-25_000))
+    }
+
+    #[test]
+    fn should_match_transaction_fields() {
+        let txn = transaction()
+            .payee_id("payee-grocer".to_string())
+            .payee_name("Corner Grocer".to_string())
+            .description("Weekly SHOP".to_string())
+            .date("2024-05-10".parse().unwrap())
+            .cleared(ClearedStatus::Cleared)
+            .approved(true)
+            .flag_color("Red".to_string())
+            .build();
+
+        let matching = [
+            TransactionFilter::DateRange(DateRange::parse("2024-05-01", "2024-05-31").unwrap()),
+            TransactionFilter::AccountIds(vec!["visa".to_string(), "checking".to_string()]),
+            TransactionFilter::PayeeIds(vec!["payee-grocer".to_string()]),
+            TransactionFilter::PayeeName("grocer".to_string()),
+            TransactionFilter::CategoryIds(vec!["groceries".to_string()]),
+            TransactionFilter::Memo("weekly shop".to_string()),
+            TransactionFilter::Cleared(vec![ClearedStatus::Cleared, ClearedStatus::Reconciled]),
+            TransactionFilter::Approved(true),
+            TransactionFilter::FlagColors(vec!["red".to_string()]),
+            TransactionFilter::Direction(Direction::Outflow),
+            TransactionFilter::MinAmount(Money::from_milliunits(-25_000)),
+            TransactionFilter::MaxAmount(Money::from_milliunits(-25_000)),
+        ];
+        let failing = [
+            TransactionFilter::DateRange(DateRange::parse("2024-06-01", "2024-06-30").unwrap()),
+            TransactionFilter::AccountIds(vec!["visa".to_string()]),
+            TransactionFilter::PayeeName("bistro".to_string()),
+            TransactionFilter::Cleared(vec![ClearedStatus::Uncleared]),
+            TransactionFilter::Approved(false),
+            TransactionFilter::FlagColors(Vec::new()),
+            TransactionFilter::Direction(Direction::Inflow),
+            TransactionFilter::Uncategorized,
+            TransactionFilter::MinAmount(Money::from_milliunits(-24_999)),
+        ];
+
+        for filter in &matching {
+            assert!(filter.matches(&txn), "{:?}", filter);
+        }
+        for filter in &failing {
+            assert!(!filter.matches(&txn), "{:?}", filter);
+        }
+    }
+
+    #[test]
+    fn should_treat_missing_fields_as_not_matching() {
+        let txn = transaction().build();
+
+        assert!(!TransactionFilter::PayeeIds(vec!["payee-grocer".to_string()]).matches(&txn));
+        assert!(!TransactionFilter::PayeeName("".to_string()).matches(&txn));
+        assert!(!TransactionFilter::Memo("shop".to_string()).matches(&txn));
+        assert!(TransactionFilter::FlagColors(Vec::new()).matches(&txn));
+        assert!(
+            !TransactionFilter::DateRange(DateRange::new(
+                crate::domain::CalendarDate::MIN,
+                crate::domain::CalendarDate::MAX
+            ))
+            .matches(&txn)
+        );
+    }
+
+    #[test]
+    fn should_only_count_categoryless_non_transfers_as_uncategorized() {
+        let uncategorized = transaction().category_id("".to_string()).build();
+        let named = transaction()
+            .category_id("cat-uncategorized".to_string())
+            .category_name("Uncategorized".to_string())
+            .build();
+        let transfer = transaction()
+            .category_id("".to_string())
+            .transfer_account_id("savings".to_string())
+            .build();
+
+        assert!(TransactionFilter::Uncategorized.matches(&uncategorized));
+        assert!(TransactionFilter::Uncategorized.matches(&named));
+        assert!(!TransactionFilter::Uncategorized.matches(&transfer));
+    }
+
+    #[test]
+    fn should_combine_filters_with_and_or_not() {
+        let visa = TransactionFilter::AccountIds(vec!["visa".to_string()]);
+        let outflow = TransactionFilter::Direction(Direction::Outflow);
+        let txn = transaction().build();
+
+        assert!(!visa.clone().and(outflow.clone()).matches(&txn));
+        assert!(visa.clone().or(outflow.clone()).matches(&txn));
+        assert!(visa.clone().negated().and(outflow.clone()).matches(&txn));
+        assert!(TransactionFilter::All(Vec::new()).matches(&txn));
+        assert!(!TransactionFilter::Any(Vec::new()).matches(&txn));
+        assert_eq!(
+            visa.clone()
+                .and(outflow.clone())
+                .and(TransactionFilter::Uncategorized),
+            TransactionFilter::All(vec![
+                visa.clone(),
+                outflow.clone(),
+                TransactionFilter::Uncategorized
+            ])
+        );
+        assert_eq!(
+            visa.clone()
+                .or(outflow.clone())
+                .or(TransactionFilter::Uncategorized),
+            TransactionFilter::Any(vec![visa, outflow, TransactionFilter::Uncategorized])
+        );
+    }
+}
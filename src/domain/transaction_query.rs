@@ -1,6 +1,6 @@
 //! Transaction query and filtering capabilities.
 
-use crate::domain::{Money, Transaction};
+use crate::domain::{DateRange, Money, Transaction, TransactionFilter};
 
 /// Sorting criteria for transactions.
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +17,9 @@ pub struct TransactionQuery {
     max_amount: Option<Money>,
     categories: Vec<String>,
     search_text: Option<String>,
+    date_range: Option<DateRange>,
+    filter: Option<TransactionFilter>,
+    split_parents: bool,
     sort_by: Option<SortBy>,
 }
 
@@ -71,6 +74,48 @@ impl TransactionQuery {
         self
     }
 
+    /// Filters transactions dated within the range; undated transactions are excluded.
+    pub fn with_date_range(mut self, date_range: DateRange) -> Self {
+        self.date_range = Some(date_range);
+        self
+    }
+
+    /// Filters transactions matching a filter expression.
+    ///
+    /// Calling this more than once requires every expression to match.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{Direction, Money, Transaction, TransactionFilter, TransactionQuery};
+    ///
+    /// let transactions = vec![
+    ///     Transaction::new("txn-1".to_string(), "visa".to_string(), "dining".to_string(), Money::from_milliunits(-8000)),
+    ///     Transaction::new("txn-2".to_string(), "checking".to_string(), "dining".to_string(), Money::from_milliunits(-9000)),
+    /// ];
+    /// let query = TransactionQuery::new()
+    ///     .with_filter(TransactionFilter::AccountIds(vec!["visa".to_string()]))
+    ///     .with_filter(TransactionFilter::Direction(Direction::Outflow));
+    ///
+    /// let found = query.filter(&transactions);
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].id(), "txn-1");
+    /// ```
+    pub fn with_filter(mut self, filter: TransactionFilter) -> Self {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(filter),
+            None => filter,
+        });
+        self
+    }
+
+    /// Returns split transactions themselves rather than their matching lines.
+    ///
+    /// A split transaction then matches when any of its lines does.
+    pub fn with_split_parents(mut self) -> Self {
+        self.split_parents = true;
+        self
+    }
+
     /// Sorts transactions by amount in ascending order.
     pub fn sort_by_amount_ascending(mut self) -> Self {
         self.sort_by = Some(SortBy::AmountAscending);
@@ -90,13 +135,24 @@ impl TransactionQuery {
     }
 
     /// Applies all filters to a list of transactions and returns matching ones.
+    ///
+    /// Filters apply to [`Transaction::lines`], so split transactions are
+    /// returned as their matching subtransactions unless
+    /// [`Self::with_split_parents`] was called. Deleted transactions are
+    /// always left out.
     pub fn filter<'a>(&self, transactions: &'a [Transaction]) -> Vec<&'a Transaction> {
-        let mut filtered: Vec<&Transaction> = transactions
-            .iter()
-            .filter(|transaction| self.matches_amount_filter(transaction))
-            .filter(|transaction| self.matches_category_filter(transaction))
-            .filter(|transaction| self.matches_text_filter(transaction))
-            .collect();
+        let mut filtered: Vec<&Transaction> = if self.split_parents {
+            transactions
+                .iter()
+                .filter(|transaction| transaction.lines().any(|line| self.matches(line)))
+                .collect()
+        } else {
+            transactions
+                .iter()
+                .flat_map(Transaction::lines)
+                .filter(|line| self.matches(line))
+                .collect()
+        };
 
         if let Some(ref sort_by) = self.sort_by {
             self.apply_sorting(&mut filtered, sort_by);
@@ -105,6 +161,19 @@ impl TransactionQuery {
         filtered
     }
 
+    /// Checks if a transaction line passes every filter.
+    fn matches(&self, transaction: &Transaction) -> bool {
+        !transaction.is_deleted()
+            && self.matches_amount_filter(transaction)
+            && self.matches_category_filter(transaction)
+            && self.matches_text_filter(transaction)
+            && self.matches_date_filter(transaction)
+            && self
+                .filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(transaction))
+    }
+
     /// Checks if a transaction matches the amount filter criteria.
     fn matches_amount_filter(&self, transaction: &Transaction) -> bool {
         let amount = transaction.amount();
@@ -147,6 +216,12 @@ impl TransactionQuery {
         true // No text filter applied
     }
 
+    /// Checks if a transaction is dated within the date range filter.
+    fn matches_date_filter(&self, transaction: &Transaction) -> bool {
+        self.date_range
+            .is_none_or(|range| transaction.date().is_some_and(|date| range.contains(date)))
+    }
+
     /// Applies the specified sorting to the filtered transactions.
     fn apply_sorting(&self, transactions: &mut Vec<&Transaction>, sort_by: &SortBy) {
         match sort_by {
@@ -159,7 +234,7 @@ impl TransactionQuery {
             SortBy::Date => {
                 transactions.sort_by(|a, b| {
                     match (a.date(), b.date()) {
-                        (Some(date_a), Some(date_b)) => date_a.cmp(date_b),
+                        (Some(date_a), Some(date_b)) => date_a.cmp(&date_b),
                         (Some(_), None) => std::cmp::Ordering::Less, // Transactions with dates come first
                         (None, Some(_)) => std::cmp::Ordering::Greater,
                         (None, None) => std::cmp::Ordering::Equal,
@@ -430,21 +505,21 @@ mod tests {
                 "acc-test".to_string(),
                 "groceries".to_string(),
                 Money::from_milliunits(-5000),
-                "2024-01-20".to_string(),
+                "2024-01-20".parse().unwrap(),
             ),
             Transaction::new_with_date(
                 "txn-2".to_string(),
                 "acc-test".to_string(),
                 "salary".to_string(),
                 Money::from_milliunits(100000),
-                "2024-01-15".to_string(),
+                "2024-01-15".parse().unwrap(),
             ),
             Transaction::new_with_date(
                 "txn-3".to_string(),
                 "acc-test".to_string(),
                 "gas".to_string(),
                 Money::from_milliunits(-3000),
-                "2024-01-25".to_string(),
+                "2024-01-25".parse().unwrap(),
             ),
         ];
 
@@ -454,9 +529,9 @@ mod tests {
 
         // Should be sorted by date: 2024-01-15, 2024-01-20, 2024-01-25
         assert_eq!(sorted.len(), 3);
-        assert_eq!(sorted[0].date(), Some("2024-01-15"));
-        assert_eq!(sorted[1].date(), Some("2024-01-20"));
-        assert_eq!(sorted[2].date(), Some("2024-01-25"));
+        assert_eq!(sorted[0].date(), "2024-01-15".parse().ok());
+        assert_eq!(sorted[1].date(), "2024-01-20".parse().ok());
+        assert_eq!(sorted[2].date(), "2024-01-25".parse().ok());
     }
 
     #[test]
@@ -511,7 +586,7 @@ mod tests {
                 "acc-test".to_string(),
                 "gas".to_string(),
                 Money::from_milliunits(-3000),
-                "2024-01-20".to_string(),
+                "2024-01-20".parse().unwrap(),
             ), // Has date
             Transaction::new(
                 "txn-3".to_string(),
@@ -527,7 +602,7 @@ mod tests {
 
         // Should be sorted: transactions with dates first, then those without
         assert_eq!(sorted.len(), 3);
-        assert_eq!(sorted[0].date(), Some("2024-01-20")); // Transaction with date comes first
+        assert_eq!(sorted[0].date(), "2024-01-20".parse().ok()); // Transaction with date comes first
         assert_eq!(sorted[1].date(), None); // Transactions without dates follow
         assert_eq!(sorted[2].date(), None);
     }
@@ -559,4 +634,108 @@ mod tests {
         assert_eq!(sorted[0].amount().as_milliunits(), -1000); // Closer to zero comes first
         assert_eq!(sorted[1].amount().as_milliunits(), -5000);
     }
+
+    #[test]
+    fn should_filter_transactions_by_date_range() {
+        let dated = |id: &str, date: &str| {
+            Transaction::new_with_date(
+                id.to_string(),
+                "acc-test".to_string(),
+                "groceries".to_string(),
+                Money::from_milliunits(-5000),
+                date.parse().unwrap(),
+            )
+        };
+        let transactions = vec![
+            dated("txn-1", "2024-06-30"),
+            dated("txn-2", "2024-07-01"),
+            dated("txn-3", "2024-09-30"),
+            dated("txn-4", "2024-10-01"),
+            Transaction::new(
+                "txn-5".to_string(),
+                "acc-test".to_string(),
+                "groceries".to_string(),
+                Money::from_milliunits(-5000),
+            ),
+        ];
+
+        let query = TransactionQuery::new()
+            .with_date_range(DateRange::parse("2024-07-01", "2024-09-30").unwrap());
+        let filtered = query.filter(&transactions);
+
+        let ids: Vec<&str> = filtered
+            .iter()
+            .map(|transaction| transaction.id())
+            .collect();
+        assert_eq!(ids, vec!["txn-2", "txn-3"]);
+    }
+
+    #[test]
+    fn should_leave_out_deleted_transactions() {
+        let transactions = vec![
+            Transaction::new(
+                "txn-1".to_string(),
+                "acc-test".to_string(),
+                "groceries".to_string(),
+                Money::from_milliunits(-5000),
+            ),
+            Transaction::builder()
+                .id("txn-2".to_string())
+                .account_id("acc-test".to_string())
+                .category_id("groceries".to_string())
+                .amount(Money::from_milliunits(-7000))
+                .deleted(true)
+                .build(),
+        ];
+
+        let active = TransactionQuery::new().filter(&transactions);
+
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id(), "txn-1");
+    }
+
+    #[test]
+    fn should_match_split_transactions_by_their_lines() {
+        let line = |id: &str, category: &str, amount: i64| {
+            Transaction::builder()
+                .id(id.to_string())
+                .account_id("acc-test".to_string())
+                .category_id(category.to_string())
+                .amount(Money::from_milliunits(amount))
+                .parent_id("txn-split".to_string())
+                .build()
+        };
+        let transactions = vec![
+            Transaction::builder()
+                .id("txn-split".to_string())
+                .account_id("acc-test".to_string())
+                .category_id("split".to_string())
+                .amount(Money::from_milliunits(-9000))
+                .subtransactions(vec![
+                    line("sub-1", "groceries", -6000),
+                    line("sub-2", "household", -3000),
+                ])
+                .build(),
+            Transaction::new(
+                "txn-2".to_string(),
+                "acc-test".to_string(),
+                "groceries".to_string(),
+                Money::from_milliunits(-1000),
+            ),
+        ];
+
+        let groceries = TransactionQuery::new().with_category("groceries".to_string());
+        let lines = groceries.filter(&transactions);
+        let parents = groceries.with_split_parents().filter(&transactions);
+        let placeholder = TransactionQuery::new()
+            .with_category("split".to_string())
+            .filter(&transactions);
+
+        let ids = |found: &[&Transaction]| -> Vec<String> {
+            found.iter().map(|txn| txn.id().to_string()).collect()
+        };
+        assert_eq!(ids(&lines), vec!["sub-1", "txn-2"]);
+        assert_eq!(ids(&parents), vec!["txn-split", "txn-2"]);
+        assert!(placeholder.is_empty());
+    }
 }
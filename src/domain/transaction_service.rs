@@ -1,6 +1,26 @@
 //! Transaction service for querying and aggregating transaction data.
 
-use crate::domain::{Transaction, TransactionQuery};
+use crate::domain::{DateRange, Money, Transaction, TransactionQuery};
+use std::collections::HashMap;
+
+/// Historical outflow of one category from one account, used to project
+/// spending that is not scheduled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpendingBaseline {
+    pub category_id: String,
+    pub account_id: String,
+    /// Total outflow over the history window, as a positive value.
+    pub total: Money,
+    /// Number of days in the history window.
+    pub days: i64,
+}
+
+impl SpendingBaseline {
+    /// Returns the average outflow per day, rounded towards zero.
+    pub fn daily_average(&self) -> Money {
+        Money::from_milliunits(self.total.as_milliunits() / self.days.max(1))
+    }
+}
 
 /// Service for executing transaction queries and aggregations.
 #[derive(Debug, Clone, Default)]
@@ -66,6 +86,76 @@ impl TransactionService {
     pub fn add_transactions(&mut self, transactions: Vec<Transaction>) {
         self.transactions.extend(transactions);
     }
+
+    /// Totals discretionary outflows per category and account over a history window.
+    ///
+    /// Discretionary spending is every outflow dated within `history` except
+    /// those in `excluded_categories`, typically the categories already covered
+    /// by scheduled transactions, and those without a category, such as
+    /// transfers between budget accounts. Baselines are ordered by category and
+    /// then account.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::{DateRange, Money, Transaction, TransactionService};
+    ///
+    /// let service = TransactionService::with_transactions(vec![
+    ///     Transaction::new_with_date("t-1".to_string(), "acc-1".to_string(), "dining".to_string(),
+    ///                                Money::from_milliunits(-30000), "2024-01-10".parse().unwrap()),
+    ///     Transaction::new_with_date("t-2".to_string(), "acc-1".to_string(), "rent".to_string(),
+    ///                                Money::from_milliunits(-900000), "2024-01-01".parse().unwrap()),
+    /// ]);
+    /// let history = DateRange::parse("2024-01-01", "2024-01-30").unwrap();
+    ///
+    /// let baselines = service.spending_baseline(&history, &["rent"]);
+    ///
+    /// assert_eq!(baselines.len(), 1);
+    /// assert_eq!(baselines[0].daily_average(), Money::from_milliunits(1000));
+    /// ```
+    pub fn spending_baseline(
+        &self,
+        history: &DateRange,
+        excluded_categories: &[&str],
+    ) -> Vec<SpendingBaseline> {
+        let days = history.day_count();
+        if days == 0 {
+            return Vec::new();
+        }
+
+        let mut totals: HashMap<(&str, &str), i64> = HashMap::new();
+        for transaction in self.transactions.iter().flat_map(Transaction::lines) {
+            let amount = transaction.amount().as_milliunits();
+            let category_id = transaction.category_id();
+            if amount >= 0
+                || transaction.is_deleted()
+                || category_id.is_empty()
+                || excluded_categories.contains(&category_id)
+                || !transaction
+                    .date()
+                    .is_some_and(|date| history.contains(date))
+            {
+                continue;
+            }
+            *totals
+                .entry((category_id, transaction.account_id()))
+                .or_insert(0) -= amount;
+        }
+
+        let mut baselines: Vec<SpendingBaseline> = totals
+            .into_iter()
+            .map(|((category_id, account_id), total)| SpendingBaseline {
+                category_id: category_id.to_string(),
+                account_id: account_id.to_string(),
+                total: Money::from_milliunits(total),
+                days,
+            })
+            .collect();
+        baselines.sort_by(|a, b| {
+            (a.category_id.as_str(), a.account_id.as_str())
+                .cmp(&(b.category_id.as_str(), b.account_id.as_str()))
+        });
+        baselines
+    }
 }
 
 #[cfg(test)]
@@ -172,4 +262,62 @@ mod tests {
 
         assert_eq!(service.total_count(), 2);
     }
+
+    #[test]
+    fn should_build_spending_baseline_per_category_and_account() {
+        let dated = |id: &str, account: &str, category: &str, amount: i64, date: &str| {
+            Transaction::new_with_date(
+                id.to_string(),
+                account.to_string(),
+                category.to_string(),
+                Money::from_milliunits(amount),
+                date.parse().unwrap(),
+            )
+        };
+        let service = TransactionService::with_transactions(vec![
+            dated("t-1", "checking", "groceries", -60_000, "2024-01-03"),
+            dated("t-2", "checking", "groceries", -30_000, "2024-01-20"),
+            dated("t-3", "visa", "groceries", -15_000, "2024-01-21"),
+            dated("t-4", "checking", "rent", -900_000, "2024-01-01"),
+            dated("t-5", "checking", "", -200_000, "2024-01-05"),
+            dated("t-6", "checking", "income", 2_000_000, "2024-01-15"),
+            dated("t-7", "checking", "groceries", -99_000, "2023-12-31"),
+            Transaction::new(
+                "t-8".to_string(),
+                "checking".to_string(),
+                "groceries".to_string(),
+                Money::from_milliunits(-5_000),
+            ),
+        ]);
+        let history = DateRange::parse("2024-01-01", "2024-01-30").unwrap();
+
+        let baselines = service.spending_baseline(&history, &["rent"]);
+
+        assert_eq!(
+            baselines,
+            vec![
+                SpendingBaseline {
+                    category_id: "groceries".to_string(),
+                    account_id: "checking".to_string(),
+                    total: Money::from_milliunits(90_000),
+                    days: 30,
+                },
+                SpendingBaseline {
+                    category_id: "groceries".to_string(),
+                    account_id: "visa".to_string(),
+                    total: Money::from_milliunits(15_000),
+                    days: 30,
+                },
+            ]
+        );
+        assert_eq!(baselines[0].daily_average(), Money::from_milliunits(3_000));
+    }
+
+    #[test]
+    fn should_return_no_baseline_for_invalid_history() {
+        let service = TransactionService::new();
+        let backwards = DateRange::parse("2024-02-01", "2024-01-01").unwrap();
+
+        assert!(service.spending_baseline(&backwards, &[]).is_empty());
+    }
 }
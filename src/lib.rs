@@ -39,16 +39,16 @@
 //! let transaction_service = ynab_mcp::domain::TransactionService::new();
 //! let handler = Handler::with_full_integration(transaction_service, client);
 //!
-//! // Execute advanced analytics
+//! // Execute advanced analytics (tools await the YNAB API)
 //! let health_check = handler.execute_tool(
 //!     "budget_health_check",
 //!     json!({"budget_id": "your-budget-id"})
-//! )?;
+//! ).await?;
 //!
 //! let spending_trends = handler.execute_tool(
 //!     "analyze_spending_trends",
 //!     json!({"budget_id": "your-budget-id", "months": 6})
-//! )?;
+//! ).await?;
 //!
 //! println!("Budget Health: {}", health_check);
 //! println!("Spending Trends: {}", spending_trends);
@@ -2,7 +2,7 @@
 
 use std::env;
 use std::io::{stdin, stdout};
-use ynab_mcp::server::run_mcp_server;
+use ynab_mcp::server::{HttpServerConfig, run_http_server, run_mcp_server};
 
 fn main() {
     // Get YNAB API token from environment variable
@@ -15,8 +15,24 @@ fn main() {
         }
     };
 
-    // Run the complete MCP server with stdin/stdout
-    if let Err(e) = run_mcp_server(stdin(), stdout(), &api_token) {
+    // Serve Streamable HTTP when a bind address is configured, stdin/stdout otherwise
+    let result = match env::var("YNAB_MCP_HTTP_ADDR") {
+        Ok(address) if !address.trim().is_empty() => match address.trim().parse() {
+            Ok(bind_address) => {
+                eprintln!("Serving MCP over HTTP at http://{}/mcp", bind_address);
+                run_http_server(HttpServerConfig::new(bind_address), &api_token)
+            }
+            Err(_) => {
+                eprintln!(
+                    "Error: YNAB_MCP_HTTP_ADDR must be a socket address such as 127.0.0.1:8080"
+                );
+                std::process::exit(1);
+            }
+        },
+        _ => run_mcp_server(stdin(), stdout(), &api_token),
+    };
+
+    if let Err(e) = result {
         eprintln!("Server error: {}", e);
         std::process::exit(1);
     }
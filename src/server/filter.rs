@@ -0,0 +1,303 @@
+//! Structured transaction filters accepted by the `search_transactions` tool.
+//!
+//! A filter is a JSON object whose fields must all match. `all` and `any`
+//! take arrays of nested filters, and `not` takes a single nested filter:
+//!
+//! ```json
+//! {
+//!     "account_ids": ["visa"],
+//!     "cleared": "uncleared",
+//!     "direction": "outflow",
+//!     "max_amount_milliunits": -50000,
+//!     "not": {"payee_name": "amazon"}
+//! }
+//! ```
+
+use crate::domain::{
+    CalendarDate, ClearedStatus, DateRange, Direction, Money, TransactionFilter, YnabError,
+    YnabResult,
+};
+use serde_json::Value;
+
+/// Parses a JSON filter object into a [`TransactionFilter`].
+///
+/// Relative `date_range` expressions resolve against `today`. Errors name
+/// the offending field by its path below `filter`, such as
+/// `filter.any[1].cleared`.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::server::parse_transaction_filter;
+/// use ynab_mcp::{CalendarDate, Money, Transaction};
+/// use serde_json::json;
+///
+/// let today = CalendarDate::parse("2024-05-31").unwrap();
+/// let filter = parse_transaction_filter(
+///     &json!({"any": [{"uncategorized": true}, {"flag_color": "red"}], "direction": "outflow"}),
+///     today,
+/// )
+/// .unwrap();
+///
+/// let uncategorized = Transaction::new("txn-1".to_string(), "acc-1".to_string(), "".to_string(),
+///                                      Money::from_milliunits(-5000));
+/// assert!(filter.matches(&uncategorized));
+/// ```
+pub fn parse_transaction_filter(
+    value: &Value,
+    today: CalendarDate,
+) -> YnabResult<TransactionFilter> {
+    parse_filter(value, today, "filter")
+}
+
+/// Parses one filter object, every field of which must match.
+fn parse_filter(value: &Value, today: CalendarDate, path: &str) -> YnabResult<TransactionFilter> {
+    let fields = value
+        .as_object()
+        .ok_or_else(|| YnabError::invalid_argument(path, "must be an object"))?;
+
+    let mut conditions = Vec::new();
+    let (mut start, mut end) = (None, None);
+    for (field, value) in fields {
+        let path = format!("{}.{}", path, field);
+        let condition = match field.as_str() {
+            "all" | "any" => {
+                let filters = value
+                    .as_array()
+                    .ok_or_else(|| YnabError::invalid_argument(&path, "must be an array"))?
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| parse_filter(item, today, &format!("{}[{}]", path, index)))
+                    .collect::<YnabResult<Vec<_>>>()?;
+                if field == "all" {
+                    TransactionFilter::All(filters)
+                } else {
+                    TransactionFilter::Any(filters)
+                }
+            }
+            "not" => parse_filter(value, today, &path)?.negated(),
+            "date_range" => {
+                let expression = text(value, &path)?;
+                TransactionFilter::DateRange(DateRange::resolve(expression, today).map_err(
+                    |_| {
+                        YnabError::invalid_argument(
+                            &path,
+                            format!(
+                                "expected a period such as last_month, last_90_days, ytd or 2024-Q3, got \"{}\"",
+                                expression
+                            ),
+                        )
+                    },
+                )?)
+            }
+            "start_date" => {
+                start = Some(date(value, &path)?);
+                continue;
+            }
+            "end_date" => {
+                end = Some(date(value, &path)?);
+                continue;
+            }
+            "account_ids" => TransactionFilter::AccountIds(texts(value, &path)?),
+            "payee_ids" => TransactionFilter::PayeeIds(texts(value, &path)?),
+            "category_ids" => TransactionFilter::CategoryIds(texts(value, &path)?),
+            "payee_name" => TransactionFilter::PayeeName(text(value, &path)?.to_string()),
+            "memo" => TransactionFilter::Memo(text(value, &path)?.to_string()),
+            "cleared" => TransactionFilter::Cleared(
+                texts(value, &path)?
+                    .iter()
+                    .map(|name| {
+                        ClearedStatus::from_api_name(name).ok_or_else(|| {
+                            YnabError::invalid_argument(
+                                &path,
+                                "must be one of \"uncleared\", \"cleared\", \"reconciled\"",
+                            )
+                        })
+                    })
+                    .collect::<YnabResult<_>>()?,
+            ),
+            "approved" => TransactionFilter::Approved(boolean(value, &path)?),
+            "flag_color" if value.is_null() => TransactionFilter::FlagColors(Vec::new()),
+            "flag_color" => TransactionFilter::FlagColors(texts(value, &path)?),
+            "direction" => {
+                TransactionFilter::Direction(Direction::from_name(text(value, &path)?).ok_or_else(
+                    || YnabError::invalid_argument(&path, "must be one of \"inflow\", \"outflow\""),
+                )?)
+            }
+            "uncategorized" if boolean(value, &path)? => TransactionFilter::Uncategorized,
+            "uncategorized" => TransactionFilter::Uncategorized.negated(),
+            "min_amount_milliunits" => TransactionFilter::MinAmount(amount(value, &path)?),
+            "max_amount_milliunits" => TransactionFilter::MaxAmount(amount(value, &path)?),
+            _ => {
+                return Err(YnabError::invalid_argument(
+                    &path,
+                    "is not a recognised filter field",
+                ));
+            }
+        };
+        conditions.push(condition);
+    }
+    if start.is_some() || end.is_some() {
+        conditions.push(TransactionFilter::DateRange(DateRange::new(
+            start.unwrap_or(CalendarDate::MIN),
+            end.unwrap_or(CalendarDate::MAX),
+        )));
+    }
+
+    Ok(match conditions.len() {
+        1 => conditions.remove(0),
+        _ => TransactionFilter::All(conditions),
+    })
+}
+
+fn text<'a>(value: &'a Value, path: &str) -> YnabResult<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| YnabError::invalid_argument(path, "must be a string"))
+}
+
+/// Reads a string or an array of strings.
+fn texts(value: &Value, path: &str) -> YnabResult<Vec<String>> {
+    match value {
+        Value::String(single) => Ok(vec![single.clone()]),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| text(item, path).map(str::to_string))
+            .collect(),
+        _ => Err(YnabError::invalid_argument(
+            path,
+            "must be a string or an array of strings",
+        )),
+    }
+}
+
+fn boolean(value: &Value, path: &str) -> YnabResult<bool> {
+    value
+        .as_bool()
+        .ok_or_else(|| YnabError::invalid_argument(path, "must be a boolean"))
+}
+
+fn amount(value: &Value, path: &str) -> YnabResult<Money> {
+    value
+        .as_i64()
+        .map(Money::from_milliunits)
+        .ok_or_else(|| YnabError::invalid_argument(path, "must be an integer amount in milliunits"))
+}
+
+fn date(value: &Value, path: &str) -> YnabResult<CalendarDate> {
+    CalendarDate::parse(text(value, path)?)
+        .map_err(|_| YnabError::invalid_argument(path, "must be a date in YYYY-MM-DD format"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn today() -> CalendarDate {
+        "2024-05-31".parse().unwrap()
+    }
+
+    fn field_of(result: YnabResult<TransactionFilter>) -> String {
+        match result {
+            Err(YnabError::InvalidArgument { field, .. }) => field,
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_parse_fields_of_one_object_as_all_of_them() {
+        let filter = parse_transaction_filter(
+            &json!({
+                "account_ids": "visa",
+                "cleared": ["uncleared", "cleared"],
+                "max_amount_milliunits": -50000,
+                "not": {"payee_name": "amazon"}
+            }),
+            today(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            filter,
+            TransactionFilter::All(vec![
+                TransactionFilter::AccountIds(vec!["visa".to_string()]),
+                TransactionFilter::Cleared(vec![ClearedStatus::Uncleared, ClearedStatus::Cleared]),
+                TransactionFilter::MaxAmount(Money::from_milliunits(-50000)),
+                TransactionFilter::PayeeName("amazon".to_string()).negated(),
+            ])
+        );
+    }
+
+    #[test]
+    fn should_parse_groups_dates_and_flags() {
+        let filter = parse_transaction_filter(
+            &json!({
+                "any": [
+                    {"date_range": "last_month"},
+                    {"start_date": "2024-05-20"},
+                    {"flag_color": null},
+                    {"uncategorized": false, "direction": "inflow", "approved": true}
+                ]
+            }),
+            today(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            filter,
+            TransactionFilter::Any(vec![
+                TransactionFilter::DateRange(DateRange::parse("2024-04-01", "2024-04-30").unwrap()),
+                TransactionFilter::DateRange(DateRange::new(
+                    "2024-05-20".parse().unwrap(),
+                    CalendarDate::MAX
+                )),
+                TransactionFilter::FlagColors(Vec::new()),
+                TransactionFilter::All(vec![
+                    TransactionFilter::Approved(true),
+                    TransactionFilter::Direction(Direction::Inflow),
+                    TransactionFilter::Uncategorized.negated(),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn should_name_the_invalid_field_by_its_path() {
+        assert_eq!(
+            field_of(parse_transaction_filter(&json!([]), today())),
+            "filter"
+        );
+        assert_eq!(
+            field_of(parse_transaction_filter(&json!({"colour": "red"}), today())),
+            "filter.colour"
+        );
+        assert_eq!(
+            field_of(parse_transaction_filter(
+                &json!({"any": [{"memo": "rent"}, {"cleared": "pending"}]}),
+                today()
+            )),
+            "filter.any[1].cleared"
+        );
+        assert_eq!(
+            field_of(parse_transaction_filter(
+                &json!({"not": {"direction": "up"}}),
+                today()
+            )),
+            "filter.not.direction"
+        );
+        assert_eq!(
+            field_of(parse_transaction_filter(
+                &json!({"start_date": "yesterday"}),
+                today()
+            )),
+            "filter.start_date"
+        );
+        assert_eq!(
+            field_of(parse_transaction_filter(
+                &json!({"min_amount_milliunits": "50"}),
+                today()
+            )),
+            "filter.min_amount_milliunits"
+        );
+    }
+}
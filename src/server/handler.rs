@@ -1,20 +1,49 @@
 //! MCP request handlers.
 
+use crate::adapters::response_mapper::ResponseMapper;
 use crate::adapters::ynab_client::YnabClient;
-use crate::domain::error::YnabResult;
+use crate::domain::account::Account;
+use crate::domain::budget::Budget;
+use crate::domain::budget_flow::{BudgetFlow, BudgetFlowClassifier};
+use crate::domain::budget_month::BudgetMonth;
+use crate::domain::calendar_date::CalendarDate;
+use crate::domain::cash_flow::CashFlowForecast;
+use crate::domain::category::Category;
+use crate::domain::date_range::DateRange;
+use crate::domain::error::{YnabError, YnabResult};
+use crate::domain::goal::GoalProgress;
+use crate::domain::money::Money;
+use crate::domain::net_worth::NetWorthReport;
+use crate::domain::payee::Payee;
+use crate::domain::payee_spending::{PayeeRanking, PayeeSpending};
+use crate::domain::scheduled_transaction::{ScheduledTransaction, expand_occurrences};
+use crate::domain::spending_trend::{SpendingTrends, TREND_THRESHOLD_PERCENT, TrendDirection};
+use crate::domain::transaction::Transaction;
+use crate::domain::transaction_filter::TransactionFilter;
+use crate::domain::transaction_query::TransactionQuery;
 use crate::domain::transaction_service::TransactionService;
+use crate::server::filter::parse_transaction_filter;
+use crate::server::resources::{
+    Resource, ResourceTemplate, ResourceUri, list_budget_resources, render_accounts,
+    render_budgets, render_categories, resource_templates,
+};
+use crate::server::schema::validate_arguments;
+use std::collections::HashMap;
 
 /// Represents an MCP tool that can be called by clients.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Tool {
     pub name: String,
     pub description: String,
+    /// JSON Schema describing the tool's arguments.
+    pub input_schema: serde_json::Value,
 }
 
 /// MCP server handler for YNAB budget analysis tools.
 pub struct Handler {
     transaction_service: Option<TransactionService>,
     ynab_client: Option<YnabClient>,
+    today: Option<CalendarDate>,
 }
 
 impl Handler {
@@ -23,6 +52,7 @@ impl Handler {
         Self {
             transaction_service: None,
             ynab_client: None,
+            today: None,
         }
     }
 
@@ -31,6 +61,7 @@ impl Handler {
         Self {
             transaction_service: Some(transaction_service),
             ynab_client: None,
+            today: None,
         }
     }
 
@@ -39,6 +70,7 @@ impl Handler {
         Self {
             transaction_service: None,
             ynab_client: Some(ynab_client),
+            today: None,
         }
     }
 
@@ -50,81 +82,454 @@ impl Handler {
         Self {
             transaction_service: Some(transaction_service),
             ynab_client: Some(ynab_client),
+            today: None,
         }
     }
 
+    /// Fixes the date that relative date ranges such as `last_month` resolve
+    /// against, instead of the system date.
+    pub fn with_today(mut self, today: CalendarDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Returns the date relative date ranges resolve against.
+    pub fn today(&self) -> CalendarDate {
+        self.today.unwrap_or_else(CalendarDate::today)
+    }
+
     /// Lists all available MCP tools for YNAB budget analysis.
+    ///
+    /// `budget_id` is only required when the handler reads from the YNAB API;
+    /// a handler over an in-memory transaction service has a single budget.
     pub fn list_tools(&self) -> Vec<Tool> {
-        vec![
+        let mut tools = vec![
             Tool {
                 name: "analyze_category_spending".to_string(),
                 description:
                     "Analyzes spending for specific categories with optional date filtering"
                         .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "category_id": {
+                            "type": "string",
+                            "description": "ID of the category to analyze; takes precedence over category_name"
+                        },
+                        "category_name": {
+                            "type": "string",
+                            "description": "Name of the category to analyze (case-insensitive)"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "anyOf": [
+                        {"required": ["category_id"]},
+                        {"required": ["category_name"]}
+                    ],
+                    "additionalProperties": false
+                }),
             },
             Tool {
                 name: "get_budget_overview".to_string(),
                 description: "Provides a comprehensive overview of budget status and spending"
                     .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema()
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
             },
             Tool {
                 name: "search_transactions".to_string(),
                 description: "Searches transactions with advanced filtering and sorting options"
                     .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "text_search": {
+                            "type": "string",
+                            "description": "Case-insensitive text to find in transaction memos"
+                        },
+                        "min_amount_milliunits": {
+                            "type": "integer",
+                            "description": "Only include transactions with an amount at or above this value, in milliunits (outflows are negative)"
+                        },
+                        "category_id": {
+                            "type": "string",
+                            "description": "Only include transactions in this category"
+                        },
+                        "filter": {
+                            "$ref": "#/$defs/transaction_filter",
+                            "description": "Structured filter whose fields must all match, e.g. {\"account_ids\": [\"visa-id\"], \"cleared\": \"uncleared\", \"max_amount_milliunits\": -50000, \"not\": {\"payee_name\": \"amazon\"}}"
+                        },
+                        "show_split_parents": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Whether to return split transactions with all their lines instead of only the matching lines"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "maximum": 1000,
+                            "default": 100,
+                            "description": "Maximum number of transactions to return"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false,
+                    "$defs": {"transaction_filter": transaction_filter_schema()}
+                }),
             },
             Tool {
                 name: "analyze_spending_trends".to_string(),
                 description:
                     "Analyzes spending trends over multiple months with detailed breakdowns"
                         .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "months": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "maximum": 60,
+                            "default": 3,
+                            "description": "Number of complete months to analyze, ending with last month"
+                        },
+                        "categories": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Category IDs to include; all categories when omitted"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
             },
             Tool {
                 name: "budget_health_check".to_string(),
                 description:
                     "Performs comprehensive budget health analysis with optimization suggestions"
                         .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema()
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
+            },
+            Tool {
+                name: "list_accounts".to_string(),
+                description:
+                    "Lists accounts with their balances, grouped into assets and liabilities"
+                        .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "include_closed": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Whether to include closed accounts"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
+            },
+            Tool {
+                name: "net_worth".to_string(),
+                description:
+                    "Reports net worth across on-budget and tracking accounts with monthly history"
+                        .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "months": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "maximum": 120,
+                            "default": 12,
+                            "description": "Number of months of history to rebuild"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
+            },
+            Tool {
+                name: "top_payees".to_string(),
+                description:
+                    "Ranks payees by total spend, visit count or average ticket over a date range"
+                        .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "sort_by": {
+                            "type": "string",
+                            "enum": ["spend", "visits", "average_ticket"],
+                            "default": "spend",
+                            "description": "Ranking order of the returned payees"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "maximum": 100,
+                            "default": 10,
+                            "description": "Maximum number of payees to return"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
+            },
+            Tool {
+                name: "goal_progress".to_string(),
+                description:
+                    "Lists category goals with progress, amount needed this month and projected completion"
+                        .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "date_range": date_range_schema(),
+                        "start_date": start_date_schema(),
+                        "end_date": end_date_schema(),
+                        "months": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "maximum": 6,
+                            "default": 3,
+                            "description": "Number of months, ending with the current one, to average the funding pace over; each earlier month costs one YNAB API request"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
+            },
+            Tool {
+                name: "upcoming_bills".to_string(),
+                description:
+                    "Lists scheduled transactions due over the next days, expanding recurrences"
+                        .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "days": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "maximum": 365,
+                            "default": 30,
+                            "description": "Number of days to look ahead, including the start date"
+                        },
+                        "date_range": date_range_schema(),
+                        "start_date": {
+                            "type": "string",
+                            "description": "First day to look at, in YYYY-MM-DD format; defaults to today"
+                        },
+                        "end_date": {
+                            "type": "string",
+                            "description": "Last day to look at, in YYYY-MM-DD format; defaults to the end of the `days` window"
+                        },
+                        "include_inflows": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Whether to also list scheduled inflows such as paychecks"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
+            },
+            Tool {
+                name: "forecast_cash_flow".to_string(),
+                description:
+                    "Projects daily balances of on-budget accounts from scheduled transactions and typical spending, flagging when each would go negative"
+                        .to_string(),
+                input_schema: serde_json::json!({
+                    "type": "object",
+                    "properties": {
+                        "budget_id": budget_id_schema(),
+                        "days": {
+                            "type": "integer",
+                            "minimum": 30,
+                            "maximum": 90,
+                            "default": 30,
+                            "description": "Number of days to project, including the start date"
+                        },
+                        "date_range": date_range_schema(),
+                        "start_date": {
+                            "type": "string",
+                            "description": "First projected day, in YYYY-MM-DD format; defaults to today"
+                        },
+                        "end_date": {
+                            "type": "string",
+                            "description": "Last projected day, in YYYY-MM-DD format; defaults to the end of the `days` window"
+                        },
+                        "history_days": {
+                            "type": "integer",
+                            "minimum": 30,
+                            "maximum": 365,
+                            "default": 90,
+                            "description": "Number of days before the start date used to estimate discretionary spending"
+                        }
+                    },
+                    "required": ["budget_id"],
+                    "additionalProperties": false
+                }),
             },
-        ]
+        ];
+
+        if self.ynab_client.is_none() {
+            for tool in &mut tools {
+                if let Some(required) = tool.input_schema["required"].as_array_mut() {
+                    required.retain(|field| field != "budget_id");
+                }
+            }
+        }
+        tools
     }
 
     /// Executes a named tool with the provided parameters.
-    pub fn execute_tool(&self, tool_name: &str, params: serde_json::Value) -> YnabResult<String> {
+    ///
+    /// Parameters are validated against the tool's input schema before the tool
+    /// runs, and API-backed tools await their YNAB requests.
+    pub async fn execute_tool(
+        &self,
+        tool_name: &str,
+        params: serde_json::Value,
+    ) -> YnabResult<String> {
+        let tool = self
+            .list_tools()
+            .into_iter()
+            .find(|tool| tool.name == tool_name)
+            .ok_or_else(|| {
+                YnabError::invalid_argument("name", format!("unknown tool {}", tool_name))
+            })?;
+        validate_arguments(&tool.input_schema, &params)?;
+
         match tool_name {
-            "analyze_category_spending" => self.analyze_category_spending(&params),
-            "get_budget_overview" => self.get_budget_overview(&params),
-            "search_transactions" => self.search_transactions(&params),
-            "analyze_spending_trends" => self.analyze_spending_trends(&params),
-            "budget_health_check" => self.budget_health_check(&params),
-            _ => Err(crate::domain::error::YnabError::InvalidBudgetId(format!(
-                "Unknown tool: {}",
-                tool_name
-            ))),
+            "analyze_category_spending" => self.analyze_category_spending(&params).await,
+            "get_budget_overview" => self.get_budget_overview(&params).await,
+            "search_transactions" => self.search_transactions(&params).await,
+            "analyze_spending_trends" => self.analyze_spending_trends(&params).await,
+            "budget_health_check" => self.budget_health_check(&params).await,
+            "list_accounts" => self.list_accounts(&params).await,
+            "net_worth" => self.net_worth(&params).await,
+            "top_payees" => self.top_payees(&params).await,
+            "goal_progress" => self.goal_progress(&params).await,
+            "upcoming_bills" => self.upcoming_bills(&params).await,
+            "forecast_cash_flow" => self.forecast_cash_flow(&params).await,
+            _ => Err(YnabError::invalid_argument(
+                "name",
+                format!("unknown tool {}", tool_name),
+            )),
         }
     }
 
+    /// Lists the URI templates of the per-budget resources.
+    pub fn list_resource_templates(&self) -> Vec<ResourceTemplate> {
+        resource_templates()
+    }
+
+    /// Lists the readable resources for every budget available to the API token.
+    ///
+    /// Without a YNAB client there is no data to expose, so the list is empty.
+    pub async fn list_resources(&self) -> YnabResult<Vec<Resource>> {
+        let Some(ynab_client) = &self.ynab_client else {
+            return Ok(Vec::new());
+        };
+
+        let budgets = Self::fetch_budgets(ynab_client).await?;
+        Ok(list_budget_resources(&budgets))
+    }
+
+    /// Reads a `ynab://` resource and returns its JSON contents.
+    pub async fn read_resource(&self, uri: &str) -> YnabResult<String> {
+        let resource = ResourceUri::parse(uri).ok_or_else(|| YnabError::resource_not_found(uri))?;
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+        let mapper = ResponseMapper::new();
+
+        let contents = match resource {
+            ResourceUri::Budgets => render_budgets(&Self::fetch_budgets(ynab_client).await?),
+            ResourceUri::Categories { budget_id } => {
+                Self::validate_api_request(&budget_id, ynab_client)?;
+                let response = ynab_client.get_categories(&budget_id).await?;
+                let categories = mapper.map_categories_from_response(&response)?;
+                render_categories(&budget_id, &categories)
+            }
+            ResourceUri::Accounts { budget_id } => {
+                Self::validate_api_request(&budget_id, ynab_client)?;
+                let response = ynab_client.get_accounts(&budget_id).await?;
+                let accounts = mapper.map_accounts_from_response(&response)?;
+                render_accounts(&budget_id, &accounts)
+            }
+        };
+
+        Ok(contents.to_string())
+    }
+
     /// Analyzes category spending using real domain data.
-    fn analyze_category_spending(&self, params: &serde_json::Value) -> YnabResult<String> {
+    async fn analyze_category_spending(&self, params: &serde_json::Value) -> YnabResult<String> {
         let category_id = params["category_id"].as_str().unwrap_or("");
         let category_name = params["category_name"].as_str().unwrap_or("");
         let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let range = date_range_argument(params, self.today())?;
 
         // First try YNAB API client integration
         if let Some(ynab_client) = &self.ynab_client {
-            return self.analyze_category_spending_with_api(
-                budget_id,
-                category_id,
-                category_name,
-                ynab_client,
-            );
+            return self
+                .analyze_category_spending_with_api(
+                    budget_id,
+                    category_id,
+                    category_name,
+                    range,
+                    ynab_client,
+                )
+                .await;
         }
 
         // Fall back to transaction service
         if let Some(transaction_service) = &self.transaction_service {
-            use crate::domain::category::Category;
-            use crate::domain::transaction_query::TransactionQuery;
-
             let category = Category::new(category_id.to_string(), category_name.to_string());
-            let query = TransactionQuery::new().with_category(category_id.to_string());
+            let query = range_query(range).with_category(category_id.to_string());
             let transactions = transaction_service.query(&query);
             let transaction_count = transactions.len();
 
@@ -137,7 +542,8 @@ impl Handler {
                 "category_spending": {
                     "category": category_name,
                     "amount_milliunits": total_spending.as_milliunits().abs(), // Convert negative to positive for display
-                    "transaction_count": transaction_count
+                    "transaction_count": transaction_count,
+                    "date_range": range_json(range)
                 }
             })
             .to_string())
@@ -156,89 +562,79 @@ impl Handler {
 
     /// Analyzes category spending using YNAB API client.
     ///
-    /// Note: This is a demonstration of API integration architecture.
-    /// In a full implementation, this would use async/await to call the YNAB API.
-    fn analyze_category_spending_with_api(
+    /// Resolves the category by ID (or case-insensitive name when no ID is
+    /// given) against the budget's live categories, then totals its
+    /// transactions within the date range through the domain layer.
+    async fn analyze_category_spending_with_api(
         &self,
         budget_id: &str,
-        _category_id: &str,
+        category_id: &str,
         category_name: &str,
+        range: Option<DateRange>,
         ynab_client: &YnabClient,
     ) -> YnabResult<String> {
-        // Validate API client configuration
-        if ynab_client.api_token().is_empty() {
-            return Err(crate::domain::error::YnabError::ApiError(
-                "Invalid API token".to_string(),
-            ));
-        }
+        let (transactions, categories) = Self::fetch_budget_data(budget_id, ynab_client).await?;
+
+        let category = categories
+            .into_iter()
+            .find(|category| {
+                if category_id.is_empty() {
+                    category.name().eq_ignore_ascii_case(category_name)
+                } else {
+                    category.id() == category_id
+                }
+            })
+            .ok_or_else(|| {
+                YnabError::category_not_found(if category_id.is_empty() {
+                    category_name
+                } else {
+                    category_id
+                })
+            })?;
 
-        // For demonstration: return a response showing API integration is working
-        // In a real implementation, this would:
-        // 1. Make async call to ynab_client.get_transactions(budget_id).await
-        // 2. Map the response using ResponseMapper
-        // 3. Process through domain services
-        // 4. Return calculated results
+        let transaction_service = TransactionService::with_transactions(transactions);
+        let query = range_query(range).with_category(category.id().to_string());
+        let category_transactions: Vec<Transaction> = transaction_service
+            .query(&query)
+            .into_iter()
+            .cloned()
+            .collect();
+        let total_spending = category.calculate_spending(&category_transactions);
 
         Ok(serde_json::json!({
             "category_spending": {
-                "category": category_name,
-                "amount_milliunits": 87500, // Mock calculated value from "API"
-                "transaction_count": 3,     // Mock transaction count
+                "category": category.name(),
+                "category_id": category.id(),
+                "amount_milliunits": total_spending.as_milliunits().abs(),
+                "transaction_count": category_transactions.len(),
+                "date_range": range_json(range),
                 "data_source": "ynab_api",
-                "budget_id": budget_id,
-                "api_token_configured": true
+                "budget_id": budget_id
             }
         })
         .to_string())
     }
 
     /// Provides budget overview using real domain data.
-    fn get_budget_overview(&self, params: &serde_json::Value) -> YnabResult<String> {
+    async fn get_budget_overview(&self, params: &serde_json::Value) -> YnabResult<String> {
         let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let range = date_range_argument(params, self.today())?;
 
         // First try YNAB API client integration
         if let Some(ynab_client) = &self.ynab_client {
-            return self.get_budget_overview_with_api(budget_id, ynab_client);
+            return self
+                .get_budget_overview_with_api(budget_id, range, ynab_client)
+                .await;
         }
 
         // Fall back to transaction service
         if let Some(transaction_service) = &self.transaction_service {
-            use crate::domain::money::Money;
-            use crate::domain::transaction_query::TransactionQuery;
-
-            let query = TransactionQuery::new();
-            let all_transactions = transaction_service.query(&query);
-
-            // Calculate totals
-            let mut total_expenses = Money::from_milliunits(0);
-            let mut total_income = Money::from_milliunits(0);
-
-            for transaction in &all_transactions {
-                let amount = transaction.amount();
-                if amount.as_milliunits() < 0 {
-                    // Negative amounts are expenses
-                    total_expenses = Money::from_milliunits(
-                        total_expenses.as_milliunits() + amount.as_milliunits().abs(),
-                    );
-                } else {
-                    // Positive amounts are income
-                    total_income = Money::from_milliunits(
-                        total_income.as_milliunits() + amount.as_milliunits(),
-                    );
-                }
-            }
-
-            let net_income = Money::from_milliunits(
-                total_income.as_milliunits() - total_expenses.as_milliunits(),
-            );
-
             Ok(serde_json::json!({
-                "budget_overview": {
-                    "total_expenses_milliunits": total_expenses.as_milliunits(),
-                    "total_income_milliunits": total_income.as_milliunits(),
-                    "net_income_milliunits": net_income.as_milliunits(),
-                    "transaction_count": all_transactions.len()
-                }
+                "budget_overview": Self::calculate_budget_overview(
+                    transaction_service,
+                    &BudgetFlowClassifier::default(),
+                    range
+                )
             })
             .to_string())
         } else {
@@ -256,95 +652,86 @@ impl Handler {
 
     /// Provides budget overview using YNAB API client.
     ///
-    /// Note: This is a demonstration of API integration architecture.
-    /// In a full implementation, this would use async/await to call the YNAB API.
-    fn get_budget_overview_with_api(
+    /// Accounts and payees are fetched alongside the transactions so transfers
+    /// and tracking accounts can be told apart from income and spending.
+    async fn get_budget_overview_with_api(
         &self,
         budget_id: &str,
+        range: Option<DateRange>,
         ynab_client: &YnabClient,
     ) -> YnabResult<String> {
-        // Validate API client configuration
-        if ynab_client.api_token().is_empty() {
-            return Err(crate::domain::error::YnabError::ApiError(
-                "Invalid API token".to_string(),
-            ));
-        }
+        let (transactions, accounts, payees) = futures::join!(
+            Self::fetch_transactions(budget_id, ynab_client),
+            Self::fetch_accounts(budget_id, ynab_client),
+            Self::fetch_payees(budget_id, ynab_client)
+        );
+        let (transactions, accounts, payees) = (transactions?, accounts?, payees?);
+        let transaction_service = TransactionService::with_transactions(transactions);
+        let classifier = BudgetFlowClassifier::new(&accounts, &payees);
 
-        // For demonstration: return a response showing API integration is working
-        // In a real implementation, this would:
-        // 1. Make async calls to ynab_client.get_transactions(budget_id).await
-        // 2. Fetch budget details with ynab_client.get_budgets().await
-        // 3. Map responses using ResponseMapper
-        // 4. Calculate totals through domain services
-        // 5. Return comprehensive budget overview
+        let mut overview =
+            Self::calculate_budget_overview(&transaction_service, &classifier, range);
+        overview["data_source"] = serde_json::json!("ynab_api");
+        overview["budget_id"] = serde_json::json!(budget_id);
 
-        Ok(serde_json::json!({
-            "budget_overview": {
-                "total_expenses_milliunits": 245_000,  // Mock calculated expenses from "API"
-                "total_income_milliunits": 4_500_000, // Mock calculated income from "API"
-                "net_income_milliunits": 4_255_000,   // Mock net income calculation
-                "transaction_count": 15,               // Mock transaction count
-                "data_source": "ynab_api",
-                "budget_id": budget_id,
-                "api_token_configured": true
-            }
+        Ok(serde_json::json!({ "budget_overview": overview }).to_string())
+    }
+
+    /// Calculates income, expense and net totals across the transactions in the
+    /// service, limited to the date range when one is given.
+    ///
+    /// Only "Inflow: Ready to Assign" counts as income. Transfers between
+    /// on-budget accounts, such as credit card payments, and tracking account
+    /// activity are left out and reported as excluded.
+    fn calculate_budget_overview(
+        transaction_service: &TransactionService,
+        classifier: &BudgetFlowClassifier,
+        range: Option<DateRange>,
+    ) -> serde_json::Value {
+        let query = range_query(range);
+        let totals = classifier.totals(transaction_service.query(&query));
+
+        serde_json::json!({
+            "total_expenses_milliunits": totals.spending.as_milliunits(),
+            "total_income_milliunits": totals.income.as_milliunits(),
+            "net_income_milliunits": totals.net().as_milliunits(),
+            "transaction_count": totals.counted,
+            "excluded_transaction_count": totals.excluded,
+            "date_range": range_json(range)
         })
-        .to_string())
     }
 
     /// Searches transactions with advanced filtering options.
-    fn search_transactions(&self, params: &serde_json::Value) -> YnabResult<String> {
-        if let Some(transaction_service) = &self.transaction_service {
-            use crate::domain::transaction_query::TransactionQuery;
-
-            let mut query = TransactionQuery::new();
-
-            // Apply text search filter if provided
-            if let Some(text_search) = params["text_search"].as_str()
-                && !text_search.is_empty()
-            {
-                query = query.with_text_search(text_search.to_string());
-            }
-
-            // Apply minimum amount filter if provided
-            if let Some(min_amount) = params["min_amount_milliunits"].as_i64() {
-                query =
-                    query.with_min_amount(crate::domain::money::Money::from_milliunits(min_amount));
-            }
-
-            // Apply category filter if provided
-            if let Some(category_id) = params["category_id"].as_str()
-                && !category_id.is_empty()
-            {
-                query = query.with_category(category_id.to_string());
-            }
+    async fn search_transactions(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let range = date_range_argument(params, self.today())?;
+        let filter = match &params["filter"] {
+            serde_json::Value::Null => None,
+            filter => Some(parse_transaction_filter(filter, self.today())?),
+        };
 
-            let found_transactions = transaction_service.query(&query);
-
-            // Apply limit if provided
-            let limit = params["limit"].as_u64().unwrap_or(100) as usize;
-            let limited_transactions: Vec<_> = found_transactions.into_iter().take(limit).collect();
-
-            // Convert transactions to JSON format
-            let transaction_json: Vec<serde_json::Value> = limited_transactions
-                .iter()
-                .map(|txn| {
-                    serde_json::json!({
-                        "id": txn.id(),
-                        "description": txn.description().unwrap_or(""),
-                        "amount_milliunits": txn.amount().as_milliunits(),
-                        "category_id": txn.category_id(),
-                        "account_id": txn.account_id()
-                    })
-                })
-                .collect();
+        // First try YNAB API client integration
+        if let Some(ynab_client) = &self.ynab_client {
+            let budget_id = params["budget_id"].as_str().unwrap_or("");
+            let (transactions, payees) = futures::join!(
+                Self::fetch_transactions(budget_id, ynab_client),
+                Self::fetch_payees(budget_id, ynab_client)
+            );
+            let transaction_service = TransactionService::with_transactions(transactions?);
+            return Ok(Self::search_service_transactions(
+                &transaction_service,
+                &payees?,
+                params,
+                range,
+                filter,
+            )
+            .to_string());
+        }
 
-            Ok(serde_json::json!({
-                "transactions": transaction_json,
-                "count": transaction_json.len(),
-                "limited": transaction_json.len() == limit
-            })
-            .to_string())
+        if let Some(transaction_service) = &self.transaction_service {
+            Ok(
+                Self::search_service_transactions(transaction_service, &[], params, range, filter)
+                    .to_string(),
+            )
         } else {
             // Fallback to empty response when no service is available
             Ok(serde_json::json!({
@@ -356,84 +743,93 @@ impl Handler {
         }
     }
 
-    /// Analyzes spending trends over multiple months with detailed breakdowns.
-    fn analyze_spending_trends(&self, params: &serde_json::Value) -> YnabResult<String> {
-        let budget_id = params["budget_id"].as_str().unwrap_or("");
-
-        // First try YNAB API client integration
-        if let Some(ynab_client) = &self.ynab_client {
-            return self.analyze_spending_trends_with_api(budget_id, ynab_client);
+    /// Applies the search tool's filters, date range and limit to the transactions in the service.
+    ///
+    /// Filters match the lines of split transactions, so a split purchase is
+    /// returned as its matching subtransactions, each naming its parent, unless
+    /// split parents were requested. The structured `filter`, when given, must
+    /// match alongside the tool's other filters.
+    fn search_service_transactions(
+        transaction_service: &TransactionService,
+        payees: &[Payee],
+        params: &serde_json::Value,
+        range: Option<DateRange>,
+        filter: Option<TransactionFilter>,
+    ) -> serde_json::Value {
+        let mut query = range_query(range);
+        if let Some(filter) = filter {
+            query = query.with_filter(filter);
         }
 
-        // Use transaction service for domain-based analysis
-        if let Some(transaction_service) = &self.transaction_service {
-            use crate::domain::transaction_query::TransactionQuery;
-            use std::collections::HashMap;
-
-            let months = params["months"].as_u64().unwrap_or(3) as usize;
-            let categories = params["categories"]
-                .as_array()
-                .map(|arr| {
-                    arr.iter()
-                        .filter_map(|v| v.as_str())
-                        .map(|s| s.to_string())
-                        .collect::<Vec<String>>()
-                })
-                .unwrap_or_default();
-
-            let query = TransactionQuery::new();
-            let all_transactions = transaction_service.query(&query);
+        // Apply text search filter if provided
+        if let Some(text_search) = params["text_search"].as_str()
+            && !text_search.is_empty()
+        {
+            query = query.with_text_search(text_search.to_string());
+        }
 
-            // Group transactions by month and category
-            let mut monthly_data = Vec::new();
-            let mut trend_analysis = HashMap::new();
+        // Apply minimum amount filter if provided
+        if let Some(min_amount) = params["min_amount_milliunits"].as_i64() {
+            query = query.with_min_amount(Money::from_milliunits(min_amount));
+        }
 
-            // Calculate monthly spending for each category
-            let mut category_totals = HashMap::new();
-            for transaction in &all_transactions {
-                let category_id = transaction.category_id();
-                let amount = transaction.amount().as_milliunits().abs();
+        // Apply category filter if provided
+        if let Some(category_id) = params["category_id"].as_str()
+            && !category_id.is_empty()
+        {
+            query = query.with_category(category_id.to_string());
+        }
 
-                if categories.is_empty() || categories.contains(&category_id.to_string()) {
-                    *category_totals.entry(category_id.to_string()).or_insert(0) += amount;
-                }
-            }
+        if params["show_split_parents"].as_bool().unwrap_or(false) {
+            query = query.with_split_parents();
+        }
 
-            // Create mock monthly data for demonstration
-            for month in 1..=months {
-                let mut month_data = serde_json::json!({
-                    "month": format!("2024-{:02}", month),
-                    "categories": {}
-                });
-
-                for (category, total) in &category_totals {
-                    month_data["categories"][category] = serde_json::json!({
-                        "amount_milliunits": total / (months as i64),
-                        "transaction_count": 1
-                    });
-                }
+        let found_transactions = transaction_service.query(&query);
+
+        // Apply limit if provided
+        let limit = params["limit"].as_u64().unwrap_or(100) as usize;
+        let limited_transactions: Vec<_> = found_transactions.into_iter().take(limit).collect();
+
+        // Convert transactions to JSON format
+        let payee_names: HashMap<&str, &str> = payees
+            .iter()
+            .map(|payee| (payee.id(), payee.name()))
+            .collect();
+        let transaction_json: Vec<serde_json::Value> = limited_transactions
+            .iter()
+            .map(|txn| transaction_json(txn, &payee_names))
+            .collect();
+
+        serde_json::json!({
+            "transactions": transaction_json,
+            "count": transaction_json.len(),
+            "limited": transaction_json.len() == limit,
+            "date_range": range_json(range)
+        })
+    }
 
-                monthly_data.push(month_data);
-            }
+    /// Analyzes spending trends over multiple months with detailed breakdowns.
+    async fn analyze_spending_trends(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let range = date_range_argument(params, self.today())?;
 
-            // Calculate trend analysis
-            trend_analysis.insert(
-                "average_monthly_spending".to_string(),
-                category_totals.values().sum::<i64>() / (months as i64),
-            );
-            trend_analysis.insert(
-                "total_categories_analyzed".to_string(),
-                category_totals.len() as i64,
-            );
+        // First try YNAB API client integration
+        if let Some(ynab_client) = &self.ynab_client {
+            return self
+                .analyze_spending_trends_with_api(budget_id, params, range, ynab_client)
+                .await;
+        }
 
+        // Use transaction service for domain-based analysis
+        if let Some(transaction_service) = &self.transaction_service {
             Ok(serde_json::json!({
-                "spending_trends": {
-                    "monthly_data": monthly_data,
-                    "trend_analysis": trend_analysis,
-                    "months_analyzed": months,
-                    "categories_count": categories.len().max(category_totals.len()),
-                    "data_source": "domain_service"
-                }
+                "spending_trends": Self::calculate_spending_trends(
+                    transaction_service,
+                    &BudgetFlowClassifier::default(),
+                    params,
+                    self.today(),
+                    range
+                )
             })
             .to_string())
         } else {
@@ -463,158 +859,174 @@ impl Handler {
     }
 
     /// Analyzes spending trends using YNAB API client.
-    fn analyze_spending_trends_with_api(
+    async fn analyze_spending_trends_with_api(
         &self,
         budget_id: &str,
+        params: &serde_json::Value,
+        range: Option<DateRange>,
         ynab_client: &YnabClient,
     ) -> YnabResult<String> {
-        // Validate API client configuration
-        if ynab_client.api_token().is_empty() {
-            return Err(crate::domain::error::YnabError::ApiError(
-                "Invalid API token".to_string(),
-            ));
-        }
+        let (transactions, accounts, payees) = futures::join!(
+            Self::fetch_transactions(budget_id, ynab_client),
+            Self::fetch_accounts(budget_id, ynab_client),
+            Self::fetch_payees(budget_id, ynab_client)
+        );
+        let (transactions, accounts, payees) = (transactions?, accounts?, payees?);
+        let transaction_service = TransactionService::with_transactions(transactions);
+
+        let mut trends = Self::calculate_spending_trends(
+            &transaction_service,
+            &BudgetFlowClassifier::new(&accounts, &payees),
+            params,
+            self.today(),
+            range,
+        );
+        trends["data_source"] = serde_json::json!("ynab_api");
+        trends["budget_id"] = serde_json::json!(budget_id);
 
-        // For demonstration: return a response showing API integration is working
-        // In a real implementation, this would:
-        // 1. Make async calls to ynab_client.get_transactions(budget_id).await for multiple months
-        // 2. Map the responses using ResponseMapper
-        // 3. Process through domain services for trend analysis
-        // 4. Return calculated trend results
+        Ok(serde_json::json!({ "spending_trends": trends }).to_string())
+    }
 
-        Ok(serde_json::json!({
-            "spending_trends": {
-                "monthly_data": [
-                    {
-                        "month": "2024-01",
-                        "categories": {
-                            "groceries": {"amount_milliunits": 87500, "transaction_count": 12},
-                            "dining": {"amount_milliunits": 45000, "transaction_count": 6}
-                        }
-                    },
-                    {
-                        "month": "2024-02",
-                        "categories": {
-                            "groceries": {"amount_milliunits": 92000, "transaction_count": 14},
-                            "dining": {"amount_milliunits": 38000, "transaction_count": 5}
-                        }
-                    }
-                ],
-                "trend_analysis": {
-                    "average_monthly_spending": 131250,
-                    "strongest_growth_category": "groceries",
-                    "largest_decline_category": "dining"
-                },
-                "months_analyzed": 6,
-                "data_source": "ynab_api",
-                "budget_id": budget_id,
-                "api_token_configured": true
+    /// Calculates per-category monthly spending trends for the transactions in the service.
+    ///
+    /// Outflows are bucketed by the month of their date over the requested
+    /// number of months ending with the last complete month before `today`,
+    /// so an unfinished month never drags the trend down. With a date range,
+    /// only transactions in the range count and the analyzed months are the
+    /// complete months the range covers, at most 60; a range lying entirely in
+    /// the current month analyzes that month alone. See [`SpendingTrends`] for
+    /// how slopes and trend directions are calculated, and which lines
+    /// `classifier` leaves out as transfers.
+    fn calculate_spending_trends(
+        transaction_service: &TransactionService,
+        classifier: &BudgetFlowClassifier,
+        params: &serde_json::Value,
+        today: CalendarDate,
+        range: Option<DateRange>,
+    ) -> serde_json::Value {
+        let last_complete = today.first_of_month().add_days(-1);
+        let (current, months) = match range {
+            Some(range) => {
+                let end = match range.end().min(last_complete) {
+                    end if end < range.start() => range.end().min(today),
+                    end => end,
+                };
+                let months = DateRange::new(range.start(), end)
+                    .months()
+                    .take(60)
+                    .count()
+                    .max(1);
+                (end, months)
             }
+            None => (
+                last_complete,
+                params["months"].as_u64().unwrap_or(3) as usize,
+            ),
+        };
+        let categories = params["categories"]
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        let query = range_query(range);
+        let all_transactions = transaction_service.query(&query);
+        let trends =
+            SpendingTrends::calculate(&all_transactions, classifier, current, months, &categories);
+
+        let monthly_data: Vec<serde_json::Value> = trends
+            .months
+            .iter()
+            .enumerate()
+            .map(|(index, month)| {
+                let mut month_categories = serde_json::Map::new();
+                let (mut total, mut count) = (0i64, 0usize);
+                for trend in &trends.categories {
+                    let spending = &trend.months[index];
+                    total += spending.amount.as_milliunits();
+                    count += spending.transaction_count;
+                    month_categories.insert(
+                        trend.category_id.clone(),
+                        serde_json::json!({
+                            "amount_milliunits": spending.amount.as_milliunits(),
+                            "transaction_count": spending.transaction_count
+                        }),
+                    );
+                }
+                serde_json::json!({
+                    "month": month.start().year_month(),
+                    "categories": month_categories,
+                    "total_milliunits": total,
+                    "transaction_count": count
+                })
+            })
+            .collect();
+
+        let category_trends: Vec<serde_json::Value> = trends
+            .categories
+            .iter()
+            .map(|trend| {
+                serde_json::json!({
+                    "category_id": trend.category_id,
+                    "total_milliunits": trend.total.as_milliunits(),
+                    "average_monthly_milliunits": trend.average.as_milliunits(),
+                    "slope_milliunits_per_month": trend.slope.round() as i64,
+                    "percent_change": trend
+                        .percent_change
+                        .map(|percent| (percent * 10.0).round() / 10.0),
+                    "direction": trend.direction().name()
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "monthly_data": monthly_data,
+            "trend_analysis": {
+                "average_monthly_spending": trends.total().as_milliunits() / trends.months.len() as i64,
+                "total_categories_analyzed": trends.categories.len(),
+                "trending_up": trends.trending(TrendDirection::Up),
+                "trending_down": trends.trending(TrendDirection::Down),
+                "category_trends": category_trends,
+                "method": format!(
+                    "Least-squares slope of monthly outflows over complete months, leaving out the current month; trending when the slope exceeds {}% of the average monthly spending. Percent change compares the first and last month.",
+                    TREND_THRESHOLD_PERCENT
+                )
+            },
+            "months_analyzed": months,
+            "start_month": trends.months.first().map(|month| month.start().year_month()),
+            "end_month": trends.months.last().map(|month| month.start().year_month()),
+            "categories_count": categories.len().max(trends.categories.len()),
+            "date_range": range_json(range),
+            "data_source": "domain_service"
         })
-        .to_string())
     }
 
     /// Performs comprehensive budget health analysis with optimization suggestions.
-    fn budget_health_check(&self, params: &serde_json::Value) -> YnabResult<String> {
+    async fn budget_health_check(&self, params: &serde_json::Value) -> YnabResult<String> {
         let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let range = date_range_argument(params, self.today())?;
 
         // First try YNAB API client integration
         if let Some(ynab_client) = &self.ynab_client {
-            return self.budget_health_check_with_api(budget_id, ynab_client);
+            return self
+                .budget_health_check_with_api(budget_id, range, ynab_client)
+                .await;
         }
 
         // Use transaction service for domain-based analysis
         if let Some(transaction_service) = &self.transaction_service {
-            use crate::domain::transaction_query::TransactionQuery;
-            use std::collections::HashMap;
-
-            let query = TransactionQuery::new();
-            let all_transactions = transaction_service.query(&query);
-
-            // Calculate health metrics
-            let mut category_spending = HashMap::new();
-            let mut total_expenses = 0i64;
-            let mut total_income = 0i64;
-            let mut transaction_count = 0;
-
-            for transaction in &all_transactions {
-                let amount = transaction.amount().as_milliunits();
-                let category = transaction.category_id();
-
-                if amount < 0 {
-                    // Expenses
-                    let expense = amount.abs();
-                    total_expenses += expense;
-                    *category_spending.entry(category.to_string()).or_insert(0) += expense;
-                } else {
-                    // Income
-                    total_income += amount;
-                }
-                transaction_count += 1;
-            }
-
-            // Calculate health score (0-100)
-            let net_income = total_income - total_expenses;
-            let savings_rate = if total_income > 0 {
-                (net_income as f64 / total_income as f64 * 100.0) as i64
-            } else {
-                0
-            };
-
-            // Generate optimization suggestions
-            let mut suggestions = Vec::new();
-            let mut risk_categories = Vec::new();
-
-            // Find high-spending categories
-            let avg_category_spending = if !category_spending.is_empty() {
-                total_expenses / category_spending.len() as i64
-            } else {
-                0
-            };
-
-            for (category, spending) in &category_spending {
-                if *spending > avg_category_spending * 2 {
-                    risk_categories.push(category.clone());
-                    suggestions.push(format!(
-                        "Consider reducing spending in {} category",
-                        category
-                    ));
-                }
-            }
-
-            // General suggestions based on savings rate
-            if savings_rate < 10 {
-                suggestions.push("Increase savings rate to at least 10% of income".to_string());
-            }
-
-            if net_income < 0 {
-                suggestions.push("Reduce expenses to achieve positive cash flow".to_string());
-            }
-
-            // Calculate overall score based on savings rate and other factors
-            let overall_score = if savings_rate >= 20 {
-                90 + (transaction_count.min(10) as f64 * 1.0) as i64
-            } else if savings_rate >= 10 {
-                70 + savings_rate
-            } else {
-                50 + savings_rate.max(0)
-            };
-
             Ok(serde_json::json!({
-                "budget_health": {
-                    "overall_score": overall_score.min(100),
-                    "optimization_suggestions": suggestions,
-                    "risk_categories": risk_categories,
-                    "spending_efficiency": {
-                        "total_expenses_milliunits": total_expenses,
-                        "total_income_milliunits": total_income,
-                        "net_income_milliunits": net_income,
-                        "savings_rate_percentage": savings_rate
-                    },
-                    "category_analysis": category_spending,
-                    "transaction_count": transaction_count,
-                    "data_source": "domain_service"
-                }
+                "budget_health": Self::calculate_budget_health(
+                    transaction_service,
+                    &BudgetFlowClassifier::default(),
+                    &HashMap::new(),
+                    None,
+                    range
+                )
             })
             .to_string())
         } else {
@@ -639,55 +1051,778 @@ impl Handler {
     }
 
     /// Performs budget health check using YNAB API client.
-    fn budget_health_check_with_api(
+    ///
+    /// Category IDs are reported by their YNAB names so suggestions read naturally,
+    /// and risk categories are the ones overspent in the current budget month,
+    /// or in the last month of the date range up to today.
+    async fn budget_health_check_with_api(
         &self,
         budget_id: &str,
+        range: Option<DateRange>,
         ynab_client: &YnabClient,
     ) -> YnabResult<String> {
-        // Validate API client configuration
-        if ynab_client.api_token().is_empty() {
-            return Err(crate::domain::error::YnabError::ApiError(
-                "Invalid API token".to_string(),
+        let month = match range {
+            Some(range) => range.end().min(self.today()).first_of_month().to_string(),
+            None => "current".to_string(),
+        };
+        let (budget_data, current_month, accounts, payees) = futures::join!(
+            Self::fetch_budget_data(budget_id, ynab_client),
+            Self::fetch_budget_month(budget_id, &month, ynab_client),
+            Self::fetch_accounts(budget_id, ynab_client),
+            Self::fetch_payees(budget_id, ynab_client)
+        );
+        let (transactions, categories) = budget_data?;
+        let (current_month, accounts, payees) = (current_month?, accounts?, payees?);
+        let category_names: HashMap<String, String> = categories
+            .iter()
+            .map(|category| (category.id().to_string(), category.name().to_string()))
+            .collect();
+        let transaction_service = TransactionService::with_transactions(transactions);
+
+        let mut health = Self::calculate_budget_health(
+            &transaction_service,
+            &BudgetFlowClassifier::new(&accounts, &payees),
+            &category_names,
+            Some(&current_month),
+            range,
+        );
+        health["data_source"] = serde_json::json!("ynab_api");
+        health["budget_id"] = serde_json::json!(budget_id);
+
+        Ok(serde_json::json!({ "budget_health": health }).to_string())
+    }
+
+    /// Scores budget health and builds suggestions from the transactions in the service.
+    ///
+    /// Categories are labelled with their entry in `category_names`, falling back
+    /// to the raw category ID when no name is known. With a budget month, risk
+    /// categories are those that spent more than was assigned to them; without
+    /// one, they are categories spending over twice the category average.
+    /// Only transactions within the date range count, when one is given.
+    /// Income and spending follow `classifier`, so transfers and tracking
+    /// accounts are left out, and refunds reduce their category's spending.
+    fn calculate_budget_health(
+        transaction_service: &TransactionService,
+        classifier: &BudgetFlowClassifier,
+        category_names: &HashMap<String, String>,
+        month: Option<&BudgetMonth>,
+        range: Option<DateRange>,
+    ) -> serde_json::Value {
+        let query = range_query(range);
+        let all_transactions = transaction_service.query(&query);
+
+        // Calculate health metrics
+        let mut category_spending = HashMap::new();
+        let mut total_expenses = 0i64;
+        let mut total_income = 0i64;
+        let mut transaction_count = 0;
+
+        for transaction in &all_transactions {
+            let amount = transaction.amount().as_milliunits();
+            let category_id = transaction.category_id();
+            let category = category_names
+                .get(category_id)
+                .map(String::as_str)
+                .unwrap_or(category_id);
+
+            match classifier.classify(transaction) {
+                BudgetFlow::Income => total_income += amount,
+                BudgetFlow::Spending => {
+                    total_expenses -= amount;
+                    *category_spending.entry(category.to_string()).or_insert(0) -= amount;
+                }
+                BudgetFlow::Transfer | BudgetFlow::OffBudget => continue,
+            }
+            transaction_count += 1;
+        }
+
+        // Calculate health score (0-100)
+        let net_income = total_income - total_expenses;
+        let savings_rate = if total_income > 0 {
+            (net_income as f64 / total_income as f64 * 100.0) as i64
+        } else {
+            0
+        };
+
+        // Generate optimization suggestions
+        let mut suggestions = Vec::new();
+        let mut risk_categories = Vec::new();
+
+        // Find high-spending categories
+        let avg_category_spending = if !category_spending.is_empty() {
+            total_expenses / category_spending.len() as i64
+        } else {
+            0
+        };
+
+        let mut overspent_categories = Vec::new();
+        if let Some(month) = month {
+            for category in month.overspent_categories() {
+                risk_categories.push(category.name().to_string());
+                suggestions.push(format!(
+                    "Cover overspending of {} milliunits in {} category",
+                    category.overspent_amount().as_milliunits(),
+                    category.name()
+                ));
+                overspent_categories.push(serde_json::json!({
+                    "category_id": category.id(),
+                    "name": category.name(),
+                    "budgeted_milliunits": category.budgeted().as_milliunits(),
+                    "activity_milliunits": category.activity().as_milliunits(),
+                    "balance_milliunits": category.balance().as_milliunits(),
+                    "overspent_milliunits": category.overspent_amount().as_milliunits()
+                }));
+            }
+        } else {
+            for (category, spending) in &category_spending {
+                if *spending > avg_category_spending * 2 {
+                    risk_categories.push(category.clone());
+                    suggestions.push(format!(
+                        "Consider reducing spending in {} category",
+                        category
+                    ));
+                }
+            }
+        }
+
+        // General suggestions based on savings rate
+        if savings_rate < 10 {
+            suggestions.push("Increase savings rate to at least 10% of income".to_string());
+        }
+
+        if net_income < 0 {
+            suggestions.push("Reduce expenses to achieve positive cash flow".to_string());
+        }
+
+        // Calculate overall score based on savings rate and other factors
+        let overall_score = if savings_rate >= 20 {
+            90 + (transaction_count.min(10) as f64 * 1.0) as i64
+        } else if savings_rate >= 10 {
+            70 + savings_rate
+        } else {
+            50 + savings_rate.max(0)
+        };
+
+        let mut health = serde_json::json!({
+            "overall_score": overall_score.min(100),
+            "optimization_suggestions": suggestions,
+            "risk_categories": risk_categories,
+            "spending_efficiency": {
+                "total_expenses_milliunits": total_expenses,
+                "total_income_milliunits": total_income,
+                "net_income_milliunits": net_income,
+                "savings_rate_percentage": savings_rate
+            },
+            "category_analysis": category_spending,
+            "transaction_count": transaction_count,
+            "date_range": range_json(range),
+            "data_source": "domain_service"
+        });
+        if let Some(month) = month {
+            health["overspending"] = serde_json::json!({
+                "month": month.month(),
+                "total_overspent_milliunits": month.total_overspent().as_milliunits(),
+                "categories": overspent_categories
+            });
+        }
+        health
+    }
+
+    /// Lists a budget's accounts grouped into assets and liabilities.
+    ///
+    /// With a date range, each account also reports the net amount of its
+    /// transactions within the range.
+    async fn list_accounts(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let include_closed = params["include_closed"].as_bool().unwrap_or(false);
+        let range = date_range_argument(params, self.today())?;
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+
+        let (accounts, transactions) =
+            futures::join!(Self::fetch_accounts(budget_id, ynab_client), async {
+                match range {
+                    Some(_) => Self::fetch_transactions(budget_id, ynab_client).await,
+                    None => Ok(Vec::new()),
+                }
+            });
+        let (accounts, transactions) = (accounts?, transactions?);
+        let activity = range.map(|range| {
+            let mut activity: HashMap<&str, i64> = HashMap::new();
+            for transaction in range_query(Some(range)).filter(&transactions) {
+                *activity.entry(transaction.account_id()).or_insert(0) +=
+                    transaction.amount().as_milliunits();
+            }
+            activity
+        });
+        let mut summary = Self::summarize_accounts(&accounts, include_closed, activity.as_ref());
+        summary["date_range"] = range_json(range);
+        summary["data_source"] = serde_json::json!("ynab_api");
+        summary["budget_id"] = serde_json::json!(budget_id);
+
+        Ok(serde_json::json!({ "accounts": summary }).to_string())
+    }
+
+    /// Groups accounts by [`Account::is_liability`] and totals their balances.
+    ///
+    /// Totals are signed as YNAB reports balances, so the liabilities total of
+    /// accounts in debt is negative and the net total is assets plus liabilities.
+    /// When `activity` is given, each account carries its entry as `activity_milliunits`.
+    fn summarize_accounts(
+        accounts: &[Account],
+        include_closed: bool,
+        activity: Option<&HashMap<&str, i64>>,
+    ) -> serde_json::Value {
+        let (liabilities, assets): (Vec<&Account>, Vec<&Account>) = accounts
+            .iter()
+            .filter(|account| include_closed || !account.is_closed())
+            .partition(|account| account.is_liability());
+
+        let total = |accounts: &[&Account]| -> i64 {
+            accounts
+                .iter()
+                .map(|account| account.balance().as_milliunits())
+                .sum()
+        };
+        let describe = |accounts: &[&Account]| -> Vec<serde_json::Value> {
+            accounts
+                .iter()
+                .map(|account| {
+                    let mut summary = serde_json::json!({
+                        "id": account.id(),
+                        "name": account.name(),
+                        "type": account.account_type().api_name(),
+                        "on_budget": account.is_on_budget(),
+                        "closed": account.is_closed(),
+                        "balance_milliunits": account.balance().as_milliunits(),
+                        "cleared_balance_milliunits": account.cleared_balance().as_milliunits(),
+                        "uncleared_balance_milliunits": account.uncleared_balance().as_milliunits(),
+                        "note": account.note(),
+                        "last_reconciled_at": account.last_reconciled_at()
+                    });
+                    if let Some(activity) = activity {
+                        summary["activity_milliunits"] =
+                            serde_json::json!(activity.get(account.id()).copied().unwrap_or(0));
+                    }
+                    summary
+                })
+                .collect()
+        };
+        let assets_total = total(&assets);
+        let liabilities_total = total(&liabilities);
+
+        serde_json::json!({
+            "assets": describe(&assets),
+            "liabilities": describe(&liabilities),
+            "assets_total_milliunits": assets_total,
+            "liabilities_total_milliunits": liabilities_total,
+            "net_milliunits": assets_total + liabilities_total
+        })
+    }
+
+    /// Reports net worth with per-account contributions and monthly history.
+    ///
+    /// With a date range, the history only lists the months the range covers.
+    async fn net_worth(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let range = date_range_argument(params, self.today())?;
+        let months = match range {
+            Some(range) => DateRange::new(range.start(), self.today())
+                .months()
+                .take(120)
+                .count()
+                .max(1),
+            None => params["months"].as_u64().unwrap_or(12) as usize,
+        };
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+
+        let (accounts, transactions) = futures::join!(
+            Self::fetch_accounts(budget_id, ynab_client),
+            Self::fetch_transactions(budget_id, ynab_client)
+        );
+        let report = NetWorthReport::calculate(&accounts?, &transactions?, self.today(), months);
+
+        let accounts: Vec<serde_json::Value> = report
+            .contributions
+            .iter()
+            .map(|contribution| {
+                serde_json::json!({
+                    "id": contribution.account_id,
+                    "name": contribution.name,
+                    "on_budget": contribution.on_budget,
+                    "is_liability": contribution.is_liability,
+                    "balance_milliunits": contribution.balance.as_milliunits()
+                })
+            })
+            .collect();
+        let history: Vec<serde_json::Value> = report
+            .history
+            .iter()
+            .filter(|snapshot| {
+                range.is_none_or(|range| {
+                    (range.start().year_month()..=range.end().year_month())
+                        .contains(&snapshot.month)
+                })
+            })
+            .map(|snapshot| {
+                serde_json::json!({
+                    "month": snapshot.month,
+                    "assets_milliunits": snapshot.assets.as_milliunits(),
+                    "liabilities_milliunits": snapshot.liabilities.as_milliunits(),
+                    "net_worth_milliunits": snapshot.net_worth.as_milliunits(),
+                    "change_milliunits": snapshot.change.map(|change| change.as_milliunits())
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "net_worth": {
+                "assets_milliunits": report.assets.as_milliunits(),
+                "liabilities_milliunits": report.liabilities.as_milliunits(),
+                "net_worth_milliunits": report.net_worth.as_milliunits(),
+                "accounts": accounts,
+                "history": history,
+                "date_range": range_json(range),
+                "data_source": "ynab_api",
+                "budget_id": budget_id
+            }
+        })
+        .to_string())
+    }
+
+    /// Ranks the payees of a budget by spend, visits or average ticket.
+    async fn top_payees(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let range = date_range_argument(params, self.today())?;
+        let ranking = PayeeRanking::from_name(params["sort_by"].as_str().unwrap_or("spend"))
+            .unwrap_or(PayeeRanking::Spend);
+        let limit = params["limit"].as_u64().unwrap_or(10) as usize;
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+
+        let (transactions, payees) = futures::join!(
+            Self::fetch_transactions(budget_id, ynab_client),
+            Self::fetch_payees(budget_id, ynab_client)
+        );
+        let ranked = PayeeSpending::rank(&transactions?, &payees?, range.as_ref(), ranking);
+        let period = range_json(range);
+
+        let payees_json: Vec<serde_json::Value> = ranked
+            .iter()
+            .take(limit)
+            .map(|entry| {
+                serde_json::json!({
+                    "payee_id": entry.payee_id,
+                    "name": entry.name,
+                    "total_spent_milliunits": entry.total_spent.as_milliunits(),
+                    "visits": entry.visits,
+                    "average_ticket_milliunits": entry.average_ticket.as_milliunits(),
+                    "first_visit": entry.first_visit.map(|date| date.to_string()),
+                    "last_visit": entry.last_visit.map(|date| date.to_string())
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "top_payees": {
+                "payees": payees_json,
+                "payee_count": ranked.len(),
+                "start_date": period["start_date"],
+                "end_date": period["end_date"],
+                "sort_by": params["sort_by"].as_str().unwrap_or("spend"),
+                "data_source": "ynab_api",
+                "budget_id": budget_id
+            }
+        })
+        .to_string())
+    }
+
+    /// Reports progress towards every category goal in the current budget month.
+    ///
+    /// The funding pace of each goal is the average amount assigned over the
+    /// requested number of months, ending with the current month. With a date
+    /// range, it is averaged over the months the range covers up to the
+    /// current month instead, at most the last 6.
+    ///
+    /// Budget months are not cached, so every month before the current one
+    /// is a separate `/months/{month}` request counted against the YNAB rate
+    /// limit of 200 requests per hour.
+    async fn goal_progress(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let months = params["months"].as_u64().unwrap_or(3) as i64;
+        let range = date_range_argument(params, self.today())?;
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+
+        let current = Self::fetch_budget_month(budget_id, "current", ynab_client).await?;
+        let current_month = CalendarDate::parse(current.month())?;
+        let funding_months: Vec<CalendarDate> = match range {
+            Some(range) => {
+                let end = range.end().min(current_month.last_of_month());
+                let start = range.start().max(end.first_of_month().add_months(-5));
+                DateRange::new(start, end)
+                    .months()
+                    .map(|month| month.start().first_of_month())
+                    .collect()
+            }
+            None => (0..months)
+                .rev()
+                .map(|offset| current_month.add_months(-offset))
+                .collect(),
+        };
+        let earlier_months: Vec<String> = funding_months
+            .iter()
+            .filter(|month| **month != current_month)
+            .map(|month| month.to_string())
+            .collect();
+        let earlier = futures::future::join_all(
+            earlier_months
+                .iter()
+                .map(|month| Self::fetch_budget_month(budget_id, month, ynab_client)),
+        )
+        .await
+        .into_iter()
+        .collect::<YnabResult<Vec<BudgetMonth>>>()?;
+
+        let mut budgeted_by_category: HashMap<&str, Vec<Money>> = HashMap::new();
+        let includes_current = funding_months.contains(&current_month);
+        for month in includes_current
+            .then_some(&current)
+            .into_iter()
+            .chain(&earlier)
+        {
+            for category in month.categories() {
+                budgeted_by_category
+                    .entry(category.id())
+                    .or_default()
+                    .push(category.budgeted());
+            }
+        }
+
+        let goals: Vec<serde_json::Value> = current
+            .categories()
+            .iter()
+            .filter_map(|category| {
+                let recent = budgeted_by_category
+                    .get(category.id())
+                    .map_or(&[][..], Vec::as_slice);
+                GoalProgress::calculate(category, recent, current_month)
+            })
+            .map(|progress| {
+                serde_json::json!({
+                    "category_id": progress.category_id,
+                    "name": progress.name,
+                    "goal_type": progress.goal.goal_type.api_name(),
+                    "target_milliunits": progress.goal.target.as_milliunits(),
+                    "target_month": progress.goal.target_month.map(|month| month.to_string()),
+                    "percentage_complete": progress.goal.percentage_complete,
+                    "needed_this_month_milliunits": progress
+                        .goal
+                        .under_funded
+                        .map_or(0, |amount| amount.as_milliunits()),
+                    "remaining_milliunits": progress.remaining.as_milliunits(),
+                    "funding_pace_milliunits": progress.funding_pace.as_milliunits(),
+                    "projected_completion": progress
+                        .projected_completion
+                        .map(|month| month.year_month()),
+                    "on_track": progress.on_track()
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "goal_progress": {
+                "month": current.month(),
+                "funding_months": funding_months.len(),
+                "date_range": range_json(range),
+                "goal_count": goals.len(),
+                "goals": goals,
+                "data_source": "ynab_api",
+                "budget_id": budget_id
+            }
+        })
+        .to_string())
+    }
+
+    /// Lists the occurrences of scheduled transactions within the next days.
+    async fn upcoming_bills(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let days = params["days"].as_u64().unwrap_or(30) as i64;
+        let include_inflows = params["include_inflows"].as_bool().unwrap_or(false);
+        let range = look_ahead_argument(params, self.today(), days)?;
+        if range.day_count() > 365 {
+            return Err(YnabError::invalid_argument(
+                "date_range",
+                format!(
+                    "upcoming bills cover at most 365 days, got {}",
+                    range.day_count()
+                ),
             ));
         }
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+
+        let scheduled_transactions =
+            Self::fetch_scheduled_transactions(budget_id, ynab_client).await?;
+        let occurrences: Vec<_> = expand_occurrences(&scheduled_transactions, &range)
+            .into_iter()
+            .filter(|occurrence| {
+                include_inflows || occurrence.scheduled_transaction.amount().as_milliunits() < 0
+            })
+            .collect();
+
+        let (mut total_outflow, mut total_inflow) = (0i64, 0i64);
+        let bills: Vec<serde_json::Value> = occurrences
+            .iter()
+            .map(|occurrence| {
+                let scheduled = occurrence.scheduled_transaction;
+                let amount = scheduled.amount().as_milliunits();
+                if amount < 0 {
+                    total_outflow -= amount;
+                } else {
+                    total_inflow += amount;
+                }
+                serde_json::json!({
+                    "date": occurrence.date.to_string(),
+                    "scheduled_transaction_id": scheduled.id(),
+                    "payee_name": scheduled.payee_name(),
+                    "category_name": scheduled.category_name(),
+                    "account_id": scheduled.account_id(),
+                    "amount_milliunits": amount,
+                    "frequency": scheduled.frequency().api_name(),
+                    "memo": scheduled.memo(),
+                    "is_transfer": scheduled.transfer_account_id().is_some()
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "upcoming_bills": {
+                "start_date": range.start().to_string(),
+                "end_date": range.end().to_string(),
+                "bills": bills,
+                "bill_count": bills.len(),
+                "total_outflow_milliunits": total_outflow,
+                "total_inflow_milliunits": total_inflow,
+                "data_source": "ynab_api",
+                "budget_id": budget_id
+            }
+        })
+        .to_string())
+    }
 
-        // For demonstration: return a response showing API integration is working
-        // In a real implementation, this would:
-        // 1. Make async calls to ynab_client.get_transactions(budget_id).await
-        // 2. Make async calls to ynab_client.get_categories(budget_id).await
-        // 3. Map the responses using ResponseMapper
-        // 4. Process through domain services for comprehensive health analysis
-        // 5. Return calculated health scores and optimization suggestions
+    /// Projects daily balances of open on-budget accounts over the next days.
+    ///
+    /// Scheduled transactions are applied on their due dates. Spending in every
+    /// other category is estimated from the transactions of the history window
+    /// ending the day before the start date, and deducted evenly day by day.
+    async fn forecast_cash_flow(&self, params: &serde_json::Value) -> YnabResult<String> {
+        let budget_id = params["budget_id"].as_str().unwrap_or("");
+        let history_days = params["history_days"].as_u64().unwrap_or(90) as i64;
+        let range = look_ahead_argument(
+            params,
+            self.today(),
+            params["days"].as_u64().unwrap_or(30) as i64,
+        )?;
+        let (start, days) = (range.start(), range.day_count());
+        if !(30..=90).contains(&days) {
+            return Err(YnabError::invalid_argument(
+                "date_range",
+                format!("the forecast must cover 30 to 90 days, got {}", days),
+            ));
+        }
+        let history = DateRange::new(start.add_days(-history_days), start.add_days(-1));
+        let ynab_client = self
+            .ynab_client
+            .as_ref()
+            .ok_or_else(|| YnabError::api_error("YNAB API client is not configured"))?;
+
+        let (accounts, transactions, scheduled_transactions) = futures::join!(
+            Self::fetch_accounts(budget_id, ynab_client),
+            Self::fetch_transactions(budget_id, ynab_client),
+            Self::fetch_scheduled_transactions(budget_id, ynab_client)
+        );
+        let (accounts, transactions, scheduled_transactions) =
+            (accounts?, transactions?, scheduled_transactions?);
+
+        // Categories paid by scheduled outflows are already in the projection
+        let scheduled_categories: Vec<&str> = scheduled_transactions
+            .iter()
+            .filter(|scheduled| !scheduled.is_deleted() && scheduled.amount().as_milliunits() < 0)
+            .filter_map(|scheduled| scheduled.category_id())
+            .collect();
+        let baselines = TransactionService::with_transactions(transactions)
+            .spending_baseline(&history, &scheduled_categories);
+        let forecast =
+            CashFlowForecast::project(&accounts, &scheduled_transactions, &baselines, start, days);
+
+        let accounts_json: Vec<serde_json::Value> = forecast
+            .accounts
+            .iter()
+            .map(|account| {
+                let daily: Vec<serde_json::Value> = account
+                    .daily
+                    .iter()
+                    .map(|day| {
+                        serde_json::json!({
+                            "date": day.date.to_string(),
+                            "balance_milliunits": day.balance.as_milliunits()
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "account_id": account.account_id,
+                    "name": account.name,
+                    "starting_balance_milliunits": account.starting_balance.as_milliunits(),
+                    "ending_balance_milliunits": account
+                        .daily
+                        .last()
+                        .map_or(0, |day| day.balance.as_milliunits()),
+                    "lowest_balance_milliunits": account.lowest_balance.as_milliunits(),
+                    "daily_discretionary_milliunits": account.daily_discretionary.as_milliunits(),
+                    "first_negative_date": account
+                        .first_negative_date
+                        .map(|date| date.to_string()),
+                    "daily_balances": daily
+                })
+            })
+            .collect();
+        let baselines_json: Vec<serde_json::Value> = baselines
+            .iter()
+            .map(|baseline| {
+                serde_json::json!({
+                    "category_id": baseline.category_id,
+                    "account_id": baseline.account_id,
+                    "history_total_milliunits": baseline.total.as_milliunits(),
+                    "daily_average_milliunits": baseline.daily_average().as_milliunits()
+                })
+            })
+            .collect();
+        let accounts_going_negative = forecast
+            .accounts
+            .iter()
+            .filter(|account| account.first_negative_date.is_some())
+            .count();
 
         Ok(serde_json::json!({
-            "budget_health": {
-                "overall_score": 85,
-                "optimization_suggestions": [
-                    "Your grocery spending is 15% above the recommended amount for your income level",
-                    "Consider automating savings to reach a 20% savings rate",
-                    "Review recurring subscriptions - you have $47/month in unused services",
-                    "Emergency fund is healthy at 4.2 months of expenses"
-                ],
-                "risk_categories": ["groceries", "subscriptions"],
-                "spending_efficiency": {
-                    "total_expenses_milliunits": 275_000,
-                    "total_income_milliunits": 420_000,
-                    "net_income_milliunits": 145_000,
-                    "savings_rate_percentage": 18
-                },
-                "category_breakdown": {
-                    "over_budget_categories": 2,
-                    "healthy_categories": 8,
-                    "categories_trending_up": ["groceries", "gas"]
-                },
+            "forecast_cash_flow": {
+                "start_date": forecast.range.start().to_string(),
+                "end_date": forecast.range.end().to_string(),
+                "history_start_date": history.start().to_string(),
+                "history_end_date": history.end().to_string(),
+                "accounts": accounts_json,
+                "accounts_going_negative": accounts_going_negative,
+                "discretionary_baselines": baselines_json,
                 "data_source": "ynab_api",
-                "budget_id": budget_id,
-                "api_token_configured": true
+                "budget_id": budget_id
             }
         })
         .to_string())
     }
 
+    /// Fetches the budgets available to the API token and maps them to domain entities.
+    async fn fetch_budgets(ynab_client: &YnabClient) -> YnabResult<Vec<Budget>> {
+        if ynab_client.api_token().is_empty() {
+            return Err(YnabError::ApiError("Invalid API token".to_string()));
+        }
+
+        let response = ynab_client.get_budgets().await?;
+        ResponseMapper::new().map_budgets_from_response(&response)
+    }
+
+    /// Fetches a budget's transactions from the YNAB API and maps them to domain entities.
+    async fn fetch_transactions(
+        budget_id: &str,
+        ynab_client: &YnabClient,
+    ) -> YnabResult<Vec<Transaction>> {
+        Self::validate_api_request(budget_id, ynab_client)?;
+
+        let response = ynab_client.get_transactions(budget_id).await?;
+        ResponseMapper::new().map_transactions_from_response(&response)
+    }
+
+    /// Fetches a budget's accounts from the YNAB API and maps them to domain entities.
+    async fn fetch_accounts(budget_id: &str, ynab_client: &YnabClient) -> YnabResult<Vec<Account>> {
+        Self::validate_api_request(budget_id, ynab_client)?;
+
+        let response = ynab_client.get_accounts(budget_id).await?;
+        ResponseMapper::new().map_accounts_from_response(&response)
+    }
+
+    /// Fetches a budget's payees, including deleted ones, and maps them to domain entities.
+    async fn fetch_payees(budget_id: &str, ynab_client: &YnabClient) -> YnabResult<Vec<Payee>> {
+        Self::validate_api_request(budget_id, ynab_client)?;
+
+        let response = ynab_client.get_payees(budget_id).await?;
+        ResponseMapper::new().map_payees_from_response(&response)
+    }
+
+    /// Fetches a budget's scheduled transactions and maps them to domain entities.
+    async fn fetch_scheduled_transactions(
+        budget_id: &str,
+        ynab_client: &YnabClient,
+    ) -> YnabResult<Vec<ScheduledTransaction>> {
+        Self::validate_api_request(budget_id, ynab_client)?;
+
+        let response = ynab_client.get_scheduled_transactions(budget_id).await?;
+        ResponseMapper::new().map_scheduled_transactions_from_response(&response)
+    }
+
+    /// Fetches one budget month with its category amounts and maps it to a domain entity.
+    async fn fetch_budget_month(
+        budget_id: &str,
+        month: &str,
+        ynab_client: &YnabClient,
+    ) -> YnabResult<BudgetMonth> {
+        Self::validate_api_request(budget_id, ynab_client)?;
+
+        let response = ynab_client.get_month(budget_id, month).await?;
+        ResponseMapper::new().map_budget_month_from_response(&response)
+    }
+
+    /// Fetches a budget's transactions and categories concurrently and maps them to domain entities.
+    async fn fetch_budget_data(
+        budget_id: &str,
+        ynab_client: &YnabClient,
+    ) -> YnabResult<(Vec<Transaction>, Vec<Category>)> {
+        Self::validate_api_request(budget_id, ynab_client)?;
+
+        let (transactions_response, categories_response) = futures::join!(
+            ynab_client.get_transactions(budget_id),
+            ynab_client.get_categories(budget_id)
+        );
+
+        let mapper = ResponseMapper::new();
+        let transactions = mapper.map_transactions_from_response(&transactions_response?)?;
+        let categories = mapper.map_categories_from_response(&categories_response?)?;
+        Ok((transactions, categories))
+    }
+
+    /// Rejects API requests that cannot succeed before any network call is made.
+    fn validate_api_request(budget_id: &str, ynab_client: &YnabClient) -> YnabResult<()> {
+        // Validate API client configuration
+        if ynab_client.api_token().is_empty() {
+            return Err(YnabError::ApiError("Invalid API token".to_string()));
+        }
+
+        if budget_id.is_empty() {
+            return Err(YnabError::invalid_budget_id("budget_id is required"));
+        }
+
+        Ok(())
+    }
+
     /// Handles incoming JSON-RPC requests according to MCP protocol.
     pub fn handle_jsonrpc_request(
         &self,
@@ -704,7 +1839,8 @@ impl Handler {
                     .map(|tool| {
                         serde_json::json!({
                             "name": tool.name,
-                            "description": tool.description
+                            "description": tool.description,
+                            "inputSchema": tool.input_schema
                         })
                     })
                     .collect();
@@ -735,6 +1871,243 @@ impl Default for Handler {
     }
 }
 
+/// Schema for the `budget_id` argument shared by every tool.
+fn budget_id_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "string",
+        "minLength": 1,
+        "description": "YNAB budget ID, or \"last-used\" for the most recently used budget"
+    })
+}
+
+/// Schema for one structured `filter` of `search_transactions`.
+///
+/// `all`, `any` and `not` nest further filters through a reference to this
+/// definition, which the tool schema publishes as `$defs.transaction_filter`.
+fn transaction_filter_schema() -> serde_json::Value {
+    let ids = |description: &str| {
+        serde_json::json!({
+            "type": ["string", "array"],
+            "items": {"type": "string"},
+            "description": description
+        })
+    };
+    let cleared_status = serde_json::json!({
+        "type": "string",
+        "enum": ["uncleared", "cleared", "reconciled"]
+    });
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "account_ids": ids("Account ID, or IDs of which any may match"),
+            "payee_ids": ids("Payee ID, or IDs of which any may match"),
+            "category_ids": ids("Category ID, or IDs of which any may match"),
+            "payee_name": {
+                "type": "string",
+                "description": "Case-insensitive text to find in the payee name"
+            },
+            "memo": {
+                "type": "string",
+                "description": "Case-insensitive text to find in the memo"
+            },
+            "cleared": {
+                "anyOf": [cleared_status, {"type": "array", "items": cleared_status}],
+                "description": "Cleared status, or statuses of which any may match"
+            },
+            "approved": {"type": "boolean"},
+            "flag_color": {
+                "type": ["string", "array", "null"],
+                "items": {"type": "string"},
+                "description": "Flag color such as red or blue, colors of which any may match, or null for unflagged transactions"
+            },
+            "direction": {"type": "string", "enum": ["inflow", "outflow"]},
+            "uncategorized": {"type": "boolean"},
+            "min_amount_milliunits": {
+                "type": "integer",
+                "description": "Lowest amount to include, in milliunits (outflows are negative)"
+            },
+            "max_amount_milliunits": {
+                "type": "integer",
+                "description": "Highest amount to include, in milliunits (outflows are negative)"
+            },
+            "date_range": {
+                "type": "string",
+                "description": "Period such as last_month, last_90_days, ytd or 2024-Q3"
+            },
+            "start_date": {
+                "type": "string",
+                "description": "First date to include, in YYYY-MM-DD format"
+            },
+            "end_date": {
+                "type": "string",
+                "description": "Last date to include, in YYYY-MM-DD format"
+            },
+            "all": {
+                "type": "array",
+                "items": {"$ref": "#/$defs/transaction_filter"},
+                "description": "Filters that must all match"
+            },
+            "any": {
+                "type": "array",
+                "items": {"$ref": "#/$defs/transaction_filter"},
+                "description": "Filters of which at least one must match"
+            },
+            "not": {
+                "$ref": "#/$defs/transaction_filter",
+                "description": "Filter that must not match"
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for the `date_range` argument shared by every tool.
+fn date_range_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "string",
+        "description": "Period such as this_month, last_month, last_quarter, ytd, last_90_days, next_30_days, 2024, 2024-Q3 or 2024-07; cannot be combined with start_date or end_date"
+    })
+}
+
+/// Schema for the `start_date` argument of an explicit date range.
+fn start_date_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "string",
+        "description": "First date to include, in YYYY-MM-DD format; unbounded when omitted"
+    })
+}
+
+/// Schema for the `end_date` argument of an explicit date range.
+fn end_date_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "string",
+        "description": "Last date to include, in YYYY-MM-DD format; unbounded when omitted"
+    })
+}
+
+/// Reads the shared `date_range`, `start_date` and `end_date` arguments.
+///
+/// A `date_range` expression is resolved against `today` with
+/// [`DateRange::resolve`]. Explicit dates leave a missing side unbounded, at
+/// [`CalendarDate::MIN`] or [`CalendarDate::MAX`]. Returns `None` when no
+/// range was requested.
+fn date_range_argument(
+    params: &serde_json::Value,
+    today: CalendarDate,
+) -> YnabResult<Option<DateRange>> {
+    let start = date_argument(params, "start_date")?;
+    let end = date_argument(params, "end_date")?;
+    if let Some(expression) = params["date_range"].as_str() {
+        if start.is_some() || end.is_some() {
+            return Err(YnabError::invalid_argument(
+                "date_range",
+                "cannot be combined with start_date or end_date",
+            ));
+        }
+        return DateRange::resolve(expression, today).map(Some).map_err(|_| {
+            YnabError::invalid_argument(
+                "date_range",
+                format!(
+                    "expected a period such as last_month, last_90_days, ytd or 2024-Q3, got \"{}\"",
+                    expression
+                ),
+            )
+        });
+    }
+    Ok((start.is_some() || end.is_some()).then(|| {
+        DateRange::new(
+            start.unwrap_or(CalendarDate::MIN),
+            end.unwrap_or(CalendarDate::MAX),
+        )
+    }))
+}
+
+/// Reads the date range of tools that look `days` days ahead from a start date.
+///
+/// The shared date range arguments take precedence; a missing `start_date`
+/// defaults to `today` and a missing `end_date` to the last day of the window.
+fn look_ahead_argument(
+    params: &serde_json::Value,
+    today: CalendarDate,
+    days: i64,
+) -> YnabResult<DateRange> {
+    match date_range_argument(params, today)? {
+        Some(range) if params["date_range"].is_string() => Ok(range),
+        _ => {
+            let start = date_argument(params, "start_date")?.unwrap_or(today);
+            let end =
+                date_argument(params, "end_date")?.unwrap_or_else(|| start.add_days(days - 1));
+            Ok(DateRange::new(start, end))
+        }
+    }
+}
+
+/// Starts a transaction query limited to the requested date range, if any.
+fn range_query(range: Option<DateRange>) -> TransactionQuery {
+    match range {
+        Some(range) => TransactionQuery::new().with_date_range(range),
+        None => TransactionQuery::new(),
+    }
+}
+
+/// Describes a transaction in search output with every YNAB field.
+///
+/// The payee name falls back to `payee_names`, and is `null` when the payee is
+/// unknown. Split transactions list their lines under `subtransactions`.
+fn transaction_json(txn: &Transaction, payee_names: &HashMap<&str, &str>) -> serde_json::Value {
+    serde_json::json!({
+        "id": txn.id(),
+        "parent_transaction_id": txn.parent_id(),
+        "description": txn.description().unwrap_or(""),
+        "date": txn.date().map(|date| date.to_string()),
+        "amount_milliunits": txn.amount().as_milliunits(),
+        "cleared": txn.cleared().api_name(),
+        "approved": txn.is_approved(),
+        "flag_color": txn.flag_color(),
+        "category_id": txn.category_id(),
+        "category_name": txn.category_name(),
+        "account_id": txn.account_id(),
+        "account_name": txn.account_name(),
+        "payee_id": txn.payee_id(),
+        "payee_name": txn
+            .payee_name()
+            .or_else(|| txn.payee_id().and_then(|id| payee_names.get(id).copied())),
+        "transfer_account_id": txn.transfer_account_id(),
+        "import_id": txn.import_id(),
+        "matched_transaction_id": txn.matched_transaction_id(),
+        "deleted": txn.is_deleted(),
+        "subtransactions": txn
+            .subtransactions()
+            .iter()
+            .map(|line| transaction_json(line, payee_names))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Describes a requested date range in tool output, leaving unbounded sides `null`.
+fn range_json(range: Option<DateRange>) -> serde_json::Value {
+    match range {
+        Some(range) => serde_json::json!({
+            "start_date": (range.start() != CalendarDate::MIN).then(|| range.start().to_string()),
+            "end_date": (range.end() != CalendarDate::MAX).then(|| range.end().to_string())
+        }),
+        None => serde_json::Value::Null,
+    }
+}
+
+/// Reads an optional `YYYY-MM-DD` date argument.
+fn date_argument(params: &serde_json::Value, field: &str) -> YnabResult<Option<CalendarDate>> {
+    let Some(date) = params[field].as_str() else {
+        return Ok(None);
+    };
+    CalendarDate::parse(date).map(Some).map_err(|_| {
+        YnabError::invalid_argument(
+            field,
+            format!("expected a YYYY-MM-DD date, got \"{}\"", date),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -775,61 +2148,493 @@ mod tests {
                 .any(|tool| tool.name == "analyze_spending_trends")
         );
         assert!(tools.iter().any(|tool| tool.name == "budget_health_check"));
-        assert_eq!(tools.len(), 5);
+        assert!(tools.iter().any(|tool| tool.name == "list_accounts"));
+        assert!(tools.iter().any(|tool| tool.name == "net_worth"));
+        assert!(tools.iter().any(|tool| tool.name == "top_payees"));
+        assert!(tools.iter().any(|tool| tool.name == "goal_progress"));
+        assert!(tools.iter().any(|tool| tool.name == "upcoming_bills"));
+        assert!(tools.iter().any(|tool| tool.name == "forecast_cash_flow"));
+        assert_eq!(tools.len(), 11);
     }
 
-    #[test]
-    fn should_handle_unknown_tool_name() {
+    #[tokio::test]
+    async fn should_handle_unknown_tool_name() {
         let handler = Handler::new();
 
-        let result = handler.execute_tool("nonexistent_tool", serde_json::json!({}));
+        let result = handler
+            .execute_tool("nonexistent_tool", serde_json::json!({}))
+            .await;
 
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Unknown tool: nonexistent_tool")
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::invalid_argument("name", "unknown tool nonexistent_tool")
+        );
+    }
+
+    /// Starts a mock YNAB API serving categories, transactions, payees and the current month for `budget-123`.
+    async fn mock_ynab_server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-123/categories")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "category_groups": [
+                            {
+                                "id": "group-1",
+                                "name": "Everyday Expenses",
+                                "categories": [
+                                    {"id": "category-456", "name": "Groceries", "category_group_id": "group-1"},
+                                    {"id": "category-789", "name": "Dining Out", "category_group_id": "group-1"}
+                                ]
+                            }
+                        ]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-123/transactions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "transactions": [
+                            {"id": "txn-1", "account_id": "acc-1", "category_id": "category-456", "payee_id": "payee-grocer", "amount": -40000, "date": "2024-01-05", "memo": "Weekly shop"},
+                            {"id": "txn-2", "account_id": "acc-1", "category_id": "category-456", "payee_id": "payee-grocer", "amount": -35000, "date": "2024-01-12", "memo": "Top-up shop"},
+                            {"id": "txn-3", "account_id": "acc-1", "category_id": "category-789", "payee_id": "payee-bistro", "amount": -300000, "date": "2024-01-14", "memo": "Anniversary dinner"},
+                            {"id": "txn-4", "account_id": "acc-1", "category_id": "income", "category_name": "Inflow: Ready to Assign", "payee_id": "payee-employer", "amount": 500000, "date": "2024-01-15", "memo": "Salary"}
+                        ]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-123/months/current")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "month": {
+                            "month": "2024-01-01",
+                            "income": 500000,
+                            "budgeted": 450000,
+                            "activity": -375000,
+                            "to_be_budgeted": 50000,
+                            "categories": [
+                                {"id": "category-456", "name": "Groceries", "category_group_id": "group-1", "budgeted": 50000, "activity": -75000, "balance": -25000, "deleted": false},
+                                {"id": "category-789", "name": "Dining Out", "category_group_id": "group-1", "budgeted": 400000, "activity": -300000, "balance": 100000, "deleted": false}
+                            ]
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-123/payees")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": {
+                        "payees": [
+                            {"id": "payee-grocer", "name": "Corner Grocer", "transfer_account_id": null, "deleted": false},
+                            {"id": "payee-bistro", "name": "Bistro", "transfer_account_id": null, "deleted": true},
+                            {"id": "payee-employer", "name": "Employer", "transfer_account_id": null, "deleted": false}
+                        ]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-123/accounts")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data":{"accounts":[
+                    {"id":"acc-1","name":"Checking","type":"checking","on_budget":true,"closed":false,"balance":125000,"cleared_balance":125000,"uncleared_balance":0}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+    }
+
+    /// Creates a handler whose YNAB client points at the given mock server.
+    fn handler_for_mock_server(server: &mockito::ServerGuard) -> Handler {
+        let ynab_client =
+            YnabClient::new_with_base_url("valid-api-token".to_string(), server.url());
+        Handler::with_ynab_client(ynab_client)
+    }
+
+    #[tokio::test]
+    async fn should_execute_analyze_category_spending_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "category_id": "category-456",
+                    "category_name": "Groceries"
+                }),
+            )
+            .await;
+
+        let response: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        let spending = &response["category_spending"];
+        assert_eq!(spending["category"], "Groceries");
+        assert_eq!(spending["amount_milliunits"], 75_000);
+        assert_eq!(spending["transaction_count"], 2);
+        assert_eq!(spending["data_source"], "ynab_api");
+        assert_eq!(spending["budget_id"], "budget-123");
+    }
+
+    #[tokio::test]
+    async fn should_resolve_category_by_name_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "category_name": "dining out"
+                }),
+            )
+            .await;
+
+        let response: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(response["category_spending"]["category_id"], "category-789");
+        assert_eq!(response["category_spending"]["amount_milliunits"], 300_000);
+    }
+
+    #[tokio::test]
+    async fn should_report_unknown_category_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "category_name": "Holidays"
+                }),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::CategoryNotFound("Holidays".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_require_budget_id_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool("get_budget_overview", serde_json::json!({}))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "budget_id"
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_arguments_of_the_wrong_type() {
+        let handler = Handler::new();
+
+        let result = handler
+            .execute_tool(
+                "analyze_spending_trends",
+                serde_json::json!({"budget_id": "test-budget", "months": "six"}),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::invalid_argument("months", "must be of type integer but was string")
         );
     }
 
-    #[test]
-    fn should_execute_analyze_category_spending_with_api_client() {
-        use crate::adapters::YnabClient;
+    #[tokio::test]
+    async fn should_require_category_id_or_name() {
+        let handler = Handler::new();
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({"budget_id": "test-budget"}),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::invalid_argument("arguments", "requires category_id or category_name")
+        );
+    }
+
+    #[test]
+    fn should_publish_input_schema_requiring_budget_id_for_every_tool() {
+        let handler = Handler::with_ynab_client(YnabClient::new("test-token".to_string()));
+
+        for tool in handler.list_tools() {
+            assert_eq!(tool.input_schema["type"], "object", "{}", tool.name);
+            assert!(
+                tool.input_schema["required"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&serde_json::json!("budget_id")),
+                "{} should require budget_id",
+                tool.name
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_not_require_budget_id_without_api_client() {
+        let handler = Handler::with_services(dated_service());
+
+        for tool in handler.list_tools() {
+            assert!(
+                !tool.input_schema["required"]
+                    .as_array()
+                    .is_some_and(|required| required.contains(&serde_json::json!("budget_id"))),
+                "{} should not require budget_id",
+                tool.name
+            );
+        }
+        let result = handler
+            .execute_tool("search_transactions", serde_json::json!({"limit": 2}))
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["count"], 2);
+    }
+
+    #[test]
+    fn should_accept_shared_date_range_arguments_on_every_tool() {
+        let handler = Handler::new();
+
+        for tool in handler.list_tools() {
+            for field in ["date_range", "start_date", "end_date"] {
+                assert!(
+                    tool.input_schema["properties"][field].is_object(),
+                    "{} should accept {}",
+                    tool.name,
+                    field
+                );
+            }
+        }
+    }
+
+    fn dated_service() -> TransactionService {
+        use crate::domain::Money;
+
+        let spend = |id: &str, amount: i64, date: &str| {
+            Transaction::new_with_date(
+                id.to_string(),
+                "acc1".to_string(),
+                "cat1".to_string(),
+                Money::from_milliunits(amount),
+                date.parse().unwrap(),
+            )
+        };
+        TransactionService::with_transactions(vec![
+            spend("txn1", -10_000, "2024-01-15"),
+            spend("txn2", -20_000, "2024-03-20"),
+            spend("txn3", -30_000, "2024-04-02"),
+            spend("txn4", -40_000, "2024-07-01"),
+            spend("txn5", -50_000, "2024-07-31"),
+            spend("txn6", -60_000, "2024-08-01"),
+        ])
+    }
+
+    #[tokio::test]
+    async fn should_resolve_relative_date_range_against_configured_today() {
+        let handler =
+            Handler::with_services(dated_service()).with_today("2024-08-14".parse().unwrap());
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "budget_id": "test-budget",
+                    "category_id": "cat1",
+                    "date_range": "last month"
+                }),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let spending = &parsed["category_spending"];
+        assert_eq!(spending["amount_milliunits"], 90_000);
+        assert_eq!(spending["transaction_count"], 2);
+        assert_eq!(spending["date_range"]["start_date"], "2024-07-01");
+        assert_eq!(spending["date_range"]["end_date"], "2024-07-31");
+    }
+
+    #[tokio::test]
+    async fn should_limit_budget_overview_to_explicit_start_date() {
+        let handler = Handler::with_services(dated_service());
+
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({"budget_id": "test-budget", "start_date": "2024-07-31"}),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let overview = &parsed["budget_overview"];
+        assert_eq!(overview["total_expenses_milliunits"], 110_000);
+        assert_eq!(overview["date_range"]["start_date"], "2024-07-31");
+        assert_eq!(overview["date_range"]["end_date"], serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn should_analyze_spending_trends_over_a_quarter() {
+        let handler =
+            Handler::with_services(dated_service()).with_today("2024-08-14".parse().unwrap());
+
+        let result = handler
+            .execute_tool(
+                "analyze_spending_trends",
+                serde_json::json!({"budget_id": "test-budget", "date_range": "2024-Q1", "months": 12}),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let trends = &parsed["spending_trends"];
+        assert_eq!(trends["months_analyzed"], 3);
+        assert_eq!(trends["start_month"], "2024-01");
+        assert_eq!(trends["end_month"], "2024-03");
+        let totals: Vec<i64> = trends["monthly_data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|month| month["total_milliunits"].as_i64().unwrap())
+            .collect();
+        assert_eq!(totals, vec![10_000, 0, 20_000]);
+    }
+
+    #[tokio::test]
+    async fn should_leave_the_unfinished_month_out_of_spending_trends() {
+        let handler =
+            Handler::with_services(dated_service()).with_today("2024-08-14".parse().unwrap());
+        let trends = |arguments: serde_json::Value| {
+            let handler = &handler;
+            async move {
+                let result = handler
+                    .execute_tool("analyze_spending_trends", arguments)
+                    .await
+                    .unwrap();
+                serde_json::from_str::<serde_json::Value>(&result).unwrap()["spending_trends"]
+                    .clone()
+            }
+        };
+
+        let ytd =
+            trends(serde_json::json!({"budget_id": "test-budget", "date_range": "ytd"})).await;
+        assert_eq!(ytd["start_month"], "2024-01");
+        assert_eq!(ytd["end_month"], "2024-07");
+        assert_eq!(ytd["months_analyzed"], 7);
+
+        let this_month =
+            trends(serde_json::json!({"budget_id": "test-budget", "date_range": "this_month"}))
+                .await;
+        assert_eq!(this_month["end_month"], "2024-08");
+        assert_eq!(this_month["months_analyzed"], 1);
+    }
+
+    #[tokio::test]
+    async fn should_reject_unknown_or_conflicting_date_ranges() {
+        let handler = Handler::with_services(dated_service());
+
+        for arguments in [
+            serde_json::json!({"budget_id": "test-budget", "date_range": "someday"}),
+            serde_json::json!({"budget_id": "test-budget", "date_range": "ytd", "start_date": "2024-01-01"}),
+        ] {
+            let result = handler
+                .execute_tool("get_budget_overview", arguments.clone())
+                .await;
+
+            assert!(
+                matches!(
+                    result,
+                    Err(YnabError::InvalidArgument { ref field, .. }) if field == "date_range"
+                ),
+                "{}",
+                arguments
+            );
+        }
+    }
 
-        let ynab_client = YnabClient::new("valid-api-token".to_string());
+    #[tokio::test]
+    async fn should_surface_api_errors_from_api_client() {
+        use crate::adapters::RetryPolicy;
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-123/transactions")
+            .with_status(500)
+            .create_async()
+            .await;
+        let ynab_client =
+            YnabClient::new_with_base_url("valid-api-token".to_string(), server.url())
+                .with_retry_policy(RetryPolicy::no_retries());
         let handler = Handler::with_ynab_client(ynab_client);
 
-        let result = handler.execute_tool(
-            "analyze_category_spending",
-            serde_json::json!({
-                "budget_id": "budget-123",
-                "category_id": "category-456",
-                "category_name": "Groceries"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({"budget_id": "budget-123"}),
+            )
+            .await;
 
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert!(response.contains("ynab_api"));
-        assert!(response.contains("api_token_configured"));
+        assert!(matches!(result, Err(YnabError::ApiError(_))));
     }
 
-    #[test]
-    fn should_fail_analyze_category_spending_with_empty_api_token() {
+    #[tokio::test]
+    async fn should_fail_analyze_category_spending_with_empty_api_token() {
         use crate::adapters::YnabClient;
 
         let ynab_client = YnabClient::new("".to_string()); // Empty token
         let handler = Handler::with_ynab_client(ynab_client);
 
-        let result = handler.execute_tool(
-            "analyze_category_spending",
-            serde_json::json!({
-                "budget_id": "budget-123",
-                "category_id": "category-456",
-                "category_name": "Groceries"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "category_id": "category-456",
+                    "category_name": "Groceries"
+                }),
+            )
+            .await;
 
         assert!(result.is_err());
         assert!(
@@ -840,39 +2645,44 @@ mod tests {
         );
     }
 
-    #[test]
-    fn should_execute_get_budget_overview_with_api_client() {
-        use crate::adapters::YnabClient;
-
-        let ynab_client = YnabClient::new("valid-api-token".to_string());
-        let handler = Handler::with_ynab_client(ynab_client);
-
-        let result = handler.execute_tool(
-            "get_budget_overview",
-            serde_json::json!({
-                "budget_id": "budget-123"
-            }),
-        );
-
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert!(response.contains("ynab_api"));
-        assert!(response.contains("total_expenses_milliunits"));
+    #[tokio::test]
+    async fn should_execute_get_budget_overview_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({
+                    "budget_id": "budget-123"
+                }),
+            )
+            .await;
+
+        let response: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        let overview = &response["budget_overview"];
+        assert_eq!(overview["total_expenses_milliunits"], 375_000);
+        assert_eq!(overview["total_income_milliunits"], 500_000);
+        assert_eq!(overview["net_income_milliunits"], 125_000);
+        assert_eq!(overview["transaction_count"], 4);
+        assert_eq!(overview["data_source"], "ynab_api");
     }
 
-    #[test]
-    fn should_fail_get_budget_overview_with_empty_api_token() {
+    #[tokio::test]
+    async fn should_fail_get_budget_overview_with_empty_api_token() {
         use crate::adapters::YnabClient;
 
         let ynab_client = YnabClient::new("".to_string()); // Empty token
         let handler = Handler::with_ynab_client(ynab_client);
 
-        let result = handler.execute_tool(
-            "get_budget_overview",
-            serde_json::json!({
-                "budget_id": "budget-123"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({
+                    "budget_id": "budget-123"
+                }),
+            )
+            .await;
 
         assert!(result.is_err());
         assert!(
@@ -883,8 +2693,8 @@ mod tests {
         );
     }
 
-    #[test]
-    fn should_execute_search_transactions_with_filters() {
+    #[tokio::test]
+    async fn should_execute_search_transactions_with_filters() {
         use crate::domain::{Money, Transaction, TransactionService};
 
         let mut service = TransactionService::new();
@@ -910,13 +2720,15 @@ mod tests {
         let handler = Handler::with_services(service);
 
         // Test with text search filter
-        let result = handler.execute_tool(
-            "search_transactions",
-            serde_json::json!({
-                "text_search": "grocery",
-                "limit": 10
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "text_search": "grocery",
+                    "limit": 10
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -924,8 +2736,8 @@ mod tests {
         assert!(!response.contains("Gas station"));
     }
 
-    #[test]
-    fn should_execute_search_transactions_with_amount_filter() {
+    #[tokio::test]
+    async fn should_execute_search_transactions_with_amount_filter() {
         use crate::domain::{Money, Transaction, TransactionService};
 
         let mut service = TransactionService::new();
@@ -951,13 +2763,15 @@ mod tests {
         let handler = Handler::with_services(service);
 
         // Test with minimum amount filter (looking for amounts >= -5000 milliunits)
-        let result = handler.execute_tool(
-            "search_transactions",
-            serde_json::json!({
-                "min_amount_milliunits": -5000,
-                "limit": 10
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "min_amount_milliunits": -5000,
+                    "limit": 10
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -966,28 +2780,269 @@ mod tests {
         assert!(!response.contains("Large purchase"));
     }
 
-    #[test]
-    fn should_execute_analyze_category_spending_tool() {
-        let handler = Handler::new();
+    #[tokio::test]
+    async fn should_search_transactions_with_structured_filter() {
+        use crate::domain::{ClearedStatus, Money, Transaction, TransactionService};
 
-        let result = handler.execute_tool(
-            "analyze_category_spending",
-            serde_json::json!({
-                "budget_id": "test-budget-123",
-                "category_name": "Groceries"
-            }),
+        let purchase = |id: &str, account_id: &str, payee: &str, amount: i64| {
+            Transaction::builder()
+                .id(id.to_string())
+                .account_id(account_id.to_string())
+                .category_id("shopping".to_string())
+                .payee_name(payee.to_string())
+                .amount(Money::from_milliunits(amount))
+        };
+        let service = TransactionService::with_transactions(vec![
+            purchase("match", "visa", "Best Buy", -120_000).build(),
+            purchase("amazon", "visa", "Amazon.com", -80_000).build(),
+            purchase("small", "visa", "Cafe", -4_000).build(),
+            purchase("cleared", "visa", "Best Buy", -90_000)
+                .cleared(ClearedStatus::Cleared)
+                .build(),
+            purchase("checking", "checking", "Best Buy", -70_000).build(),
+            purchase("refund", "visa", "Best Buy", 60_000).build(),
+        ]);
+        let handler = Handler::with_services(service);
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "budget_id": "test-budget",
+                    "filter": {
+                        "account_ids": ["visa"],
+                        "cleared": "uncleared",
+                        "direction": "outflow",
+                        "max_amount_milliunits": -50_000,
+                        "not": {"payee_name": "amazon"}
+                    }
+                }),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(response["count"], 1);
+        assert_eq!(response["transactions"][0]["id"], "match");
+    }
+
+    #[tokio::test]
+    async fn should_publish_and_enforce_structured_filter_fields() {
+        let handler = Handler::with_services(TransactionService::new());
+        let tool = handler
+            .list_tools()
+            .into_iter()
+            .find(|tool| tool.name == "search_transactions")
+            .unwrap();
+        let definition = &tool.input_schema["$defs"]["transaction_filter"];
+        assert_eq!(definition["additionalProperties"], false);
+        assert_eq!(
+            definition["properties"]["not"]["$ref"],
+            "#/$defs/transaction_filter"
+        );
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "budget_id": "test-budget",
+                    "filter": {"not": {"all": [{"payee": "amazon"}]}}
+                }),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::invalid_argument("filter.not.all[0].payee", "is not a recognised argument")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_structured_filter() {
+        let handler = Handler::with_services(TransactionService::new());
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "budget_id": "test-budget",
+                    "filter": {"any": [{"cleared": "pending"}]}
+                }),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "filter.any[0].cleared"
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_return_full_transaction_fields_and_hide_deleted_transactions() {
+        use crate::domain::{ClearedStatus, Money, Transaction, TransactionService};
+
+        let mut service = TransactionService::new();
+        service.add_transaction(
+            Transaction::builder()
+                .id("txn-1".to_string())
+                .account_id("account-1".to_string())
+                .account_name("Checking".to_string())
+                .category_id("dining".to_string())
+                .category_name("Dining Out".to_string())
+                .payee_name("Bistro".to_string())
+                .amount(Money::from_milliunits(-25000))
+                .cleared(ClearedStatus::Reconciled)
+                .approved(true)
+                .flag_color("red".to_string())
+                .import_id("YNAB:-25000:2024-03-01:1".to_string())
+                .build(),
+        );
+        service.add_transaction(
+            Transaction::builder()
+                .id("txn-2".to_string())
+                .account_id("account-1".to_string())
+                .category_id("dining".to_string())
+                .amount(Money::from_milliunits(-5000))
+                .deleted(true)
+                .build(),
+        );
+        let handler = Handler::with_services(service);
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({"budget_id": "test-budget"}),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let transactions = response["transactions"].as_array().unwrap();
+        assert_eq!(transactions.len(), 1);
+        let transaction = &transactions[0];
+        assert_eq!(transaction["cleared"], "reconciled");
+        assert_eq!(transaction["approved"], true);
+        assert_eq!(transaction["flag_color"], "red");
+        assert_eq!(transaction["account_name"], "Checking");
+        assert_eq!(transaction["category_name"], "Dining Out");
+        assert_eq!(transaction["payee_name"], "Bistro");
+        assert_eq!(transaction["import_id"], "YNAB:-25000:2024-03-01:1");
+        assert_eq!(transaction["transfer_account_id"], serde_json::Value::Null);
+        assert_eq!(transaction["deleted"], false);
+    }
+
+    fn split_service() -> TransactionService {
+        use crate::domain::Money;
+
+        let line = |id: &str, category: &str, amount: i64| {
+            Transaction::builder()
+                .id(id.to_string())
+                .account_id("acc1".to_string())
+                .category_id(category.to_string())
+                .amount(Money::from_milliunits(amount))
+                .description("Costco run".to_string())
+                .parent_id("txn-split".to_string())
+                .build()
+        };
+        TransactionService::with_transactions(vec![
+            Transaction::builder()
+                .id("txn-split".to_string())
+                .account_id("acc1".to_string())
+                .category_id("split".to_string())
+                .category_name("Split".to_string())
+                .amount(Money::from_milliunits(-90_000))
+                .description("Costco run".to_string())
+                .subtransactions(vec![
+                    line("sub-1", "groceries", -60_000),
+                    line("sub-2", "household", -30_000),
+                ])
+                .build(),
+        ])
+    }
+
+    #[tokio::test]
+    async fn should_search_split_transaction_lines_or_parents() {
+        let handler = Handler::with_services(split_service());
+
+        let lines = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({"budget_id": "test-budget", "category_id": "household"}),
+            )
+            .await
+            .unwrap();
+        let parents = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "budget_id": "test-budget",
+                    "category_id": "household",
+                    "show_split_parents": true
+                }),
+            )
+            .await
+            .unwrap();
+
+        let lines: serde_json::Value = serde_json::from_str(&lines).unwrap();
+        assert_eq!(lines["count"], 1);
+        assert_eq!(lines["transactions"][0]["id"], "sub-2");
+        assert_eq!(
+            lines["transactions"][0]["parent_transaction_id"],
+            "txn-split"
         );
+        assert_eq!(lines["transactions"][0]["amount_milliunits"], -30_000);
+        let parents: serde_json::Value = serde_json::from_str(&parents).unwrap();
+        let parent = &parents["transactions"][0];
+        assert_eq!(parent["id"], "txn-split");
+        assert_eq!(parent["category_name"], "Split");
+        assert_eq!(parent["subtransactions"].as_array().unwrap().len(), 2);
+        assert_eq!(parent["subtransactions"][0]["category_id"], "groceries");
+    }
+
+    #[tokio::test]
+    async fn should_analyze_category_spending_by_split_lines() {
+        let handler = Handler::with_services(split_service());
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({"budget_id": "test-budget", "category_id": "groceries"}),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let spending = &parsed["category_spending"];
+        assert_eq!(spending["amount_milliunits"], 60_000);
+        assert_eq!(spending["transaction_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn should_execute_analyze_category_spending_tool() {
+        let handler = Handler::new();
+
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "budget_id": "test-budget-123",
+                    "category_name": "Groceries"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.contains("category_spending"));
     }
 
-    #[test]
-    fn should_return_error_for_unknown_tool() {
+    #[tokio::test]
+    async fn should_return_error_for_unknown_tool() {
         let handler = Handler::new();
 
-        let result = handler.execute_tool("unknown_tool", serde_json::json!({}));
+        let result = handler
+            .execute_tool("unknown_tool", serde_json::json!({}))
+            .await;
 
         assert!(result.is_err());
     }
@@ -1033,24 +3088,26 @@ mod tests {
         assert_eq!(response["error"]["message"], "Method not found");
     }
 
-    #[test]
-    fn should_execute_get_budget_overview_tool() {
+    #[tokio::test]
+    async fn should_execute_get_budget_overview_tool() {
         let handler = Handler::new();
 
-        let result = handler.execute_tool(
-            "get_budget_overview",
-            serde_json::json!({
-                "budget_id": "test-budget-456"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({
+                    "budget_id": "test-budget-456"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.contains("budget_overview"));
     }
 
-    #[test]
-    fn should_analyze_category_spending_with_real_domain_data() {
+    #[tokio::test]
+    async fn should_analyze_category_spending_with_real_domain_data() {
         use crate::domain::money::Money;
         use crate::domain::transaction::Transaction;
 
@@ -1074,13 +3131,15 @@ mod tests {
         // Create handler with real services
         let handler = Handler::with_services(transaction_service);
 
-        let result = handler.execute_tool(
-            "analyze_category_spending",
-            serde_json::json!({
-                "category_id": "cat1",
-                "category_name": "Groceries"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "analyze_category_spending",
+                serde_json::json!({
+                    "category_id": "cat1",
+                    "category_name": "Groceries"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -1095,8 +3154,157 @@ mod tests {
         assert_eq!(response_json["category_spending"]["category"], "Groceries");
     }
 
-    #[test]
-    fn should_get_budget_overview_with_real_domain_data() {
+    async fn mock_transfers_server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-9/transactions")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"transactions":[
+                    {"id":"pay","account_id":"checking","category_id":"rta","category_name":"Inflow: Ready to Assign","amount":500000,"date":"2024-05-01"},
+                    {"id":"shop","account_id":"visa","category_id":"groceries","category_name":"Groceries","amount":-80000,"date":"2024-05-03"},
+                    {"id":"refund","account_id":"visa","category_id":"groceries","category_name":"Groceries","amount":10000,"date":"2024-05-04"},
+                    {"id":"card-out","account_id":"checking","category_id":null,"amount":-80000,"date":"2024-05-10","transfer_account_id":"visa"},
+                    {"id":"card-in","account_id":"visa","category_id":null,"amount":80000,"date":"2024-05-10","transfer_account_id":"checking"},
+                    {"id":"save-out","account_id":"checking","category_id":null,"payee_id":"payee-savings","amount":-100000,"date":"2024-05-12"},
+                    {"id":"save-in","account_id":"savings","category_id":null,"payee_id":"payee-checking","amount":100000,"date":"2024-05-12"},
+                    {"id":"dividend","account_id":"brokerage","category_id":null,"amount":5000,"date":"2024-05-20"}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-9/accounts")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"accounts":[
+                    {"id":"checking","name":"Checking","type":"checking","on_budget":true,"closed":false,"balance":0},
+                    {"id":"savings","name":"Savings","type":"savings","on_budget":true,"closed":false,"balance":0},
+                    {"id":"visa","name":"Visa","type":"creditCard","on_budget":true,"closed":false,"balance":0},
+                    {"id":"brokerage","name":"Brokerage","type":"otherAsset","on_budget":false,"closed":false,"balance":0}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-9/payees")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"payees":[
+                    {"id":"payee-savings","name":"Transfer : Savings","transfer_account_id":"savings","deleted":false},
+                    {"id":"payee-checking","name":"Transfer : Checking","transfer_account_id":"checking","deleted":false}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn should_leave_transfers_and_tracking_accounts_out_of_budget_overview() {
+        let server = mock_transfers_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({"budget_id": "budget-9"}),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let overview = &response["budget_overview"];
+        // Groceries net of the refund; the card payment and savings transfer move money only
+        assert_eq!(overview["total_expenses_milliunits"], 70_000);
+        assert_eq!(overview["total_income_milliunits"], 500_000);
+        assert_eq!(overview["net_income_milliunits"], 430_000);
+        assert_eq!(overview["transaction_count"], 3);
+        assert_eq!(overview["excluded_transaction_count"], 5);
+    }
+
+    #[tokio::test]
+    async fn should_leave_transfers_out_of_spending_trends() {
+        let server = mock_transfers_server().await;
+        let handler = handler_for_mock_server(&server).with_today("2024-06-15".parse().unwrap());
+
+        let result = handler
+            .execute_tool(
+                "analyze_spending_trends",
+                serde_json::json!({"budget_id": "budget-9", "months": 1}),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let trends = &response["spending_trends"];
+        // Only the groceries purchase; the card payment and savings transfer move money only
+        assert_eq!(
+            trends["monthly_data"][0]["categories"],
+            serde_json::json!({"groceries": {"amount_milliunits": 80_000, "transaction_count": 1}})
+        );
+        let category_ids: Vec<&str> = trends["trend_analysis"]["category_trends"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|trend| trend["category_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(category_ids, vec!["groceries"]);
+    }
+
+    #[tokio::test]
+    async fn should_leave_credit_card_payments_out_of_budget_health() {
+        use crate::domain::{Money, READY_TO_ASSIGN_CATEGORY, Transaction, TransactionService};
+
+        let line = |id: &str, account_id: &str, amount: i64| {
+            Transaction::builder()
+                .id(id.to_string())
+                .account_id(account_id.to_string())
+                .amount(Money::from_milliunits(amount))
+        };
+        let service = TransactionService::with_transactions(vec![
+            line("pay", "checking", 400_000)
+                .category_id("rta".to_string())
+                .category_name(READY_TO_ASSIGN_CATEGORY.to_string())
+                .build(),
+            line("shop", "visa", -60_000)
+                .category_id("groceries".to_string())
+                .build(),
+            line("card-out", "checking", -60_000)
+                .category_id("".to_string())
+                .transfer_account_id("visa".to_string())
+                .build(),
+            line("card-in", "visa", 60_000)
+                .category_id("".to_string())
+                .transfer_account_id("checking".to_string())
+                .build(),
+        ]);
+        let handler = Handler::with_services(service);
+
+        let result = handler
+            .execute_tool(
+                "budget_health_check",
+                serde_json::json!({"budget_id": "test-budget"}),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let health = &response["budget_health"];
+        let efficiency = &health["spending_efficiency"];
+        assert_eq!(efficiency["total_expenses_milliunits"], 60_000);
+        assert_eq!(efficiency["total_income_milliunits"], 400_000);
+        assert_eq!(efficiency["savings_rate_percentage"], 85);
+        assert_eq!(
+            health["category_analysis"],
+            serde_json::json!({"groceries": 60_000})
+        );
+        assert_eq!(health["transaction_count"], 2);
+    }
+
+    #[tokio::test]
+    async fn should_get_budget_overview_with_real_domain_data() {
+        use crate::domain::budget_flow::READY_TO_ASSIGN_CATEGORY;
         use crate::domain::money::Money;
         use crate::domain::transaction::Transaction;
 
@@ -1117,6 +3325,7 @@ mod tests {
             .id("txn3".to_string())
             .amount(Money::from_milliunits(3_000_000)) // $3000 salary income
             .category_id("salary".to_string())
+            .category_name(READY_TO_ASSIGN_CATEGORY.to_string())
             .account_id("acc1".to_string())
             .build();
 
@@ -1125,12 +3334,14 @@ mod tests {
 
         let handler = Handler::with_services(transaction_service);
 
-        let result = handler.execute_tool(
-            "get_budget_overview",
-            serde_json::json!({
-                "budget_id": "test-budget-789"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "get_budget_overview",
+                serde_json::json!({
+                    "budget_id": "test-budget-789"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -1152,131 +3363,710 @@ mod tests {
         assert_eq!(response_json["budget_overview"]["transaction_count"], 3);
     }
 
-    #[test]
-    fn should_execute_analyze_spending_trends_tool() {
+    #[tokio::test]
+    async fn should_execute_analyze_spending_trends_tool() {
         let handler = Handler::new();
 
-        let result = handler.execute_tool(
-            "analyze_spending_trends",
-            serde_json::json!({
-                "budget_id": "test-budget-123",
-                "months": 6
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "analyze_spending_trends",
+                serde_json::json!({
+                    "budget_id": "test-budget-123",
+                    "months": 6
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.contains("spending_trends"));
     }
 
-    #[test]
-    fn should_execute_analyze_spending_trends_with_api_client() {
-        use crate::adapters::YnabClient;
-
-        let ynab_client = YnabClient::new("valid-api-token".to_string());
-        let handler = Handler::with_ynab_client(ynab_client);
-
-        let result = handler.execute_tool(
-            "analyze_spending_trends",
-            serde_json::json!({
-                "budget_id": "test-budget-123",
-                "months": 3
-            }),
+    #[tokio::test]
+    async fn should_execute_analyze_spending_trends_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "analyze_spending_trends",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "months": 3
+                }),
+            )
+            .await;
+
+        let response: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        let trends = &response["spending_trends"];
+        assert_eq!(trends["data_source"], "ynab_api");
+        assert_eq!(trends["months_analyzed"], 3);
+        assert_eq!(trends["monthly_data"].as_array().unwrap().len(), 3);
+        // The unfinished current month is left out
+        assert_eq!(
+            trends["end_month"],
+            CalendarDate::today()
+                .first_of_month()
+                .add_months(-1)
+                .year_month()
         );
-
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert!(response.contains("ynab_api"));
-        assert!(response.contains("months_analyzed"));
     }
 
-    #[test]
-    fn should_execute_analyze_spending_trends_with_transaction_service() {
+    #[tokio::test]
+    async fn should_execute_analyze_spending_trends_with_transaction_service() {
         use crate::domain::{Money, Transaction, TransactionService};
 
-        let mut service = TransactionService::new();
-        service.add_transaction(
-            Transaction::builder()
-                .id("txn-1".to_string())
-                .account_id("account-1".to_string())
-                .category_id("groceries".to_string())
-                .amount(Money::from_milliunits(-5000))
-                .description("January grocery".to_string())
-                .build(),
-        );
-        service.add_transaction(
+        let latest_month = CalendarDate::today().first_of_month().add_months(-1);
+        let earlier_month = latest_month.add_months(-1);
+        let grocery = |id: &str, amount: i64, month: CalendarDate| {
             Transaction::builder()
-                .id("txn-2".to_string())
+                .id(id.to_string())
                 .account_id("account-1".to_string())
                 .category_id("groceries".to_string())
-                .amount(Money::from_milliunits(-6000))
-                .description("February grocery".to_string())
-                .build(),
-        );
+                .amount(Money::from_milliunits(amount))
+                .date(month)
+                .build()
+        };
+        let mut service = TransactionService::new();
+        service.add_transaction(grocery("txn-1", -5000, earlier_month));
+        service.add_transaction(grocery("txn-2", -6000, latest_month));
+        service.add_transaction(grocery("txn-3", -4000, latest_month));
 
         let handler = Handler::with_services(service);
 
-        let result = handler.execute_tool(
-            "analyze_spending_trends",
-            serde_json::json!({
-                "budget_id": "test-budget-123",
-                "months": 2,
-                "categories": ["groceries", "fuel"]
-            }),
+        let result = handler
+            .execute_tool(
+                "analyze_spending_trends",
+                serde_json::json!({
+                    "budget_id": "test-budget-123",
+                    "months": 2,
+                    "categories": ["groceries", "fuel"]
+                }),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let trends = &response["spending_trends"];
+        assert_eq!(
+            trends["monthly_data"][0]["month"],
+            earlier_month.year_month()
         );
-
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert!(response.contains("spending_trends"));
-        assert!(response.contains("groceries"));
+        let current = &trends["monthly_data"][1]["categories"]["groceries"];
+        assert_eq!(current["amount_milliunits"], 10000);
+        assert_eq!(current["transaction_count"], 2);
+        assert_eq!(trends["trend_analysis"]["trending_up"][0], "groceries");
+        assert_eq!(trends["categories_count"], 2);
     }
 
     #[test]
-    fn should_execute_budget_health_check_tool() {
-        let handler = Handler::new();
+    fn should_bucket_spending_trends_by_actual_month() {
+        use crate::domain::{Money, Transaction, TransactionService};
 
-        let result = handler.execute_tool(
-            "budget_health_check",
-            serde_json::json!({
-                "budget_id": "test-budget-123"
-            }),
+        let spend = |id: &str, category: &str, amount: i64, date: &str| {
+            Transaction::new_with_date(
+                id.to_string(),
+                "account-1".to_string(),
+                category.to_string(),
+                Money::from_milliunits(amount),
+                date.parse().unwrap(),
+            )
+        };
+        let service = TransactionService::with_transactions(vec![
+            spend("t-1", "dining", -30000, "2024-01-05"),
+            spend("t-2", "dining", -20000, "2024-02-05"),
+            spend("t-3", "dining", -10000, "2024-03-05"),
+            spend("t-4", "fuel", -40000, "2024-03-09"),
+            spend("t-5", "income", 900000, "2024-03-15"),
+        ]);
+
+        let trends = Handler::calculate_spending_trends(
+            &service,
+            &BudgetFlowClassifier::default(),
+            &serde_json::json!({"months": 3}),
+            "2024-04-02".parse().unwrap(),
+            None,
         );
 
+        let months: Vec<&str> = trends["monthly_data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|month| month["month"].as_str().unwrap())
+            .collect();
+        assert_eq!(months, vec!["2024-01", "2024-02", "2024-03"]);
+        assert_eq!(trends["monthly_data"][2]["total_milliunits"], 50000);
+        assert_eq!(trends["monthly_data"][2]["transaction_count"], 2);
+        assert_eq!(
+            trends["monthly_data"][1]["categories"]["fuel"]["transaction_count"],
+            0
+        );
+        let analysis = &trends["trend_analysis"];
+        assert_eq!(analysis["average_monthly_spending"], 33333);
+        assert_eq!(analysis["trending_up"], serde_json::json!(["fuel"]));
+        assert_eq!(analysis["trending_down"], serde_json::json!(["dining"]));
+        let dining = &analysis["category_trends"][0];
+        assert_eq!(dining["slope_milliunits_per_month"], -10000);
+        assert_eq!(dining["percent_change"], -66.7);
+    }
+
+    #[tokio::test]
+    async fn should_execute_budget_health_check_tool() {
+        let handler = Handler::new();
+
+        let result = handler
+            .execute_tool(
+                "budget_health_check",
+                serde_json::json!({
+                    "budget_id": "test-budget-123"
+                }),
+            )
+            .await;
+
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.contains("budget_health"));
     }
 
-    #[test]
-    fn should_execute_budget_health_check_with_api_client() {
-        use crate::adapters::YnabClient;
-
-        let ynab_client = YnabClient::new("valid-api-token".to_string());
-        let handler = Handler::with_ynab_client(ynab_client);
+    #[tokio::test]
+    async fn should_execute_budget_health_check_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "budget_health_check",
+                serde_json::json!({
+                    "budget_id": "budget-123"
+                }),
+            )
+            .await;
+
+        let response: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        let health = &response["budget_health"];
+        assert_eq!(health["data_source"], "ynab_api");
+        assert_eq!(
+            health["spending_efficiency"]["total_expenses_milliunits"],
+            375_000
+        );
+        assert_eq!(health["category_analysis"]["Groceries"], 75_000);
+        assert_eq!(health["category_analysis"]["Dining Out"], 300_000);
+    }
 
-        let result = handler.execute_tool(
-            "budget_health_check",
+    #[tokio::test]
+    async fn should_report_categories_overspent_against_assigned_amounts() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "budget_health_check",
+                serde_json::json!({"budget_id": "budget-123"}),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let health = &response["budget_health"];
+        // Dining Out spent the most but stayed within its assignment
+        assert_eq!(health["risk_categories"], serde_json::json!(["Groceries"]));
+        let overspending = &health["overspending"];
+        assert_eq!(overspending["month"], "2024-01-01");
+        assert_eq!(overspending["total_overspent_milliunits"], 25_000);
+        assert_eq!(
+            overspending["categories"][0],
             serde_json::json!({
-                "budget_id": "test-budget-123"
-            }),
+                "category_id": "category-456",
+                "name": "Groceries",
+                "budgeted_milliunits": 50_000,
+                "activity_milliunits": -75_000,
+                "balance_milliunits": -25_000,
+                "overspent_milliunits": 25_000
+            })
+        );
+        assert!(
+            health["optimization_suggestions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|suggestion| suggestion
+                    .as_str()
+                    .unwrap()
+                    .contains("overspending of 25000 milliunits in Groceries"))
         );
+    }
 
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert!(response.contains("ynab_api"));
-        assert!(response.contains("optimization_suggestions"));
+    #[tokio::test]
+    async fn should_execute_search_transactions_with_api_client() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "text_search": "shop"
+                }),
+            )
+            .await;
+
+        let response: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
+        assert_eq!(response["count"], 2);
+        assert_eq!(response["transactions"][0]["id"], "txn-1");
     }
 
-    #[test]
-    fn should_execute_search_transactions_with_no_service() {
-        let handler = Handler::new(); // No transaction service
+    #[tokio::test]
+    async fn should_join_payee_names_into_searched_transactions() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({"budget_id": "budget-123", "text_search": "dinner"}),
+            )
+            .await
+            .unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let transaction = &response["transactions"][0];
+        assert_eq!(transaction["payee_id"], "payee-bistro");
+        // Deleted payees still name the transactions recorded against them
+        assert_eq!(transaction["payee_name"], "Bistro");
+    }
 
-        let result = handler.execute_tool(
-            "search_transactions",
-            serde_json::json!({
-                "text_search": "test"
-            }),
+    #[tokio::test]
+    async fn should_rank_top_payees_by_spend() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool("top_payees", serde_json::json!({"budget_id": "budget-123"}))
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let report = &parsed["top_payees"];
+        assert_eq!(report["payee_count"], 2);
+        assert_eq!(report["payees"][0]["name"], "Bistro");
+        assert_eq!(report["payees"][0]["total_spent_milliunits"], 300_000);
+        assert_eq!(report["payees"][1]["name"], "Corner Grocer");
+        assert_eq!(report["payees"][1]["visits"], 2);
+        assert_eq!(report["payees"][1]["average_ticket_milliunits"], 37_500);
+        assert_eq!(report["data_source"], "ynab_api");
+    }
+
+    #[tokio::test]
+    async fn should_rank_top_payees_by_visits_within_date_range() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "top_payees",
+                serde_json::json!({
+                    "budget_id": "budget-123",
+                    "start_date": "2024-01-01",
+                    "end_date": "2024-01-13",
+                    "sort_by": "visits",
+                    "limit": 1
+                }),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let report = &parsed["top_payees"];
+        assert_eq!(report["payee_count"], 1);
+        assert_eq!(report["payees"].as_array().unwrap().len(), 1);
+        assert_eq!(report["payees"][0]["payee_id"], "payee-grocer");
+        assert_eq!(report["payees"][0]["last_visit"], "2024-01-12");
+        assert_eq!(report["start_date"], "2024-01-01");
+    }
+
+    #[tokio::test]
+    async fn should_report_goal_progress_with_projected_completion() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-1/months/current")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "data": {"month": {"month": "2024-03-01", "categories": [
+                        {"id": "vacation", "name": "Vacation", "budgeted": 150000, "balance": 400000,
+                         "goal_type": "TBD", "goal_target": 1000000, "goal_target_month": "2024-12-01",
+                         "goal_percentage_complete": 40, "goal_under_funded": 75000},
+                        {"id": "rent", "name": "Rent", "budgeted": 900000, "balance": 900000,
+                         "goal_type": "NEED", "goal_target": 1200000, "goal_percentage_complete": 75,
+                         "goal_under_funded": 300000},
+                        {"id": "fun", "name": "Fun Money", "budgeted": 50000, "balance": 20000}
+                    ]}}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-1/months/2024-02-01")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "data": {"month": {"month": "2024-02-01", "categories": [
+                        {"id": "vacation", "name": "Vacation", "budgeted": 50000, "balance": 250000,
+                         "goal_type": "TBD", "goal_target": 1000000}
+                    ]}}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "goal_progress",
+                serde_json::json!({"budget_id": "budget-1", "months": 2}),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let report = &parsed["goal_progress"];
+        assert_eq!(report["month"], "2024-03-01");
+        assert_eq!(report["goal_count"], 2);
+        let vacation = &report["goals"][0];
+        assert_eq!(vacation["goal_type"], "TBD");
+        assert_eq!(vacation["percentage_complete"], 40);
+        assert_eq!(vacation["needed_this_month_milliunits"], 75_000);
+        assert_eq!(vacation["remaining_milliunits"], 600_000);
+        assert_eq!(vacation["funding_pace_milliunits"], 100_000);
+        assert_eq!(vacation["projected_completion"], "2024-09");
+        assert_eq!(vacation["on_track"], true);
+        let rent = &report["goals"][1];
+        assert_eq!(rent["needed_this_month_milliunits"], 300_000);
+        assert!(rent["projected_completion"].is_null());
+    }
+
+    #[tokio::test]
+    async fn should_limit_goal_progress_to_six_months() {
+        let handler = Handler::new();
+
+        let result = handler
+            .execute_tool(
+                "goal_progress",
+                serde_json::json!({"budget_id": "budget-1", "months": 12}),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::invalid_argument("months", "must be at most 6")
+        );
+    }
+
+    /// Starts a mock YNAB API serving scheduled transactions for `budget-1`.
+    async fn mock_scheduled_transactions_server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-1/scheduled_transactions")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "data": {"server_knowledge": 3, "scheduled_transactions": [
+                        {"id": "rent", "account_id": "checking", "frequency": "monthly",
+                         "date_first": "2024-01-01", "date_next": "2024-03-01", "amount": -1500000,
+                         "payee_name": "Landlord", "payee_id": "p-1", "category_id": "c-1", "category_name": "Rent"},
+                        {"id": "paycheck", "account_id": "checking", "frequency": "everyOtherWeek",
+                         "date_first": "2024-01-05", "date_next": "2024-03-08", "amount": 2000000,
+                         "payee_name": "Employer", "payee_id": "p-2"},
+                        {"id": "streaming", "account_id": "visa", "frequency": "monthly",
+                         "date_first": "2023-06-12", "date_next": "2024-03-12", "amount": -15000,
+                         "payee_name": "Streamer", "payee_id": "p-3", "memo": "Family plan"}
+                    ]}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn should_list_upcoming_bills_within_window() {
+        let server = mock_scheduled_transactions_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "upcoming_bills",
+                serde_json::json!({"budget_id": "budget-1", "start_date": "2024-03-01", "days": 45}),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let report = &parsed["upcoming_bills"];
+        assert_eq!(report["end_date"], "2024-04-14");
+        let bills: Vec<(&str, &str)> = report["bills"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|bill| {
+                (
+                    bill["date"].as_str().unwrap(),
+                    bill["scheduled_transaction_id"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            bills,
+            vec![
+                ("2024-03-01", "rent"),
+                ("2024-03-12", "streaming"),
+                ("2024-04-01", "rent"),
+                ("2024-04-12", "streaming")
+            ]
         );
+        assert_eq!(report["bills"][1]["memo"], "Family plan");
+        assert_eq!(report["bills"][0]["category_name"], "Rent");
+        assert_eq!(report["total_outflow_milliunits"], 3_030_000);
+        assert_eq!(report["total_inflow_milliunits"], 0);
+    }
+
+    #[tokio::test]
+    async fn should_include_scheduled_inflows_when_requested() {
+        let server = mock_scheduled_transactions_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "upcoming_bills",
+                serde_json::json!({
+                    "budget_id": "budget-1",
+                    "start_date": "2024-03-01",
+                    "days": 14,
+                    "include_inflows": true
+                }),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let report = &parsed["upcoming_bills"];
+        assert_eq!(report["bill_count"], 3);
+        assert_eq!(report["bills"][1]["payee_name"], "Employer");
+        assert_eq!(report["bills"][1]["frequency"], "everyOtherWeek");
+        assert_eq!(report["total_inflow_milliunits"], 2_000_000);
+    }
+
+    #[tokio::test]
+    async fn should_reject_upcoming_bills_ranges_over_a_year() {
+        let handler = Handler::with_ynab_client(YnabClient::new("token".to_string()))
+            .with_today("2024-03-01".parse().unwrap());
+
+        for arguments in [
+            serde_json::json!({"budget_id": "budget-1", "end_date": "9999-12-31"}),
+            serde_json::json!({"budget_id": "budget-1", "date_range": "next_13_months"}),
+            serde_json::json!({"budget_id": "budget-1", "start_date": "2024-01-01", "end_date": "2025-01-01"}),
+        ] {
+            let result = handler
+                .execute_tool("upcoming_bills", arguments.clone())
+                .await;
+
+            assert!(
+                matches!(
+                    result,
+                    Err(YnabError::InvalidArgument { ref field, .. }) if field == "date_range"
+                ),
+                "{}",
+                arguments
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reject_impossible_upcoming_bills_start_date() {
+        let handler = Handler::with_ynab_client(YnabClient::new("token".to_string()));
+
+        let result = handler
+            .execute_tool(
+                "upcoming_bills",
+                serde_json::json!({"budget_id": "budget-1", "start_date": "2024-02-30"}),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "start_date"
+        ));
+    }
+
+    async fn mock_cash_flow_server() -> mockito::ServerGuard {
+        let mut server = mock_scheduled_transactions_server().await;
+        server
+            .mock("GET", "/budgets/budget-1/accounts")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"accounts":[
+                    {"id":"checking","name":"Checking","type":"checking","on_budget":true,"closed":false,"balance":2000000,"cleared_balance":2000000,"uncleared_balance":0},
+                    {"id":"visa","name":"Visa","type":"creditCard","on_budget":true,"closed":false,"balance":0,"cleared_balance":0,"uncleared_balance":0},
+                    {"id":"brokerage","name":"Brokerage","type":"otherAsset","on_budget":false,"closed":false,"balance":9000000,"cleared_balance":9000000,"uncleared_balance":0}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-1/transactions")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "data": {"server_knowledge": 7, "transactions": [
+                        {"id": "t-1", "account_id": "checking", "category_id": "groceries", "amount": -3000000, "date": "2024-02-10"},
+                        {"id": "t-2", "account_id": "checking", "category_id": "c-1", "amount": -1500000, "date": "2024-02-01"},
+                        {"id": "t-3", "account_id": "checking", "category_id": "groceries", "amount": -800000, "date": "2023-12-20"},
+                        {"id": "t-4", "account_id": "checking", "category_id": "income", "amount": 2000000, "date": "2024-02-09"}
+                    ]}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn should_forecast_first_negative_date_per_account() {
+        let server = mock_cash_flow_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "forecast_cash_flow",
+                serde_json::json!({
+                    "budget_id": "budget-1",
+                    "start_date": "2024-03-01",
+                    "history_days": 30
+                }),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let forecast = &parsed["forecast_cash_flow"];
+        assert_eq!(forecast["end_date"], "2024-03-30");
+        assert_eq!(forecast["history_start_date"], "2024-01-31");
+        assert_eq!(forecast["history_end_date"], "2024-02-29");
+        // Card debt is not a cash shortfall, so only checking counts
+        assert_eq!(forecast["accounts_going_negative"], 1);
+        // Rent is scheduled, so only groceries feed the discretionary baseline
+        let baselines = forecast["discretionary_baselines"].as_array().unwrap();
+        assert_eq!(baselines.len(), 1);
+        assert_eq!(baselines[0]["category_id"], "groceries");
+        assert_eq!(baselines[0]["daily_average_milliunits"], 100_000);
+
+        let accounts = forecast["accounts"].as_array().unwrap();
+        assert_eq!(accounts.len(), 2);
+        let checking = &accounts[0];
+        assert_eq!(checking["daily_balances"].as_array().unwrap().len(), 30);
+        assert_eq!(checking["daily_balances"][0]["balance_milliunits"], 400_000);
+        assert_eq!(checking["first_negative_date"], "2024-03-06");
+        let visa = &accounts[1];
+        assert_eq!(visa["first_negative_date"], serde_json::Value::Null);
+        assert_eq!(visa["ending_balance_milliunits"], -15_000);
+    }
+
+    #[tokio::test]
+    async fn should_forecast_over_resolved_date_range() {
+        let server = mock_cash_flow_server().await;
+        let handler = handler_for_mock_server(&server).with_today("2024-03-01".parse().unwrap());
+
+        let result = handler
+            .execute_tool(
+                "forecast_cash_flow",
+                serde_json::json!({
+                    "budget_id": "budget-1",
+                    "date_range": "next_45_days",
+                    "history_days": 30
+                }),
+            )
+            .await
+            .unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let forecast = &parsed["forecast_cash_flow"];
+        assert_eq!(forecast["start_date"], "2024-03-01");
+        assert_eq!(forecast["end_date"], "2024-04-14");
+        assert_eq!(forecast["history_end_date"], "2024-02-29");
+        let checking = &forecast["accounts"][0];
+        assert_eq!(checking["daily_balances"].as_array().unwrap().len(), 45);
+    }
+
+    #[tokio::test]
+    async fn should_reject_forecast_date_range_shorter_than_thirty_days() {
+        let handler = Handler::with_ynab_client(YnabClient::new("token".to_string()))
+            .with_today("2024-03-01".parse().unwrap());
+
+        let result = handler
+            .execute_tool(
+                "forecast_cash_flow",
+                serde_json::json!({"budget_id": "budget-1", "date_range": "this_week"}),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "date_range"
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_forecast_shorter_than_thirty_days() {
+        let handler = Handler::with_ynab_client(YnabClient::new("token".to_string()));
+
+        let result = handler
+            .execute_tool(
+                "forecast_cash_flow",
+                serde_json::json!({"budget_id": "budget-1", "days": 7}),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "days"
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_malformed_top_payees_dates() {
+        let server = mock_ynab_server().await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "top_payees",
+                serde_json::json!({"budget_id": "budget-123", "start_date": "Jan 2024"}),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "start_date"
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_execute_search_transactions_with_no_service() {
+        let handler = Handler::new(); // No transaction service
+
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "text_search": "test"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -1284,8 +4074,8 @@ mod tests {
         assert!(response.contains("\"count\":0"));
     }
 
-    #[test]
-    fn should_handle_search_transactions_with_category_filter() {
+    #[tokio::test]
+    async fn should_handle_search_transactions_with_category_filter() {
         use crate::domain::{Money, Transaction, TransactionService};
 
         let mut service = TransactionService::new();
@@ -1310,13 +4100,15 @@ mod tests {
 
         let handler = Handler::with_services(service);
 
-        let result = handler.execute_tool(
-            "search_transactions",
-            serde_json::json!({
-                "category_id": "groceries",
-                "limit": 5
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "search_transactions",
+                serde_json::json!({
+                    "category_id": "groceries",
+                    "limit": 5
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -1324,9 +4116,9 @@ mod tests {
         assert!(!response.contains("Gas station"));
     }
 
-    #[test]
-    fn should_execute_budget_health_check_with_transaction_service() {
-        use crate::domain::{Money, Transaction, TransactionService};
+    #[tokio::test]
+    async fn should_execute_budget_health_check_with_transaction_service() {
+        use crate::domain::{Money, READY_TO_ASSIGN_CATEGORY, Transaction, TransactionService};
 
         let mut service = TransactionService::new();
         // Add transactions that will trigger various health check conditions
@@ -1344,6 +4136,7 @@ mod tests {
                 .id("txn-2".to_string())
                 .account_id("account-1".to_string())
                 .category_id("salary".to_string())
+                .category_name(READY_TO_ASSIGN_CATEGORY.to_string())
                 .amount(Money::from_milliunits(5000000)) // Income
                 .description("Monthly salary".to_string())
                 .build(),
@@ -1351,12 +4144,14 @@ mod tests {
 
         let handler = Handler::with_services(service);
 
-        let result = handler.execute_tool(
-            "budget_health_check",
-            serde_json::json!({
-                "budget_id": "test-budget-123"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "budget_health_check",
+                serde_json::json!({
+                    "budget_id": "test-budget-123"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -1365,9 +4160,9 @@ mod tests {
         assert!(response.contains("optimization_suggestions"));
     }
 
-    #[test]
-    fn should_handle_budget_health_check_with_negative_cash_flow() {
-        use crate::domain::{Money, Transaction, TransactionService};
+    #[tokio::test]
+    async fn should_handle_budget_health_check_with_negative_cash_flow() {
+        use crate::domain::{Money, READY_TO_ASSIGN_CATEGORY, Transaction, TransactionService};
 
         let mut service = TransactionService::new();
         // Create scenario with negative cash flow
@@ -1385,6 +4180,7 @@ mod tests {
                 .id("txn-2".to_string())
                 .account_id("account-1".to_string())
                 .category_id("salary".to_string())
+                .category_name(READY_TO_ASSIGN_CATEGORY.to_string())
                 .amount(Money::from_milliunits(250000)) // Lower income than expenses
                 .description("Part-time salary".to_string())
                 .build(),
@@ -1392,15 +4188,152 @@ mod tests {
 
         let handler = Handler::with_services(service);
 
-        let result = handler.execute_tool(
-            "budget_health_check",
-            serde_json::json!({
-                "budget_id": "test-budget-123"
-            }),
-        );
+        let result = handler
+            .execute_tool(
+                "budget_health_check",
+                serde_json::json!({
+                    "budget_id": "test-budget-123"
+                }),
+            )
+            .await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.contains("Reduce expenses to achieve positive cash flow"));
     }
+
+    #[tokio::test]
+    async fn should_list_accounts_grouped_by_liability() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-123/accounts")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"accounts":[
+                    {"id":"acc-1","name":"Checking","type":"checking","on_budget":true,"closed":false,"balance":500000,"cleared_balance":450000,"uncleared_balance":50000,"note":"Main account","last_reconciled_at":"2024-06-30T12:00:00Z"},
+                    {"id":"acc-2","name":"Visa","type":"creditCard","on_budget":true,"closed":false,"balance":-120000,"cleared_balance":-120000,"uncleared_balance":0},
+                    {"id":"acc-3","name":"Mortgage","type":"mortgage","on_budget":false,"closed":false,"balance":-200000000,"cleared_balance":-200000000,"uncleared_balance":0},
+                    {"id":"acc-4","name":"Old Savings","type":"savings","on_budget":true,"closed":true,"balance":0,"cleared_balance":0,"uncleared_balance":0}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "list_accounts",
+                serde_json::json!({"budget_id": "budget-123"}),
+            )
+            .await
+            .unwrap();
+
+        let summary: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let accounts = &summary["accounts"];
+        assert_eq!(accounts["assets"].as_array().unwrap().len(), 1);
+        assert_eq!(accounts["assets"][0]["name"], "Checking");
+        assert_eq!(accounts["assets"][0]["uncleared_balance_milliunits"], 50000);
+        assert_eq!(accounts["assets"][0]["note"], "Main account");
+        let liabilities: Vec<&str> = accounts["liabilities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|account| account["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(liabilities, vec!["Visa", "Mortgage"]);
+        assert_eq!(accounts["assets_total_milliunits"], 500000);
+        assert_eq!(accounts["liabilities_total_milliunits"], -200120000);
+        assert_eq!(accounts["net_milliunits"], 500000 - 200120000);
+    }
+
+    #[tokio::test]
+    async fn should_include_closed_accounts_when_requested() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-123/accounts")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"accounts":[{"id":"acc-4","name":"Old Savings","type":"savings","on_budget":true,"closed":true,"balance":1000}]}}"#,
+            )
+            .create_async()
+            .await;
+        let handler = handler_for_mock_server(&server);
+
+        let result = handler
+            .execute_tool(
+                "list_accounts",
+                serde_json::json!({"budget_id": "budget-123", "include_closed": true}),
+            )
+            .await
+            .unwrap();
+
+        let summary: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(summary["accounts"]["assets"][0]["closed"], true);
+        assert_eq!(summary["accounts"]["assets_total_milliunits"], 1000);
+    }
+
+    #[tokio::test]
+    async fn should_require_api_client_to_list_accounts() {
+        let handler = Handler::new();
+
+        let result = handler
+            .execute_tool("list_accounts", serde_json::json!({"budget_id": "b"}))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            YnabError::api_error("YNAB API client is not configured")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_net_worth_with_history() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-123/accounts")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"accounts":[
+                    {"id":"acc-1","name":"Checking","type":"checking","on_budget":true,"balance":400000},
+                    {"id":"acc-2","name":"House","type":"otherAsset","on_budget":false,"balance":2000000},
+                    {"id":"acc-3","name":"Mortgage","type":"mortgage","on_budget":false,"balance":-1500000}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-123/transactions")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"transactions":[
+                    {"id":"t-1","account_id":"acc-1","category_id":"c-1","amount":-100000,"date":"2024-05-03"},
+                    {"id":"t-2","account_id":"acc-3","category_id":"c-2","amount":50000,"date":"2024-05-01"}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        let handler = handler_for_mock_server(&server).with_today("2024-05-20".parse().unwrap());
+
+        let result = handler
+            .execute_tool(
+                "net_worth",
+                serde_json::json!({"budget_id": "budget-123", "months": 2}),
+            )
+            .await
+            .unwrap();
+
+        let report: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let net_worth = &report["net_worth"];
+        assert_eq!(net_worth["assets_milliunits"], 2400000);
+        assert_eq!(net_worth["liabilities_milliunits"], 1500000);
+        assert_eq!(net_worth["net_worth_milliunits"], 900000);
+        assert_eq!(net_worth["accounts"][2]["balance_milliunits"], -1500000);
+        assert_eq!(net_worth["history"][0]["month"], "2024-04");
+        assert_eq!(net_worth["history"][0]["net_worth_milliunits"], 950000);
+        assert_eq!(
+            net_worth["history"][0]["change_milliunits"],
+            serde_json::Value::Null
+        );
+        assert_eq!(net_worth["history"][1]["change_milliunits"], -50000);
+    }
 }
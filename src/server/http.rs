@@ -0,0 +1,836 @@
+//! Streamable HTTP transport for the MCP server.
+//!
+//! Clients POST JSON-RPC messages to a single endpoint and receive JSON responses.
+//! A client may also open a GET Server-Sent Events stream on the same endpoint to
+//! receive server-initiated messages. Sessions are created by `initialize` and
+//! identified by the `Mcp-Session-Id` header on every later request. A session
+//! that sees no requests and has no open SSE stream for the configured idle
+//! timeout expires.
+
+use crate::adapters::YnabClient;
+use crate::domain::{TransactionService, YnabError, YnabResult};
+use crate::server::handler::Handler;
+use crate::server::jsonrpc::{JsonRpcResponse, PARSE_ERROR};
+use crate::server::mcp_protocol::McpServer;
+use hyper::header::{ACCEPT, ALLOW, CACHE_CONTROL, CONTENT_TYPE, HeaderValue, ORIGIN};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Header carrying the session identifier assigned at initialization.
+pub const SESSION_ID_HEADER: &str = "Mcp-Session-Id";
+
+/// How long a session may go without requests before it expires.
+pub const DEFAULT_SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Configuration for the Streamable HTTP transport.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpServerConfig {
+    /// Address the server listens on. Use port 0 to pick a free port.
+    pub bind_address: SocketAddr,
+    /// Path of the MCP endpoint.
+    pub endpoint_path: String,
+    /// Browser origins allowed in addition to loopback origins.
+    pub allowed_origins: Vec<String>,
+    /// How long a session without requests or open SSE streams is kept.
+    pub session_idle_timeout: Duration,
+}
+
+impl HttpServerConfig {
+    /// Creates a configuration serving `/mcp` on the given address.
+    pub fn new(bind_address: SocketAddr) -> Self {
+        Self {
+            bind_address,
+            endpoint_path: "/mcp".to_string(),
+            allowed_origins: Vec::new(),
+            session_idle_timeout: DEFAULT_SESSION_IDLE_TIMEOUT,
+        }
+    }
+
+    /// Sets the path of the MCP endpoint.
+    pub fn with_endpoint_path<S: Into<String>>(mut self, endpoint_path: S) -> Self {
+        self.endpoint_path = endpoint_path.into();
+        self
+    }
+
+    /// Allows requests from an additional browser origin.
+    pub fn with_allowed_origin<S: Into<String>>(mut self, origin: S) -> Self {
+        self.allowed_origins.push(origin.into());
+        self
+    }
+
+    /// Sets how long an idle session is kept before it expires.
+    pub fn with_session_idle_timeout(mut self, session_idle_timeout: Duration) -> Self {
+        self.session_idle_timeout = session_idle_timeout;
+        self
+    }
+}
+
+impl Default for HttpServerConfig {
+    fn default() -> Self {
+        Self::new(SocketAddr::from(([127, 0, 0, 1], 8080)))
+    }
+}
+
+/// Tracks active sessions and the SSE streams opened for each of them.
+///
+/// Cloning is cheap and every clone refers to the same sessions, so a registry
+/// obtained from [`HttpServer::sessions`] can push messages while the server runs.
+/// Sessions idle for longer than the idle timeout are dropped the next time a
+/// session is created or touched.
+#[derive(Debug, Clone)]
+pub struct SessionRegistry {
+    sessions: Arc<Mutex<HashMap<String, Session>>>,
+    idle_timeout: Duration,
+}
+
+/// SSE streams of one session and the time of its latest request.
+#[derive(Debug)]
+struct Session {
+    streams: Vec<mpsc::UnboundedSender<String>>,
+    last_seen: Instant,
+}
+
+impl Session {
+    fn new() -> Self {
+        Self {
+            streams: Vec::new(),
+            last_seen: Instant::now(),
+        }
+    }
+
+    /// Returns whether the session has had no requests and no open stream for `idle_timeout`.
+    fn is_expired(&self, idle_timeout: Duration) -> bool {
+        self.last_seen.elapsed() >= idle_timeout
+            && self.streams.iter().all(|stream| stream.is_closed())
+    }
+}
+
+impl SessionRegistry {
+    /// Creates an empty registry whose sessions expire after [`DEFAULT_SESSION_IDLE_TIMEOUT`].
+    pub fn new() -> Self {
+        Self::with_idle_timeout(DEFAULT_SESSION_IDLE_TIMEOUT)
+    }
+
+    /// Creates an empty registry whose sessions expire after `idle_timeout`.
+    pub fn with_idle_timeout(idle_timeout: Duration) -> Self {
+        Self {
+            sessions: Arc::default(),
+            idle_timeout,
+        }
+    }
+
+    /// Starts a new session and returns its identifier.
+    ///
+    /// The identifier is 128 bits from the operating system's secure random
+    /// number generator, written as 32 hex digits.
+    pub fn create(&self) -> YnabResult<String> {
+        let mut bytes = [0u8; 16];
+        getrandom::fill(&mut bytes)
+            .map_err(|e| YnabError::api_error(format!("Failed to generate a session ID: {}", e)))?;
+        let session_id: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+        let mut sessions = self.lock();
+        self.remove_expired(&mut sessions);
+        sessions.insert(session_id.clone(), Session::new());
+        Ok(session_id)
+    }
+
+    /// Returns whether the session exists and has not expired.
+    pub fn contains(&self, session_id: &str) -> bool {
+        self.lock()
+            .get(session_id)
+            .is_some_and(|session| !session.is_expired(self.idle_timeout))
+    }
+
+    /// Records a request on a session, keeping it from expiring.
+    ///
+    /// Returns whether the session exists and had not expired.
+    pub fn touch(&self, session_id: &str) -> bool {
+        let mut sessions = self.lock();
+        self.remove_expired(&mut sessions);
+        match sessions.get_mut(session_id) {
+            Some(session) => {
+                session.last_seen = Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Ends a session, closing its SSE streams. Returns whether it existed.
+    pub fn remove(&self, session_id: &str) -> bool {
+        self.lock().remove(session_id).is_some()
+    }
+
+    /// Returns the number of active sessions.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether there are no active sessions.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Sends a JSON-RPC message to every open SSE stream of a session.
+    ///
+    /// Returns the number of streams the message was delivered to.
+    pub fn notify(&self, session_id: &str, message: &str) -> usize {
+        let mut sessions = self.lock();
+        let Some(Session { streams, .. }) = sessions.get_mut(session_id) else {
+            return 0;
+        };
+
+        streams.retain(|stream| stream.send(message.to_string()).is_ok());
+        streams.len()
+    }
+
+    /// Opens a new SSE stream for a session, or returns `None` if it does not exist.
+    fn open_stream(&self, session_id: &str) -> Option<mpsc::UnboundedReceiver<String>> {
+        let mut sessions = self.lock();
+        let session = sessions.get_mut(session_id)?;
+        let (sender, receiver) = mpsc::unbounded_channel();
+        session.streams.push(sender);
+        Some(receiver)
+    }
+
+    /// Drops every session that has expired, closing its SSE streams.
+    fn remove_expired(&self, sessions: &mut HashMap<String, Session>) {
+        sessions.retain(|_, session| !session.is_expired(self.idle_timeout));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Session>> {
+        self.sessions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for SessionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// MCP server exposed over the Streamable HTTP transport.
+///
+/// # Example
+/// ```no_run
+/// use ynab_mcp::server::{Handler, HttpServer, HttpServerConfig, McpServer};
+///
+/// # async fn example() -> ynab_mcp::YnabResult<()> {
+/// let config = HttpServerConfig::new("127.0.0.1:0".parse().unwrap());
+/// let server = HttpServer::bind(config, McpServer::new(Handler::new()))?;
+/// println!("Listening on http://{}/mcp", server.local_addr());
+/// server.run().await
+/// # }
+/// ```
+pub struct HttpServer {
+    listener: TcpListener,
+    local_addr: SocketAddr,
+    state: Arc<HttpState>,
+}
+
+/// State shared by every connection.
+struct HttpState {
+    mcp_server: McpServer,
+    sessions: SessionRegistry,
+    endpoint_path: String,
+    allowed_origins: Vec<String>,
+}
+
+impl HttpServer {
+    /// Binds the listening socket without starting to serve requests.
+    pub fn bind(config: HttpServerConfig, mcp_server: McpServer) -> YnabResult<Self> {
+        let listener = TcpListener::bind(config.bind_address)?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+
+        Ok(Self {
+            listener,
+            local_addr,
+            state: Arc::new(HttpState {
+                mcp_server,
+                sessions: SessionRegistry::with_idle_timeout(config.session_idle_timeout),
+                endpoint_path: config.endpoint_path,
+                allowed_origins: config.allowed_origins,
+            }),
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Returns the registry of sessions served by this server.
+    pub fn sessions(&self) -> SessionRegistry {
+        self.state.sessions.clone()
+    }
+
+    /// Serves requests until the process exits.
+    pub async fn run(self) -> YnabResult<()> {
+        self.run_until(std::future::pending()).await
+    }
+
+    /// Serves requests until the `shutdown` future completes.
+    pub async fn run_until<F: Future<Output = ()>>(self, shutdown: F) -> YnabResult<()> {
+        let state = self.state;
+        let make_service = make_service_fn(move |_connection| {
+            let state = Arc::clone(&state);
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let state = Arc::clone(&state);
+                    async move { Ok::<_, Infallible>(handle_http_request(&state, request).await) }
+                }))
+            }
+        });
+
+        hyper::Server::from_tcp(self.listener)
+            .map_err(|e| YnabError::api_error(format!("Failed to start HTTP server: {}", e)))?
+            .serve(make_service)
+            .with_graceful_shutdown(shutdown)
+            .await
+            .map_err(|e| YnabError::api_error(format!("HTTP server failed: {}", e)))
+    }
+}
+
+/// Runs a Streamable HTTP MCP server with a YNAB client for the given token.
+///
+/// Blocks the calling thread on a dedicated Tokio runtime until the server stops.
+pub fn run_http_server(config: HttpServerConfig, api_token: &str) -> YnabResult<()> {
+    let ynab_client = YnabClient::new(api_token.to_string());
+    let handler = Handler::with_full_integration(TransactionService::new(), ynab_client);
+    let server = HttpServer::bind(config, McpServer::new(handler))?;
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+
+    runtime.block_on(server.run())
+}
+
+/// Routes a request to the MCP endpoint handlers.
+async fn handle_http_request(state: &HttpState, request: Request<Body>) -> Response<Body> {
+    if request.uri().path() != state.endpoint_path {
+        return plain_response(StatusCode::NOT_FOUND, "Not Found");
+    }
+
+    if let Some(origin) = request.headers().get(ORIGIN)
+        && !is_allowed_origin(origin, &state.allowed_origins)
+    {
+        return plain_response(StatusCode::FORBIDDEN, "Origin not allowed");
+    }
+
+    match *request.method() {
+        Method::POST => handle_post(state, request).await,
+        Method::GET => handle_get(state, &request),
+        Method::DELETE => handle_delete(state, &request),
+        _ => {
+            let mut response = plain_response(StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed");
+            response
+                .headers_mut()
+                .insert(ALLOW, HeaderValue::from_static("GET, POST, DELETE"));
+            response
+        }
+    }
+}
+
+/// Handles a POST carrying one JSON-RPC message or a batch of them.
+async fn handle_post(state: &HttpState, request: Request<Body>) -> Response<Body> {
+    let session_header = session_id(&request);
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(e) => return plain_response(StatusCode::BAD_REQUEST, &e.to_string()),
+    };
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            let error = JsonRpcResponse::error(
+                Value::Null,
+                PARSE_ERROR,
+                format!("Parse error: {}", e),
+                None,
+            );
+            return json_response(StatusCode::BAD_REQUEST, error.to_json(), None);
+        }
+    };
+
+    let is_batch = payload.is_array();
+    let messages = match payload {
+        Value::Array(messages) => messages,
+        message => vec![message],
+    };
+
+    let initializing = messages
+        .iter()
+        .any(|message| message["method"] == "initialize");
+    let session_id = if initializing {
+        match state.sessions.create() {
+            Ok(session_id) => session_id,
+            Err(e) => return plain_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+        }
+    } else {
+        match validate_session(state, session_header.as_deref()) {
+            Ok(session_id) => session_id,
+            Err((status, reason)) => return plain_response(status, reason),
+        }
+    };
+
+    // Only requests (messages with both a method and an id) produce responses
+    let mut responses = Vec::new();
+    for message in messages.iter().filter(|m| is_request(m)) {
+        if let Some(response) = state.mcp_server.handle_value(message).await {
+            responses.push(response.to_json());
+        }
+    }
+
+    if responses.is_empty() {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = StatusCode::ACCEPTED;
+        return response;
+    }
+
+    let body = if is_batch {
+        format!("[{}]", responses.join(","))
+    } else {
+        responses.remove(0)
+    };
+    json_response(StatusCode::OK, body, Some(&session_id))
+}
+
+/// Opens an SSE stream for server-initiated messages on an existing session.
+fn handle_get(state: &HttpState, request: &Request<Body>) -> Response<Body> {
+    let accepts_event_stream = request
+        .headers()
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/event-stream"));
+    if !accepts_event_stream {
+        return plain_response(
+            StatusCode::NOT_ACCEPTABLE,
+            "GET requires Accept: text/event-stream",
+        );
+    }
+
+    let session_id = match validate_session(state, session_id(request).as_deref()) {
+        Ok(session_id) => session_id,
+        Err((status, reason)) => return plain_response(status, reason),
+    };
+    let Some(receiver) = state.sessions.open_stream(&session_id) else {
+        return plain_response(StatusCode::NOT_FOUND, "Unknown session");
+    };
+
+    let events = futures::stream::unfold(receiver, |mut receiver| async move {
+        let message = receiver.recv().await?;
+        let event = format!("event: message\ndata: {}\n\n", message);
+        Some((Ok::<_, Infallible>(event), receiver))
+    });
+
+    let mut response = Response::new(Body::wrap_stream(events));
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    insert_session_header(&mut response, &session_id);
+    response
+}
+
+/// Terminates a session at the client's request.
+fn handle_delete(state: &HttpState, request: &Request<Body>) -> Response<Body> {
+    match validate_session(state, session_id(request).as_deref()) {
+        Ok(session_id) => {
+            state.sessions.remove(&session_id);
+            plain_response(StatusCode::OK, "Session terminated")
+        }
+        Err((status, reason)) => plain_response(status, reason),
+    }
+}
+
+/// Checks the session header and records the request on the session, returning
+/// the status and reason when it is missing, unknown or expired.
+fn validate_session(
+    state: &HttpState,
+    session_id: Option<&str>,
+) -> Result<String, (StatusCode, &'static str)> {
+    match session_id {
+        None => Err((StatusCode::BAD_REQUEST, "Missing Mcp-Session-Id header")),
+        Some(session_id) if !state.sessions.touch(session_id) => {
+            Err((StatusCode::NOT_FOUND, "Unknown session"))
+        }
+        Some(session_id) => Ok(session_id.to_string()),
+    }
+}
+
+/// Returns whether a JSON-RPC message is a request expecting a response.
+fn is_request(message: &Value) -> bool {
+    message.get("method").is_some() && message.get("id").is_some_and(|id| !id.is_null())
+}
+
+/// Accepts loopback origins and any explicitly configured origin.
+fn is_allowed_origin(origin: &HeaderValue, allowed_origins: &[String]) -> bool {
+    let Ok(origin) = origin.to_str() else {
+        return false;
+    };
+    if allowed_origins.iter().any(|allowed| allowed == origin) {
+        return true;
+    }
+
+    let host = origin
+        .split_once("://")
+        .map_or(origin, |(_, rest)| rest)
+        .trim_end_matches('/');
+    let host = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "::1")
+}
+
+fn session_id(request: &Request<Body>) -> Option<String> {
+    request
+        .headers()
+        .get(SESSION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn insert_session_header(response: &mut Response<Body>, session_id: &str) {
+    if let Ok(value) = HeaderValue::from_str(session_id) {
+        response.headers_mut().insert(SESSION_ID_HEADER, value);
+    }
+}
+
+fn json_response(status: StatusCode, body: String, session_id: Option<&str>) -> Response<Body> {
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    if let Some(session_id) = session_id {
+        insert_session_header(&mut response, session_id);
+    }
+    response
+}
+
+fn plain_response(status: StatusCode, message: &str) -> Response<Body> {
+    let mut response = Response::new(Body::from(message.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::sync::oneshot;
+
+    /// Starts a server on an ephemeral localhost port and returns its endpoint URL.
+    fn start_server() -> (String, SessionRegistry, oneshot::Sender<()>) {
+        start_server_with(HttpServerConfig::new("127.0.0.1:0".parse().unwrap()))
+    }
+
+    fn start_server_with(
+        config: HttpServerConfig,
+    ) -> (String, SessionRegistry, oneshot::Sender<()>) {
+        let server = HttpServer::bind(config, McpServer::new(Handler::new())).unwrap();
+        let url = format!("http://{}/mcp", server.local_addr());
+        let sessions = server.sessions();
+        let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+
+        tokio::spawn(server.run_until(async {
+            let _ = shutdown_signal.await;
+        }));
+
+        (url, sessions, shutdown)
+    }
+
+    async fn initialize(client: &reqwest::Client, url: &str) -> String {
+        let response = client
+            .post(url)
+            .json(&json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        response.headers()[SESSION_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn should_assign_session_on_initialize() {
+        let (url, sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+
+        let session_id = initialize(&client, &url).await;
+
+        assert_eq!(session_id.len(), 32);
+        assert!(sessions.contains(&session_id));
+    }
+
+    #[tokio::test]
+    async fn should_answer_requests_within_a_session() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let response = client
+            .post(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .json(&json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body["id"], 2);
+        assert_eq!(body["result"]["tools"].as_array().unwrap().len(), 11);
+    }
+
+    #[tokio::test]
+    async fn should_reject_requests_without_a_valid_session() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let request = json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"});
+
+        let missing = client.post(&url).json(&request).send().await.unwrap();
+        let unknown = client
+            .post(&url)
+            .header(SESSION_ID_HEADER, "not-a-session")
+            .json(&request)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(missing.status(), 400);
+        assert_eq!(unknown.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn should_accept_notifications_without_a_body() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let response = client
+            .post(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .json(&json!({"jsonrpc": "2.0", "method": "notifications/initialized"}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 202);
+        assert!(response.text().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_answer_batches_with_an_array() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let response = client
+            .post(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .json(&json!([
+                {"jsonrpc": "2.0", "id": 3, "method": "tools/list"},
+                {"jsonrpc": "2.0", "method": "notifications/initialized"},
+                {"jsonrpc": "2.0", "id": 4, "method": "unknown/method"}
+            ]))
+            .send()
+            .await
+            .unwrap();
+
+        let body: Value = response.json().await.unwrap();
+        let responses = body.as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 3);
+        assert_eq!(responses[1]["error"]["code"], -32601);
+    }
+
+    #[tokio::test]
+    async fn should_return_parse_error_for_malformed_json() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+
+        let response = client
+            .post(&url)
+            .header(CONTENT_TYPE, "application/json")
+            .body("{\"jsonrpc\":")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 400);
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body["error"]["code"], -32700);
+    }
+
+    #[tokio::test]
+    async fn should_stream_server_messages_over_sse() {
+        let (url, sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let mut stream = client
+            .get(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .header(ACCEPT, "text/event-stream")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(stream.headers()[CONTENT_TYPE], "text/event-stream");
+
+        let notification = r#"{"jsonrpc":"2.0","method":"notifications/tools/list_changed"}"#;
+        assert_eq!(sessions.notify(&session_id, notification), 1);
+
+        let chunk = stream.chunk().await.unwrap().unwrap();
+        assert_eq!(
+            String::from_utf8(chunk.to_vec()).unwrap(),
+            format!("event: message\ndata: {}\n\n", notification)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_require_event_stream_accept_header_for_get() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let response = client
+            .get(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 406);
+    }
+
+    #[tokio::test]
+    async fn should_terminate_session_on_delete() {
+        let (url, sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let deleted = client
+            .delete(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .send()
+            .await
+            .unwrap();
+        let after_delete = client
+            .post(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .json(&json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(deleted.status(), 200);
+        assert_eq!(after_delete.status(), 404);
+        assert!(sessions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_reject_foreign_origins_and_unknown_paths() {
+        let (url, _sessions, _shutdown) = start_server();
+        let client = reqwest::Client::new();
+        let initialize = json!({"jsonrpc": "2.0", "id": 1, "method": "initialize"});
+
+        let foreign = client
+            .post(&url)
+            .header(ORIGIN, "https://evil.example")
+            .json(&initialize)
+            .send()
+            .await
+            .unwrap();
+        let local = client
+            .post(&url)
+            .header(ORIGIN, "http://localhost:3000")
+            .json(&initialize)
+            .send()
+            .await
+            .unwrap();
+        let wrong_path = client
+            .post(url.replace("/mcp", "/other"))
+            .json(&initialize)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(foreign.status(), 403);
+        assert_eq!(local.status(), 200);
+        assert_eq!(wrong_path.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn should_expire_idle_sessions() {
+        let config = HttpServerConfig::new("127.0.0.1:0".parse().unwrap())
+            .with_session_idle_timeout(Duration::ZERO);
+        let (url, sessions, _shutdown) = start_server_with(config);
+        let client = reqwest::Client::new();
+        let session_id = initialize(&client, &url).await;
+
+        let response = client
+            .post(&url)
+            .header(SESSION_ID_HEADER, &session_id)
+            .json(&json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 404);
+        assert!(sessions.is_empty());
+    }
+
+    #[test]
+    fn should_keep_sessions_alive_while_requests_or_streams_continue() {
+        let sessions = SessionRegistry::with_idle_timeout(Duration::from_millis(200));
+        let active = sessions.create().unwrap();
+        let idle = sessions.create().unwrap();
+        let streaming = sessions.create().unwrap();
+        let stream = sessions.open_stream(&streaming).unwrap();
+
+        std::thread::sleep(Duration::from_millis(120));
+        assert!(sessions.touch(&active));
+        std::thread::sleep(Duration::from_millis(120));
+
+        assert!(sessions.touch(&active));
+        assert!(sessions.touch(&streaming));
+        assert!(!sessions.touch(&idle));
+        drop(stream);
+        std::thread::sleep(Duration::from_millis(250));
+        assert!(!sessions.contains(&streaming));
+    }
+
+    #[test]
+    fn should_generate_unique_session_ids() {
+        let sessions = SessionRegistry::new();
+
+        let first = sessions.create().unwrap();
+        let second = sessions.create().unwrap();
+
+        assert_ne!(first, second);
+        assert!(first.chars().all(|digit| digit.is_ascii_hexdigit()));
+        assert_eq!(first.len(), 32);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions.notify("missing", "{}"), 0);
+    }
+}
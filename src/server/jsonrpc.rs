@@ -1,7 +1,24 @@
 //! JSON-RPC 2.0 message handling for MCP protocol.
 
 use crate::domain::{YnabError, YnabResult};
-use serde_json::Value;
+use serde_json::{Value, json};
+
+/// Invalid JSON was received.
+pub const PARSE_ERROR: i32 = -32700;
+/// The JSON sent is not a valid request object.
+pub const INVALID_REQUEST: i32 = -32600;
+/// The method does not exist.
+pub const METHOD_NOT_FOUND: i32 = -32601;
+/// Invalid method parameters or tool arguments.
+pub const INVALID_PARAMS: i32 = -32602;
+/// Generic server error, such as a failed YNAB API request.
+pub const SERVER_ERROR: i32 = -32000;
+/// The YNAB access token was rejected or lacks permission.
+pub const UNAUTHORIZED: i32 = -32001;
+/// The requested MCP resource or YNAB record does not exist.
+pub const RESOURCE_NOT_FOUND: i32 = -32002;
+/// The YNAB API rate limit was reached.
+pub const RATE_LIMITED: i32 = -32003;
 
 /// A JSON-RPC 2.0 request message.
 #[derive(Debug, Clone, PartialEq)]
@@ -14,18 +31,50 @@ pub struct JsonRpcRequest {
 
 impl JsonRpcRequest {
     /// Parses a JSON-RPC request from a JSON string.
+    ///
+    /// Returns [`YnabError::ApiError`] when the text is not JSON, and the
+    /// errors of [`JsonRpcRequest::from_value`] when it is not a request.
     pub fn from_json(json: &str) -> YnabResult<Self> {
         let value: Value = serde_json::from_str(json)
             .map_err(|e| YnabError::api_error(format!("Invalid JSON: {}", e)))?;
 
-        let jsonrpc = value["jsonrpc"]
-            .as_str()
-            .ok_or_else(|| YnabError::api_error("Missing jsonrpc field".to_string()))?
-            .to_string();
+        Self::from_value(&value)
+    }
+
+    /// Reads a JSON-RPC request from parsed JSON.
+    ///
+    /// Returns [`YnabError::InvalidArgument`] naming the offending member when
+    /// the value is not a JSON-RPC 2.0 request object.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::server::JsonRpcRequest;
+    /// use ynab_mcp::YnabError;
+    /// use serde_json::json;
+    ///
+    /// let request = JsonRpcRequest::from_value(&json!({"jsonrpc": "2.0", "id": 1, "method": "ping"}));
+    /// assert_eq!(request.unwrap().method, "ping");
+    ///
+    /// let missing_method = JsonRpcRequest::from_value(&json!({"jsonrpc": "2.0", "id": 1}));
+    /// assert!(matches!(missing_method, Err(YnabError::InvalidArgument { field, .. }) if field == "method"));
+    /// ```
+    pub fn from_value(value: &Value) -> YnabResult<Self> {
+        if !value.is_object() {
+            return Err(YnabError::invalid_argument(
+                "request",
+                "must be a JSON object",
+            ));
+        }
+
+        let jsonrpc = match value["jsonrpc"].as_str() {
+            Some("2.0") => "2.0".to_string(),
+            Some(_) => return Err(YnabError::invalid_argument("jsonrpc", "must be \"2.0\"")),
+            None => return Err(YnabError::invalid_argument("jsonrpc", "is required")),
+        };
 
         let method = value["method"]
             .as_str()
-            .ok_or_else(|| YnabError::api_error("Missing method field".to_string()))?
+            .ok_or_else(|| YnabError::invalid_argument("method", "must be a string"))?
             .to_string();
 
         let id = if value["id"].is_null() {
@@ -65,6 +114,77 @@ pub struct JsonRpcError {
     pub data: Option<Value>,
 }
 
+impl JsonRpcError {
+    /// Maps a domain error to a JSON-RPC error.
+    ///
+    /// The `data` payload carries the error `kind`, the offending `field` when
+    /// known, and the YNAB error id, detail or rate limit quota when present.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::server::{INVALID_PARAMS, JsonRpcError};
+    /// use ynab_mcp::YnabError;
+    ///
+    /// let error = JsonRpcError::from_ynab_error(&YnabError::invalid_argument("months", "must be at least 1"));
+    /// assert_eq!(error.code, INVALID_PARAMS);
+    /// let data = error.data.unwrap();
+    /// assert_eq!(data["kind"], "invalid_argument");
+    /// assert_eq!(data["field"], "months");
+    /// ```
+    pub fn from_ynab_error(error: &YnabError) -> Self {
+        let code = match error {
+            YnabError::InvalidArgument { .. }
+            | YnabError::InvalidBudgetId(_)
+            | YnabError::CategoryNotFound(_)
+            | YnabError::AccountNotFound(_)
+            | YnabError::PayeeNotFound(_)
+            | YnabError::TransactionNotFound(_)
+            | YnabError::InvalidAmount(_)
+            | YnabError::InvalidDate(_) => INVALID_PARAMS,
+            YnabError::ResourceNotFound(_) | YnabError::NotFound { .. } => RESOURCE_NOT_FOUND,
+            YnabError::Unauthorized { .. } | YnabError::Forbidden { .. } => UNAUTHORIZED,
+            YnabError::RateLimited { .. } => RATE_LIMITED,
+            YnabError::Conflict { .. }
+            | YnabError::HttpApiError(_)
+            | YnabError::IoError(_)
+            | YnabError::ApiError(_) => SERVER_ERROR,
+        };
+
+        let mut data = json!({ "kind": error.kind() });
+        if let Some(field) = error.field() {
+            data["field"] = json!(field);
+        }
+        match error {
+            YnabError::ResourceNotFound(uri) => data["uri"] = json!(uri),
+            YnabError::Unauthorized { id, detail }
+            | YnabError::Forbidden { id, detail }
+            | YnabError::NotFound { id, detail }
+            | YnabError::Conflict { id, detail } => {
+                data["ynab_error_id"] = json!(id);
+                data["detail"] = json!(detail);
+            }
+            YnabError::RateLimited {
+                remaining,
+                retry_after_secs,
+                id,
+                detail,
+            } => {
+                data["remaining"] = json!(remaining);
+                data["retry_after_secs"] = json!(retry_after_secs);
+                data["ynab_error_id"] = json!(id);
+                data["detail"] = json!(detail);
+            }
+            _ => {}
+        }
+
+        Self {
+            code,
+            message: error.to_string(),
+            data: Some(data),
+        }
+    }
+}
+
 impl JsonRpcResponse {
     /// Creates a success response with the given result.
     pub fn success(id: impl Into<Value>, result: Value) -> Self {
@@ -90,6 +210,16 @@ impl JsonRpcResponse {
         }
     }
 
+    /// Creates an error response for a domain error, see [`JsonRpcError::from_ynab_error`].
+    pub fn from_ynab_error(id: impl Into<Value>, error: &YnabError) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            id: id.into(),
+            result: None,
+            error: Some(JsonRpcError::from_ynab_error(error)),
+        }
+    }
+
     /// Converts the response to a JSON string.
     pub fn to_json(&self) -> String {
         let mut response = serde_json::Map::new();
@@ -170,8 +300,28 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            YnabError::ApiError(msg) => assert_eq!(msg, "Missing method field"),
-            other => panic!("Expected ApiError, got: {:?}", other),
+            YnabError::InvalidArgument { field, .. } => assert_eq!(field, "method"),
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_requests_with_missing_or_wrong_version() {
+        for (request, field) in [
+            (json!({"id": 1, "method": "ping"}), "jsonrpc"),
+            (
+                json!({"jsonrpc": "1.0", "id": 1, "method": "ping"}),
+                "jsonrpc",
+            ),
+            (json!({"jsonrpc": "2.0", "id": 1, "method": 7}), "method"),
+            (json!([1, 2]), "request"),
+        ] {
+            match JsonRpcRequest::from_value(&request) {
+                Err(YnabError::InvalidArgument { field: actual, .. }) => {
+                    assert_eq!(actual, field, "{}", request)
+                }
+                other => panic!("Expected InvalidArgument, got: {:?}", other),
+            }
         }
     }
 
@@ -192,4 +342,73 @@ mod tests {
         assert!(json.contains("\"message\":\"Invalid Request\""));
         assert!(json.contains("\"details\":\"Missing required field\""));
     }
+
+    #[test]
+    fn should_map_domain_errors_to_error_codes() {
+        let cases = [
+            (
+                YnabError::invalid_argument("limit", "too big"),
+                INVALID_PARAMS,
+            ),
+            (YnabError::category_not_found("Holidays"), INVALID_PARAMS),
+            (YnabError::invalid_date("2024-13-01"), INVALID_PARAMS),
+            (
+                YnabError::resource_not_found("ynab://payees"),
+                RESOURCE_NOT_FOUND,
+            ),
+            (
+                YnabError::not_found("404.1", "Not found"),
+                RESOURCE_NOT_FOUND,
+            ),
+            (YnabError::unauthorized("401", "Unauthorized"), UNAUTHORIZED),
+            (YnabError::forbidden("403.1", "Lapsed"), UNAUTHORIZED),
+            (
+                YnabError::rate_limited(0, std::time::Duration::from_secs(5)),
+                RATE_LIMITED,
+            ),
+            (YnabError::conflict("409", "Duplicate"), SERVER_ERROR),
+            (YnabError::api_error("HTTP 500 for /budgets"), SERVER_ERROR),
+        ];
+
+        for (error, code) in cases {
+            let mapped = JsonRpcError::from_ynab_error(&error);
+
+            assert_eq!(mapped.code, code, "{:?}", error);
+            assert_eq!(mapped.message, error.to_string());
+            assert_eq!(mapped.data.unwrap()["kind"], error.kind());
+        }
+    }
+
+    #[test]
+    fn should_describe_offending_field_in_error_data() {
+        let not_found = JsonRpcError::from_ynab_error(&YnabError::category_not_found("Holidays"));
+        let throttled = JsonRpcError::from_ynab_error(&YnabError::rate_limited(
+            2,
+            std::time::Duration::from_secs(30),
+        ));
+        let unauthorized =
+            JsonRpcError::from_ynab_error(&YnabError::unauthorized("401", "Unauthorized"));
+
+        assert_eq!(
+            not_found.data,
+            Some(json!({"kind": "category_not_found", "field": "category_id"}))
+        );
+        let throttled = throttled.data.unwrap();
+        assert_eq!(throttled["remaining"], 2);
+        assert_eq!(throttled["retry_after_secs"], 30);
+        assert_eq!(
+            unauthorized.data,
+            Some(json!({"kind": "unauthorized", "ynab_error_id": "401", "detail": "Unauthorized"}))
+        );
+    }
+
+    #[test]
+    fn should_keep_request_id_in_domain_error_response() {
+        let response =
+            JsonRpcResponse::from_ynab_error(7, &YnabError::invalid_argument("params", "missing"));
+
+        assert_eq!(response.id, json!(7));
+        assert!(response.result.is_none());
+        assert_eq!(response.error.unwrap().code, INVALID_PARAMS);
+    }
 }
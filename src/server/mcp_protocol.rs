@@ -1,8 +1,12 @@
 //! MCP (Model Context Protocol) implementation.
 
-use crate::domain::YnabResult;
+use crate::domain::{YnabError, YnabResult};
 use crate::server::handler::Handler;
-use crate::server::jsonrpc::{JsonRpcRequest, JsonRpcResponse};
+use crate::server::jsonrpc::{
+    INVALID_REQUEST, JsonRpcError, JsonRpcRequest, JsonRpcResponse, METHOD_NOT_FOUND, PARSE_ERROR,
+};
+use crate::server::prompts::{get_prompt, list_prompts};
+use crate::server::resources::RESOURCE_MIME_TYPE;
 use serde_json::json;
 
 /// MCP server that wraps the Handler and provides MCP protocol methods.
@@ -16,17 +20,77 @@ impl McpServer {
         Self { handler }
     }
 
+    /// Parses a raw JSON-RPC message and handles it, returning the response to
+    /// send back.
+    ///
+    /// Text that is not JSON becomes a `-32700` parse error, and JSON that is
+    /// not a request object a `-32600` invalid request error. Handler failures
+    /// are mapped by [`JsonRpcResponse::from_ynab_error`] with the request's
+    /// id. Notifications, which carry no id, are handled but never answered,
+    /// so this returns `None` for them.
+    pub async fn handle_message(&self, message: &str) -> Option<JsonRpcResponse> {
+        match serde_json::from_str(message) {
+            Ok(value) => self.handle_value(&value).await,
+            Err(e) => Some(JsonRpcResponse::error(
+                serde_json::Value::Null,
+                PARSE_ERROR,
+                format!("Parse error: {}", e),
+                None,
+            )),
+        }
+    }
+
+    /// Handles a JSON-RPC message that has already been parsed, such as one
+    /// element of an HTTP batch, as [`handle_message`](Self::handle_message) does.
+    pub async fn handle_value(&self, value: &serde_json::Value) -> Option<JsonRpcResponse> {
+        // Error response for well-formed JSON that is not a JSON-RPC request
+        let request = match JsonRpcRequest::from_value(value) {
+            Ok(req) => req,
+            Err(e) => {
+                let id = match &value["id"] {
+                    id @ (serde_json::Value::String(_) | serde_json::Value::Number(_)) => {
+                        id.clone()
+                    }
+                    _ => serde_json::Value::Null,
+                };
+                return Some(JsonRpcResponse::error(
+                    id,
+                    INVALID_REQUEST,
+                    format!("Invalid Request: {}", e),
+                    JsonRpcError::from_ynab_error(&e).data,
+                ));
+            }
+        };
+
+        // Process request through MCP protocol layer
+        let Some(id) = request.id.clone() else {
+            let _ = self.handle_request(request).await;
+            return None;
+        };
+        Some(match self.handle_request(request).await {
+            Ok(resp) => resp,
+            Err(e) => JsonRpcResponse::from_ynab_error(id, &e),
+        })
+    }
+
     /// Handles an MCP request and returns an appropriate response.
-    pub fn handle_request(&self, request: JsonRpcRequest) -> YnabResult<JsonRpcResponse> {
+    ///
+    /// Tool calls may await YNAB API requests, so this must be driven by an async runtime.
+    pub async fn handle_request(&self, request: JsonRpcRequest) -> YnabResult<JsonRpcResponse> {
         let id = request.id.clone().unwrap_or(json!(null));
 
         match request.method.as_str() {
             "initialize" => self.handle_initialize(id, request.params),
             "tools/list" => self.handle_tools_list(id),
-            "tools/call" => self.handle_tools_call(id, request.params),
+            "tools/call" => self.handle_tools_call(id, request.params).await,
+            "resources/list" => self.handle_resources_list(id).await,
+            "resources/templates/list" => self.handle_resource_templates_list(id),
+            "resources/read" => self.handle_resources_read(id, request.params).await,
+            "prompts/list" => self.handle_prompts_list(id),
+            "prompts/get" => self.handle_prompts_get(id, request.params),
             _ => Ok(JsonRpcResponse::error(
                 id,
-                -32601,
+                METHOD_NOT_FOUND,
                 "Method not found".to_string(),
                 None,
             )),
@@ -46,7 +110,9 @@ impl McpServer {
         let result = json!({
             "protocolVersion": "2024-11-05",
             "capabilities": {
-                "tools": {}
+                "tools": {},
+                "resources": {},
+                "prompts": {}
             },
             "serverInfo": {
                 "name": "ynab-mcp-server",
@@ -65,7 +131,8 @@ impl McpServer {
             .map(|tool| {
                 json!({
                     "name": tool.name,
-                    "description": tool.description
+                    "description": tool.description,
+                    "inputSchema": tool.input_schema
                 })
             })
             .collect();
@@ -78,22 +145,26 @@ impl McpServer {
     }
 
     /// Handles the tools/call method.
-    fn handle_tools_call(
+    ///
+    /// Unknown tools and arguments that fail validation are protocol errors
+    /// (`-32602`). Any other failure, such as an unknown category or a failed
+    /// YNAB request, is returned as a result with `isError: true` so the model
+    /// can read the message and recover; its `structuredContent` carries the
+    /// same `kind` and `field` as the `data` of a protocol error.
+    async fn handle_tools_call(
         &self,
         id: serde_json::Value,
         params: Option<serde_json::Value>,
     ) -> YnabResult<JsonRpcResponse> {
-        let params = params.ok_or_else(|| {
-            crate::domain::YnabError::api_error("Missing params for tools/call".to_string())
-        })?;
+        let params = params.ok_or_else(|| YnabError::invalid_argument("params", "is required"))?;
 
         let tool_name = params["name"]
             .as_str()
-            .ok_or_else(|| crate::domain::YnabError::api_error("Missing tool name".to_string()))?;
+            .ok_or_else(|| YnabError::invalid_argument("name", "is required"))?;
 
         let arguments = params["arguments"].clone();
 
-        match self.handler.execute_tool(tool_name, arguments) {
+        match self.handler.execute_tool(tool_name, arguments).await {
             Ok(content) => {
                 let result = json!({
                     "content": [
@@ -105,12 +176,165 @@ impl McpServer {
                 });
                 Ok(JsonRpcResponse::success(id, result))
             }
-            Err(e) => Ok(JsonRpcResponse::error(
+            Err(e @ YnabError::InvalidArgument { .. }) => {
+                Ok(JsonRpcResponse::from_ynab_error(id, &e))
+            }
+            Err(e) => Ok(JsonRpcResponse::success(
                 id,
-                -32000,
-                format!("Tool execution failed: {}", e),
-                None,
+                json!({
+                    "content": [
+                        {
+                            "type": "text",
+                            "text": e.to_string()
+                        }
+                    ],
+                    "structuredContent": JsonRpcError::from_ynab_error(&e).data,
+                    "isError": true
+                }),
+            )),
+        }
+    }
+
+    /// Handles the resources/list method.
+    async fn handle_resources_list(&self, id: serde_json::Value) -> YnabResult<JsonRpcResponse> {
+        match self.handler.list_resources().await {
+            Ok(resources) => {
+                let resource_objects: Vec<serde_json::Value> = resources
+                    .into_iter()
+                    .map(|resource| {
+                        json!({
+                            "uri": resource.uri,
+                            "name": resource.name,
+                            "description": resource.description,
+                            "mimeType": resource.mime_type
+                        })
+                    })
+                    .collect();
+
+                Ok(JsonRpcResponse::success(
+                    id,
+                    json!({ "resources": resource_objects }),
+                ))
+            }
+            Err(e) => Ok(JsonRpcResponse::from_ynab_error(id, &e)),
+        }
+    }
+
+    /// Handles the resources/templates/list method.
+    fn handle_resource_templates_list(&self, id: serde_json::Value) -> YnabResult<JsonRpcResponse> {
+        let templates: Vec<serde_json::Value> = self
+            .handler
+            .list_resource_templates()
+            .into_iter()
+            .map(|template| {
+                json!({
+                    "uriTemplate": template.uri_template,
+                    "name": template.name,
+                    "description": template.description,
+                    "mimeType": template.mime_type
+                })
+            })
+            .collect();
+
+        Ok(JsonRpcResponse::success(
+            id,
+            json!({ "resourceTemplates": templates }),
+        ))
+    }
+
+    /// Handles the resources/read method.
+    async fn handle_resources_read(
+        &self,
+        id: serde_json::Value,
+        params: Option<serde_json::Value>,
+    ) -> YnabResult<JsonRpcResponse> {
+        let Some(uri) = params
+            .as_ref()
+            .and_then(|params| params["uri"].as_str())
+            .map(str::to_string)
+        else {
+            return Ok(JsonRpcResponse::from_ynab_error(
+                id,
+                &YnabError::invalid_argument("uri", "is required"),
+            ));
+        };
+
+        match self.handler.read_resource(&uri).await {
+            Ok(text) => Ok(JsonRpcResponse::success(
+                id,
+                json!({
+                    "contents": [
+                        {
+                            "uri": uri,
+                            "mimeType": RESOURCE_MIME_TYPE,
+                            "text": text
+                        }
+                    ]
+                }),
             )),
+            Err(e) => Ok(JsonRpcResponse::from_ynab_error(id, &e)),
+        }
+    }
+
+    /// Handles the prompts/list method.
+    fn handle_prompts_list(&self, id: serde_json::Value) -> YnabResult<JsonRpcResponse> {
+        let prompts: Vec<serde_json::Value> = list_prompts()
+            .into_iter()
+            .map(|prompt| {
+                let arguments: Vec<serde_json::Value> = prompt
+                    .arguments
+                    .into_iter()
+                    .map(|argument| {
+                        json!({
+                            "name": argument.name,
+                            "description": argument.description,
+                            "required": argument.required
+                        })
+                    })
+                    .collect();
+
+                json!({
+                    "name": prompt.name,
+                    "description": prompt.description,
+                    "arguments": arguments
+                })
+            })
+            .collect();
+
+        Ok(JsonRpcResponse::success(id, json!({ "prompts": prompts })))
+    }
+
+    /// Handles the prompts/get method.
+    fn handle_prompts_get(
+        &self,
+        id: serde_json::Value,
+        params: Option<serde_json::Value>,
+    ) -> YnabResult<JsonRpcResponse> {
+        let params = params.unwrap_or_else(|| json!({}));
+        let Some(name) = params["name"].as_str() else {
+            return Ok(JsonRpcResponse::from_ynab_error(
+                id,
+                &YnabError::invalid_argument("name", "is required"),
+            ));
+        };
+
+        match get_prompt(name, &params["arguments"]) {
+            Ok(prompt) => Ok(JsonRpcResponse::success(
+                id,
+                json!({
+                    "description": prompt.description,
+                    "messages": [
+                        {
+                            "role": "user",
+                            "content": {
+                                "type": "text",
+                                "text": prompt.text
+                            }
+                        }
+                    ]
+                }),
+            )),
+            Err(e) => Ok(JsonRpcResponse::from_ynab_error(id, &e)),
         }
     }
 }
@@ -119,11 +343,11 @@ impl McpServer {
 mod tests {
     use super::*;
     use crate::server::handler::Handler;
-    use crate::server::jsonrpc::JsonRpcRequest;
+    use crate::server::jsonrpc::{INVALID_PARAMS, JsonRpcRequest};
     use serde_json::json;
 
-    #[test]
-    fn should_handle_initialize_request() {
+    #[tokio::test]
+    async fn should_handle_initialize_request() {
         let handler = Handler::new();
         let mcp_server = McpServer::new(handler);
         let request = JsonRpcRequest::from_json(
@@ -139,7 +363,7 @@ mod tests {
         )
         .unwrap();
 
-        let response = mcp_server.handle_request(request).unwrap();
+        let response = mcp_server.handle_request(request).await.unwrap();
 
         assert_eq!(response.jsonrpc, "2.0");
         assert_eq!(response.id, json!(1));
@@ -151,8 +375,8 @@ mod tests {
         assert!(result["serverInfo"].is_object());
     }
 
-    #[test]
-    fn should_list_available_tools() {
+    #[tokio::test]
+    async fn should_list_available_tools() {
         use crate::adapters::YnabClient;
         use crate::domain::TransactionService;
 
@@ -171,7 +395,7 @@ mod tests {
         )
         .unwrap();
 
-        let response = mcp_server.handle_request(request).unwrap();
+        let response = mcp_server.handle_request(request).await.unwrap();
 
         assert_eq!(response.jsonrpc, "2.0");
         assert_eq!(response.id, json!(2));
@@ -179,21 +403,42 @@ mod tests {
 
         let result = response.result.unwrap();
         let tools = result["tools"].as_array().unwrap();
-        assert_eq!(tools.len(), 5); // Our 5 analytical tools
+        assert_eq!(tools.len(), 11); // Our 11 analytical tools
 
         // Verify tool structure
         let first_tool = &tools[0];
         assert!(first_tool["name"].is_string());
         assert!(first_tool["description"].is_string());
+        assert_eq!(first_tool["inputSchema"]["type"], "object");
+        assert_eq!(first_tool["inputSchema"]["required"], json!(["budget_id"]));
+        assert!(first_tool["inputSchema"]["properties"]["budget_id"].is_object());
     }
 
-    #[test]
-    fn should_execute_tool_via_mcp_protocol() {
+    #[tokio::test]
+    async fn should_execute_tool_via_mcp_protocol() {
         use crate::adapters::YnabClient;
         use crate::domain::TransactionService;
 
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/test-budget-123/categories")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"category_groups":[{"id":"group-1","categories":[{"id":"cat-1","name":"Groceries"}]}]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/test-budget-123/transactions")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"transactions":[{"id":"txn-1","account_id":"acc-1","category_id":"cat-1","amount":-12000}]}}"#,
+            )
+            .create_async()
+            .await;
+
         let transaction_service = TransactionService::new();
-        let ynab_client = YnabClient::new("test-token".to_string());
+        let ynab_client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
         let handler = Handler::with_full_integration(transaction_service, ynab_client);
         let mcp_server = McpServer::new(handler);
 
@@ -213,7 +458,7 @@ mod tests {
         )
         .unwrap();
 
-        let response = mcp_server.handle_request(request).unwrap();
+        let response = mcp_server.handle_request(request).await.unwrap();
 
         assert_eq!(response.jsonrpc, "2.0");
         assert_eq!(response.id, json!(3));
@@ -227,11 +472,11 @@ mod tests {
 
         let content_item = &content_array[0];
         assert_eq!(content_item["type"], json!("text"));
-        assert!(content_item["text"].is_string());
+        assert!(content_item["text"].as_str().unwrap().contains("12000"));
     }
 
-    #[test]
-    fn should_handle_unknown_mcp_method() {
+    #[tokio::test]
+    async fn should_handle_unknown_mcp_method() {
         let handler = Handler::new();
         let mcp_server = McpServer::new(handler);
 
@@ -245,7 +490,7 @@ mod tests {
         )
         .unwrap();
 
-        let response = mcp_server.handle_request(request).unwrap();
+        let response = mcp_server.handle_request(request).await.unwrap();
 
         assert_eq!(response.jsonrpc, "2.0");
         assert_eq!(response.id, json!(4));
@@ -256,8 +501,8 @@ mod tests {
         assert_eq!(error.message, "Method not found");
     }
 
-    #[test]
-    fn should_handle_tools_call_with_invalid_tool_name() {
+    #[tokio::test]
+    async fn should_handle_tools_call_with_invalid_tool_name() {
         use crate::adapters::YnabClient;
         use crate::domain::TransactionService;
 
@@ -279,14 +524,335 @@ mod tests {
         )
         .unwrap();
 
-        let response = mcp_server.handle_request(request).unwrap();
+        let response = mcp_server.handle_request(request).await.unwrap();
 
         assert_eq!(response.jsonrpc, "2.0");
         assert_eq!(response.id, json!(5));
         assert!(response.error.is_some());
 
         let error = response.error.unwrap();
-        assert_eq!(error.code, -32000);
-        assert!(error.message.contains("Tool execution failed"));
+        assert_eq!(error.code, INVALID_PARAMS);
+        assert!(error.message.contains("nonexistent_tool"));
+        assert_eq!(
+            error.data,
+            Some(json!({"kind": "invalid_argument", "field": "name"}))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_tool_arguments_as_invalid_params() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 13, "method": "tools/call", "params": {"name": "analyze_spending_trends", "arguments": {"budget_id": "b", "months": 0}}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let error = response.error.unwrap();
+        assert_eq!(response.id, json!(13));
+        assert_eq!(error.code, INVALID_PARAMS);
+        assert_eq!(error.data.unwrap()["field"], "months");
+    }
+
+    #[tokio::test]
+    async fn should_return_domain_failures_as_tool_errors() {
+        use crate::adapters::YnabClient;
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets/budget-1/categories")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"category_groups":[{"id":"group-1","categories":[{"id":"cat-1","name":"Groceries"}]}]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-1/transactions")
+            .with_status(200)
+            .with_body(r#"{"data":{"transactions":[]}}"#)
+            .create_async()
+            .await;
+        let ynab_client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+        let mcp_server = McpServer::new(Handler::with_ynab_client(ynab_client));
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 14, "method": "tools/call", "params": {"name": "analyze_category_spending", "arguments": {"budget_id": "budget-1", "category_name": "Holidays"}}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        assert!(response.error.is_none());
+        let result = response.result.unwrap();
+        assert_eq!(result["isError"], true);
+        assert_eq!(result["content"][0]["text"], "Category not found: Holidays");
+        assert_eq!(
+            result["structuredContent"],
+            json!({"kind": "category_not_found", "field": "category_id"})
+        );
+    }
+
+    #[tokio::test]
+    async fn should_answer_malformed_messages_with_parse_or_invalid_request_errors() {
+        use crate::server::jsonrpc::{INVALID_REQUEST, PARSE_ERROR};
+
+        let mcp_server = McpServer::new(Handler::new());
+
+        let response = mcp_server
+            .handle_message(r#"{"jsonrpc": "2.0", "id": 1"#)
+            .await
+            .unwrap();
+        assert_eq!(response.id, json!(null));
+        assert_eq!(response.error.unwrap().code, PARSE_ERROR);
+
+        for (message, id, field) in [
+            (r#"{"jsonrpc": "2.0", "id": 2}"#, json!(2), "method"),
+            (
+                r#"{"id": "three", "method": "ping"}"#,
+                json!("three"),
+                "jsonrpc",
+            ),
+            (
+                r#"{"jsonrpc": "1.0", "id": 4, "method": "ping"}"#,
+                json!(4),
+                "jsonrpc",
+            ),
+            (r#"[]"#, json!(null), "request"),
+        ] {
+            let response = mcp_server.handle_message(message).await.unwrap();
+
+            let error = response.error.unwrap();
+            assert_eq!(response.id, id, "{}", message);
+            assert_eq!(error.code, INVALID_REQUEST, "{}", message);
+            assert_eq!(error.data.unwrap()["field"], field, "{}", message);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_answer_missing_tool_params_with_invalid_params() {
+        let mcp_server = McpServer::new(Handler::new());
+
+        let response = mcp_server
+            .handle_message(r#"{"jsonrpc": "2.0", "id": 15, "method": "tools/call"}"#)
+            .await
+            .unwrap();
+
+        let error = response.error.unwrap();
+        assert_eq!(response.id, json!(15));
+        assert_eq!(error.code, INVALID_PARAMS);
+        assert_eq!(
+            error.data,
+            Some(json!({"kind": "invalid_argument", "field": "params"}))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_answer_notifications() {
+        let mcp_server = McpServer::new(Handler::new());
+
+        let response = mcp_server
+            .handle_message(r#"{"jsonrpc": "2.0", "method": "notifications/initialized"}"#)
+            .await;
+
+        assert_eq!(response, None);
+    }
+
+    #[tokio::test]
+    async fn should_handle_already_parsed_messages() {
+        let mcp_server = McpServer::new(Handler::new());
+
+        let response = mcp_server
+            .handle_value(&json!({"jsonrpc": "2.0", "id": 7, "method": "tools/list"}))
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, json!(7));
+        assert!(response.result.is_some());
+    }
+
+    /// Creates an MCP server backed by a mock YNAB API with one budget and its accounts.
+    async fn mcp_server_with_mock_budget() -> (McpServer, mockito::ServerGuard) {
+        use crate::adapters::YnabClient;
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/budgets")
+            .with_status(200)
+            .with_body(r#"{"data":{"budgets":[{"id":"budget-1","name":"Household"}]}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/budgets/budget-1/accounts")
+            .with_status(200)
+            .with_body(
+                r#"{"data":{"accounts":[{"id":"acc-1","name":"Checking","type":"checking","on_budget":true},{"id":"acc-2","name":"Visa","type":"creditCard","on_budget":true}]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let ynab_client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+        let mcp_server = McpServer::new(Handler::with_ynab_client(ynab_client));
+        (mcp_server, server)
+    }
+
+    #[tokio::test]
+    async fn should_advertise_resources_capability() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        assert!(response.result.unwrap()["capabilities"]["resources"].is_object());
+    }
+
+    #[tokio::test]
+    async fn should_list_resources_for_each_budget() {
+        let (mcp_server, _server) = mcp_server_with_mock_budget().await;
+        let request =
+            JsonRpcRequest::from_json(r#"{"jsonrpc": "2.0", "id": 5, "method": "resources/list"}"#)
+                .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let result = response.result.unwrap();
+        let uris: Vec<&str> = result["resources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|resource| resource["uri"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            uris,
+            vec![
+                "ynab://budgets",
+                "ynab://budgets/budget-1/categories",
+                "ynab://budgets/budget-1/accounts"
+            ]
+        );
+        assert_eq!(result["resources"][0]["mimeType"], "application/json");
+    }
+
+    #[tokio::test]
+    async fn should_list_resource_templates() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 6, "method": "resources/templates/list"}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let templates = response.result.unwrap()["resourceTemplates"].clone();
+        assert_eq!(
+            templates[0]["uriTemplate"],
+            "ynab://budgets/{budget_id}/categories"
+        );
+        assert_eq!(
+            templates[1]["uriTemplate"],
+            "ynab://budgets/{budget_id}/accounts"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_read_accounts_resource() {
+        let (mcp_server, _server) = mcp_server_with_mock_budget().await;
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 7, "method": "resources/read", "params": {"uri": "ynab://budgets/budget-1/accounts"}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let contents = response.result.unwrap()["contents"][0].clone();
+        assert_eq!(contents["uri"], "ynab://budgets/budget-1/accounts");
+        assert_eq!(contents["mimeType"], "application/json");
+        let accounts: serde_json::Value =
+            serde_json::from_str(contents["text"].as_str().unwrap()).unwrap();
+        assert_eq!(accounts["accounts"][1]["name"], "Visa");
+        assert_eq!(accounts["accounts"][1]["is_liability"], true);
+    }
+
+    #[tokio::test]
+    async fn should_report_unknown_resource() {
+        let (mcp_server, _server) = mcp_server_with_mock_budget().await;
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 8, "method": "resources/read", "params": {"uri": "ynab://budgets/budget-1/payees"}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let error = response.error.unwrap();
+        assert_eq!(response.id, json!(8));
+        assert_eq!(error.code, -32002);
+        let data = error.data.unwrap();
+        assert_eq!(data["kind"], "resource_not_found");
+        assert_eq!(data["uri"], "ynab://budgets/budget-1/payees");
+    }
+
+    #[tokio::test]
+    async fn should_require_uri_for_resource_read() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 9, "method": "resources/read", "params": {}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        assert_eq!(response.error.unwrap().code, -32602);
+    }
+
+    #[tokio::test]
+    async fn should_list_prompts() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request =
+            JsonRpcRequest::from_json(r#"{"jsonrpc": "2.0", "id": 10, "method": "prompts/list"}"#)
+                .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let prompts = response.result.unwrap()["prompts"].clone();
+        assert_eq!(prompts.as_array().unwrap().len(), 3);
+        assert_eq!(prompts[0]["name"], "monthly_review");
+        assert_eq!(prompts[0]["arguments"][1]["name"], "month");
+        assert_eq!(prompts[0]["arguments"][1]["required"], true);
+    }
+
+    #[tokio::test]
+    async fn should_get_prompt_with_arguments() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 11, "method": "prompts/get", "params": {"name": "overspending_investigation", "arguments": {"budget_id": "budget-1", "month": "2024-07"}}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let message = response.result.unwrap()["messages"][0].clone();
+        assert_eq!(message["role"], "user");
+        let text = message["content"]["text"].as_str().unwrap();
+        assert!(text.contains("budget_health_check"));
+        assert!(text.contains("2024-07"));
+    }
+
+    #[tokio::test]
+    async fn should_reject_prompt_with_invalid_arguments() {
+        let mcp_server = McpServer::new(Handler::new());
+        let request = JsonRpcRequest::from_json(
+            r#"{"jsonrpc": "2.0", "id": 12, "method": "prompts/get", "params": {"name": "monthly_review", "arguments": {"budget_id": "budget-1"}}}"#,
+        )
+        .unwrap();
+
+        let response = mcp_server.handle_request(request).await.unwrap();
+
+        let error = response.error.unwrap();
+        assert_eq!(response.id, json!(12));
+        assert_eq!(error.code, -32602);
+        assert!(error.message.contains("month"));
     }
 }
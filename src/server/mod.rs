@@ -3,87 +3,153 @@
 //! This module contains the Model Context Protocol server implementation,
 //! including request handlers and server setup.
 
+pub mod filter;
 pub mod handler;
+pub mod http;
 pub mod jsonrpc;
 pub mod mcp_protocol;
+pub mod prompts;
+pub mod resources;
+pub mod schema;
 pub mod transport;
 
+pub use filter::*;
 pub use handler::*;
+pub use http::*;
 pub use jsonrpc::*;
 pub use mcp_protocol::*;
+pub use prompts::*;
+pub use resources::*;
+pub use schema::*;
 pub use transport::*;
 
 use crate::adapters::YnabClient;
-use crate::domain::{TransactionService, YnabResult};
-use std::io::{Read, Write};
+use crate::domain::{TransactionService, YnabError, YnabResult};
+use std::io::{BufReader, Read, Write};
+use std::sync::{Arc, mpsc};
+use std::thread;
+use tokio::task::JoinSet;
 
 /// Runs the complete MCP server session, processing messages from stdin and writing to stdout.
 ///
 /// This is the main server runtime that ties together all components:
-/// - Transport layer (Content-Length framed stdio)
+/// - Transport layer (newline-delimited or Content-Length framed stdio)
 /// - JSON-RPC message parsing
 /// - MCP protocol handling
-/// - Tool execution via Handler
+/// - Tool execution via Handler on a session-owned async runtime
 ///
 /// # Arguments
 /// * `reader` - Input stream (usually stdin)
 /// * `writer` - Output stream (usually stdout)
 /// * `api_token` - YNAB API token for client integration
-pub fn run_mcp_server<R: Read, W: Write>(
-    mut reader: R,
-    mut writer: W,
+pub fn run_mcp_server<R: Read, W: Write + Send>(
+    reader: R,
+    writer: W,
     api_token: &str,
+) -> YnabResult<()> {
+    let ynab_client = YnabClient::new(api_token.to_string());
+    run_mcp_server_with_client(reader, writer, ynab_client)
+}
+
+/// Runs the complete MCP server session against an already configured YNAB client.
+///
+/// This allows the server to be pointed at a different API base URL, for example
+/// a local mock server created with [`YnabClient::new_with_base_url`].
+///
+/// The stdio framing is detected from the first message and responses are written
+/// back in the same framing.
+/// A message that cannot be read, such as a line of invalid UTF-8, is answered
+/// with a parse error and the session carries on; only the end of the input
+/// stream or a failure reading it ends the session.
+///
+/// Each request is spawned onto a multi-threaded Tokio runtime owned by the session,
+/// so a tool awaiting the YNAB API never blocks reading the next message. Responses
+/// are written by a dedicated writer thread in the order they complete; clients match
+/// them to requests by JSON-RPC id.
+///
+/// # Arguments
+/// * `reader` - Input stream (usually stdin)
+/// * `writer` - Output stream (usually stdout)
+/// * `ynab_client` - YNAB API client used by the tool handlers
+pub fn run_mcp_server_with_client<R: Read, W: Write + Send>(
+    reader: R,
+    writer: W,
+    ynab_client: YnabClient,
 ) -> YnabResult<()> {
     // Set up the complete MCP server stack
     let transaction_service = TransactionService::new();
-    let ynab_client = YnabClient::new(api_token.to_string());
     let handler = Handler::with_full_integration(transaction_service, ynab_client);
-    let mcp_server = McpServer::new(handler);
-
-    // Server loop: read messages, process them, write responses
-    loop {
-        // Read incoming message with Content-Length framing
-        let message = match read_message(&mut reader) {
-            Ok(msg) => msg,
-            Err(_) => break, // EOF or error, exit gracefully
-        };
-
-        // Parse JSON-RPC request
-        let request = match JsonRpcRequest::from_json(&message) {
-            Ok(req) => req,
-            Err(e) => {
-                // Send error response for malformed JSON-RPC
-                let error_response = JsonRpcResponse::error(
-                    serde_json::Value::Null,
-                    -32700,
-                    format!("Parse error: {}", e),
-                    None,
-                );
-                let response_json = error_response.to_json();
-                write_message(&mut writer, &response_json)?;
-                continue;
-            }
-        };
-
-        // Process request through MCP protocol layer
-        let response = match mcp_server.handle_request(request) {
-            Ok(resp) => resp,
-            Err(e) => {
-                // Send error response for MCP handling failure
-                JsonRpcResponse::error(
-                    serde_json::Value::Null,
-                    -32000,
-                    format!("Server error: {}", e),
-                    None,
-                )
-            }
-        };
+    let mcp_server = Arc::new(McpServer::new(handler));
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
 
-        // Write response back with Content-Length framing
-        let response_json = response.to_json();
-        write_message(&mut writer, &response_json)?;
-    }
+    let mut reader = FramedReader::new(BufReader::new(reader));
+    let (response_sender, response_receiver) = mpsc::channel::<(Framing, String)>();
+
+    thread::scope(|scope| {
+        let writer_thread = scope.spawn(move || write_responses(writer, response_receiver));
+        let mut in_flight = JoinSet::new();
+
+        // Server loop: read messages and hand each one to the runtime
+        while !writer_thread.is_finished() {
+            // Read the next framed message, keeping any bytes buffered past it
+            let message = reader.read_message();
+            let framing = reader.framing().unwrap_or(Framing::NewlineDelimited);
+            let message = match message {
+                Ok(Some(msg)) => msg,
+                // EOF, or the stream itself failing, ends the session
+                Ok(None) | Err(YnabError::IoError(_)) => break,
+                // The malformed message was consumed, so answer it and read on
+                Err(e) => {
+                    let response = JsonRpcResponse::error(
+                        serde_json::Value::Null,
+                        PARSE_ERROR,
+                        format!("Parse error: {}", e),
+                        None,
+                    );
+                    if response_sender.send((framing, response.to_json())).is_err() {
+                        break;
+                    }
+                    continue;
+                }
+            };
 
+            let mcp_server = Arc::clone(&mcp_server);
+            let response_sender = response_sender.clone();
+            in_flight.spawn_on(
+                async move {
+                    // Notifications carry no id and must not be answered
+                    if let Some(response) = mcp_server.handle_message(&message).await {
+                        // The writer only hangs up after a write failure, which it reports itself
+                        let _ = response_sender.send((framing, response.to_json()));
+                    }
+                },
+                runtime.handle(),
+            );
+
+            // Reap finished requests so long sessions don't accumulate handles
+            while in_flight.try_join_next().is_some() {}
+        }
+
+        // Let in-flight tool calls finish before closing the response stream
+        runtime.block_on(async { while in_flight.join_next().await.is_some() {} });
+        drop(response_sender);
+
+        writer_thread
+            .join()
+            .unwrap_or_else(|_| Err(YnabError::api_error("Response writer panicked")))
+    })
+}
+
+/// Writes each response in its request's framing until the sending side hangs up.
+fn write_responses<W: Write>(
+    mut writer: W,
+    responses: mpsc::Receiver<(Framing, String)>,
+) -> YnabResult<()> {
+    for (framing, response_json) in responses {
+        write_framed_message(&mut writer, &response_json, framing)?;
+    }
     Ok(())
 }
 
@@ -135,8 +201,9 @@ mod tests {
 
         let output = String::from_utf8(stdout).unwrap();
         assert!(output.contains("Content-Length:"));
-        assert!(output.contains("Server error"));
-        assert!(output.contains("-32000"));
+        // Missing params are invalid params, answered with the request's id
+        assert!(output.contains("-32602"));
+        assert!(output.contains(r#""id":1"#));
     }
 
     #[test]
@@ -196,6 +263,130 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn should_execute_api_backed_tool_on_session_runtime() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/budgets/budget-1/transactions")
+            .with_status(200)
+            .with_body(r#"{"data":{"transactions":[{"id":"txn-1","account_id":"acc-1","category_id":"cat-1","amount":-2500}]}}"#)
+            .create();
+        server
+            .mock("GET", "/budgets/budget-1/accounts")
+            .with_status(200)
+            .with_body(r#"{"data":{"accounts":[]}}"#)
+            .create();
+        server
+            .mock("GET", "/budgets/budget-1/payees")
+            .with_status(200)
+            .with_body(r#"{"data":{"payees":[]}}"#)
+            .create();
+
+        let call = r#"{"jsonrpc":"2.0","method":"tools/call","id":1,"params":{"name":"get_budget_overview","arguments":{"budget_id":"budget-1"}}}"#;
+        let input = format!("Content-Length: {}\r\n\r\n{}", call.len(), call);
+        let mut stdin = Cursor::new(input);
+        let mut stdout = Vec::new();
+        let ynab_client = YnabClient::new_with_base_url("test-token".to_string(), server.url());
+
+        run_mcp_server_with_client(&mut stdin, &mut stdout, ynab_client).unwrap();
+
+        let output = String::from_utf8(stdout).unwrap();
+        assert!(output.contains("total_expenses_milliunits"));
+        assert!(output.contains("2500"));
+        assert!(!output.contains("-32000"));
+    }
+
+    #[test]
+    fn should_answer_newline_delimited_requests_in_the_same_framing() {
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","method":"initialize","id":1,"params":{}}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","method":"tools/list","id":2}"#,
+            "\n"
+        );
+        let mut stdin = Cursor::new(input);
+        let mut stdout = Vec::new();
+
+        run_mcp_server(&mut stdin, &mut stdout, "test-token").unwrap();
+
+        let output = String::from_utf8(stdout).unwrap();
+        assert!(!output.contains("Content-Length:"));
+        let mut ids: Vec<i64> = output
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<serde_json::Value>(line).unwrap()["id"]
+                    .as_i64()
+                    .unwrap()
+            })
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn should_not_answer_notifications() {
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","method":"initialize","id":1,"params":{}}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            "\n"
+        );
+        let mut stdin = Cursor::new(input);
+        let mut stdout = Vec::new();
+
+        run_mcp_server(&mut stdin, &mut stdout, "test-token").unwrap();
+
+        let output = String::from_utf8(stdout).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 1);
+        let response: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(response["id"], 1);
+        assert!(response["result"]["protocolVersion"].is_string());
+    }
+
+    #[test]
+    fn should_answer_malformed_lines_and_keep_reading() {
+        let mut input = b"{\"jsonrpc\":\"2.0\",\"method\":\"\xff\",\"id\":1}\n".to_vec();
+        input.extend_from_slice(br#"{"jsonrpc":"2.0","method":"tools/list","id":2}"#);
+        input.push(b'\n');
+        let mut stdin = Cursor::new(input);
+        let mut stdout = Vec::new();
+
+        run_mcp_server(&mut stdin, &mut stdout, "test-token").unwrap();
+
+        let output = String::from_utf8(stdout).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let parse_error: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(parse_error["error"]["code"], -32700);
+        assert!(parse_error["id"].is_null());
+        let tools: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(tools["id"], 2);
+        assert!(tools["result"]["tools"].is_array());
+    }
+
+    #[test]
+    fn should_answer_every_pipelined_content_length_request() {
+        let first = r#"{"jsonrpc":"2.0","method":"tools/list","id":1}"#;
+        let second = r#"{"jsonrpc":"2.0","method":"tools/list","id":2}"#;
+        let input = format!(
+            "Content-Length: {}\r\nContent-Type: application/json\r\n\r\n{}Content-Length: {}\r\n\r\n{}",
+            first.len(),
+            first,
+            second.len(),
+            second
+        );
+        let mut stdin = Cursor::new(input);
+        let mut stdout = Vec::new();
+
+        run_mcp_server(&mut stdin, &mut stdout, "test-token").unwrap();
+
+        let output = String::from_utf8(stdout).unwrap();
+        assert_eq!(output.matches("Content-Length:").count(), 2);
+        assert!(output.contains(r#""id":1"#));
+        assert!(output.contains(r#""id":2"#));
+    }
+
     #[test]
     fn should_handle_request_id_in_error_response() {
         // Test that request ID is preserved in error responses
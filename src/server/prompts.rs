@@ -0,0 +1,318 @@
+//! MCP prompt templates for repeatable budgeting workflows.
+//!
+//! Each prompt takes a `budget_id` and a `month` (`YYYY-MM`) and expands into
+//! instructions naming the tools an agent should call and what to report.
+//! Every tool call is scoped to the month, or to the months leading up to
+//! it, through the shared `date_range`, `start_date` and `end_date` arguments.
+
+use crate::domain::{CalendarDate, YnabError, YnabResult};
+use serde_json::Value;
+
+/// An argument accepted by a prompt template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptArgument {
+    pub name: String,
+    pub description: String,
+    pub required: bool,
+}
+
+/// A prompt template that clients can list and expand.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prompt {
+    pub name: String,
+    pub description: String,
+    pub arguments: Vec<PromptArgument>,
+}
+
+/// A prompt expanded with its arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedPrompt {
+    pub description: String,
+    /// Text of the single user message carrying the instructions.
+    pub text: String,
+}
+
+/// Lists the available prompt templates.
+pub fn list_prompts() -> Vec<Prompt> {
+    vec![
+        Prompt {
+            name: "monthly_review".to_string(),
+            description: "Reviews a month of budget activity: overview, health and trends"
+                .to_string(),
+            arguments: standard_arguments(),
+        },
+        Prompt {
+            name: "overspending_investigation".to_string(),
+            description: "Finds the categories and transactions behind overspending in a month"
+                .to_string(),
+            arguments: standard_arguments(),
+        },
+        Prompt {
+            name: "subscription_audit".to_string(),
+            description: "Identifies recurring charges and subscriptions worth cancelling"
+                .to_string(),
+            arguments: standard_arguments(),
+        },
+    ]
+}
+
+/// Expands a prompt template with the given arguments.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::server::get_prompt;
+/// use serde_json::json;
+///
+/// let prompt = get_prompt(
+///     "monthly_review",
+///     &json!({"budget_id": "budget-1", "month": "2024-07"}),
+/// ).unwrap();
+///
+/// assert!(prompt.text.contains("budget_health_check"));
+/// assert!(prompt.text.contains("2024-07"));
+/// ```
+pub fn get_prompt(name: &str, arguments: &Value) -> YnabResult<RenderedPrompt> {
+    let prompt = list_prompts()
+        .into_iter()
+        .find(|prompt| prompt.name == name)
+        .ok_or_else(|| YnabError::invalid_argument("name", format!("unknown prompt {}", name)))?;
+
+    let budget_id = required_argument(arguments, "budget_id")?;
+    let month = required_argument(arguments, "month")?;
+    validate_month(month)?;
+
+    let text = match name {
+        "monthly_review" => monthly_review(budget_id, month),
+        "overspending_investigation" => overspending_investigation(budget_id, month),
+        _ => subscription_audit(budget_id, month),
+    };
+
+    Ok(RenderedPrompt {
+        description: format!("{} for {}", prompt.description, month),
+        text,
+    })
+}
+
+fn standard_arguments() -> Vec<PromptArgument> {
+    vec![
+        PromptArgument {
+            name: "budget_id".to_string(),
+            description: "YNAB budget ID, or \"last-used\" for the most recently used budget"
+                .to_string(),
+            required: true,
+        },
+        PromptArgument {
+            name: "month".to_string(),
+            description: "Month to review in YYYY-MM format".to_string(),
+            required: true,
+        },
+    ]
+}
+
+fn monthly_review(budget_id: &str, month: &str) -> String {
+    let (start, end) = months_ending(month, 3);
+    format!(
+        "Run a monthly budget review of YNAB budget `{budget_id}` for {month}.\n\
+         \n\
+         1. Call `get_budget_overview` with budget_id \"{budget_id}\" and date_range \"{month}\" to get income, expenses and net cash flow.\n\
+         2. Call `budget_health_check` with budget_id \"{budget_id}\" and date_range \"{month}\" to get the health score and the categories needing attention.\n\
+         3. Call `analyze_spending_trends` with budget_id \"{budget_id}\", start_date \"{start}\" and end_date \"{end}\" to compare {month} with the two months before it.\n\
+         \n\
+         Report for {month}:\n\
+         - Total income, total expenses and net cash flow.\n\
+         - The health score and each recommendation, with the numbers behind it.\n\
+         - The three categories with the largest spending and whether each is trending up or down.\n\
+         - Two or three concrete actions for next month.\n\
+         Quote amounts from the tool results; do not estimate."
+    )
+}
+
+fn overspending_investigation(budget_id: &str, month: &str) -> String {
+    let (start, end) = months_ending(month, 6);
+    format!(
+        "Investigate overspending in YNAB budget `{budget_id}` during {month}.\n\
+         \n\
+         1. Call `budget_health_check` with budget_id \"{budget_id}\" and date_range \"{month}\" and note every category flagged as overspent or high spending.\n\
+         2. For each flagged category, call `analyze_category_spending` with budget_id \"{budget_id}\", its category_id and date_range \"{month}\" to get the total and transaction count.\n\
+         3. For each flagged category, call `search_transactions` with budget_id \"{budget_id}\", its category_id and date_range \"{month}\", and list the largest outflows.\n\
+         4. Call `analyze_spending_trends` with budget_id \"{budget_id}\", start_date \"{start}\" and end_date \"{end}\" to check whether the overspending in {month} is a one-off or a trend.\n\
+         \n\
+         Report each overspent category for {month} with its total, the transactions that drove it, \
+         whether it is recurring, and a suggested budget adjustment or spending change."
+    )
+}
+
+fn subscription_audit(budget_id: &str, month: &str) -> String {
+    let (start, end) = months_ending(month, 12);
+    format!(
+        "Audit subscriptions and recurring charges in YNAB budget `{budget_id}` as of {month}.\n\
+         \n\
+         1. Call `search_transactions` with budget_id \"{budget_id}\", start_date \"{start}\", end_date \"{end}\" and limit 500 to collect the year of transactions up to {month}.\n\
+         2. Group outflows by payee and memo, and treat charges of a similar amount repeating monthly, quarterly or yearly as subscriptions.\n\
+         3. Call `analyze_spending_trends` with budget_id \"{budget_id}\", start_date \"{start}\" and end_date \"{end}\" to spot subscriptions whose price increased.\n\
+         \n\
+         Report every subscription active in {month} with its amount, billing frequency, annual cost \
+         and the date of the latest charge. Flag duplicates, price increases and anything not charged \
+         in the last two billing periods, and total the annual savings from cancelling the flagged items."
+    )
+}
+
+/// Returns the first and last day, as `YYYY-MM-DD`, of the `count` months
+/// ending with the validated `YYYY-MM` month.
+fn months_ending(month: &str, count: i64) -> (String, String) {
+    let first = CalendarDate::parse(&format!("{}-01", month)).expect("month was validated");
+    (
+        first.add_months(1 - count).to_string(),
+        first.last_of_month().to_string(),
+    )
+}
+
+/// Returns a required string argument, rejecting missing or blank values.
+fn required_argument<'a>(arguments: &'a Value, name: &str) -> YnabResult<&'a str> {
+    match arguments.get(name) {
+        Some(Value::String(value)) if !value.trim().is_empty() => Ok(value),
+        Some(Value::String(_)) | None | Some(Value::Null) => {
+            Err(YnabError::invalid_argument(name, "is required"))
+        }
+        Some(_) => Err(YnabError::invalid_argument(name, "must be a string")),
+    }
+}
+
+/// Checks that a month argument has the `YYYY-MM` form.
+fn validate_month(month: &str) -> YnabResult<()> {
+    let valid = match month.split_once('-') {
+        Some((year, month_number)) => {
+            year.len() == 4
+                && year.chars().all(|c| c.is_ascii_digit())
+                && month_number.len() == 2
+                && matches!(month_number.parse::<u32>(), Ok(1..=12))
+        }
+        None => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(YnabError::invalid_argument(
+            "month",
+            "must be a month in YYYY-MM format",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn should_list_prompts_with_budget_and_month_arguments() {
+        let prompts = list_prompts();
+
+        let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "monthly_review",
+                "overspending_investigation",
+                "subscription_audit"
+            ]
+        );
+        for prompt in &prompts {
+            let arguments: Vec<&str> = prompt.arguments.iter().map(|a| a.name.as_str()).collect();
+            assert_eq!(arguments, vec!["budget_id", "month"]);
+            assert!(prompt.arguments.iter().all(|a| a.required));
+        }
+    }
+
+    #[test]
+    fn should_name_existing_tools_in_every_prompt() {
+        let tool_names: Vec<String> = crate::server::Handler::new()
+            .list_tools()
+            .into_iter()
+            .map(|tool| tool.name)
+            .collect();
+        let arguments = json!({"budget_id": "budget-1", "month": "2024-07"});
+
+        for prompt in list_prompts() {
+            let rendered = get_prompt(&prompt.name, &arguments).unwrap();
+            let referenced: Vec<&str> = rendered
+                .text
+                .split('`')
+                .skip(1)
+                .step_by(2)
+                .filter(|name| *name != "budget-1")
+                .collect();
+
+            assert!(!referenced.is_empty(), "{}", prompt.name);
+            for tool in referenced {
+                assert!(tool_names.iter().any(|name| name == tool), "{}", tool);
+            }
+            assert!(rendered.text.contains("\"budget-1\""));
+            assert!(rendered.text.contains("2024-07"));
+        }
+    }
+
+    #[test]
+    fn should_scope_every_tool_call_to_the_month() {
+        let arguments = json!({"budget_id": "budget-1", "month": "2024-03"});
+        let text = |name: &str| get_prompt(name, &arguments).unwrap().text;
+
+        let review = text("monthly_review");
+        assert_eq!(review.matches("date_range \"2024-03\"").count(), 2);
+        assert!(review.contains("start_date \"2024-01-01\" and end_date \"2024-03-31\""));
+
+        let overspending = text("overspending_investigation");
+        assert_eq!(overspending.matches("date_range \"2024-03\"").count(), 3);
+        assert!(overspending.contains("start_date \"2023-10-01\" and end_date \"2024-03-31\""));
+
+        let audit = text("subscription_audit");
+        assert!(audit.contains("start_date \"2023-04-01\", end_date \"2024-03-31\""));
+        assert!(audit.contains("start_date \"2023-04-01\" and end_date \"2024-03-31\""));
+
+        for prompt in [review, overspending, audit] {
+            assert!(!prompt.contains("months 3") && !prompt.contains("months 12"));
+        }
+    }
+
+    #[test]
+    fn should_reject_unknown_prompt() {
+        let result = get_prompt("tax_return", &json!({"budget_id": "b", "month": "2024-07"}));
+
+        assert!(matches!(
+            result,
+            Err(YnabError::InvalidArgument { ref field, .. }) if field == "name"
+        ));
+    }
+
+    #[test]
+    fn should_require_budget_id_and_month() {
+        let missing_budget = get_prompt("monthly_review", &json!({"month": "2024-07"}));
+        let missing_month = get_prompt("monthly_review", &json!({"budget_id": "b"}));
+
+        assert_eq!(
+            missing_budget.unwrap_err(),
+            YnabError::invalid_argument("budget_id", "is required")
+        );
+        assert_eq!(
+            missing_month.unwrap_err(),
+            YnabError::invalid_argument("month", "is required")
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_month() {
+        for month in ["2024-13", "July", "2024-7", "24-07"] {
+            let result = get_prompt(
+                "subscription_audit",
+                &json!({"budget_id": "b", "month": month}),
+            );
+
+            assert!(
+                matches!(result, Err(YnabError::InvalidArgument { ref field, .. }) if field == "month"),
+                "{}",
+                month
+            );
+        }
+    }
+}
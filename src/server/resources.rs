@@ -0,0 +1,250 @@
+//! MCP resources exposing YNAB budget data as attachable context.
+//!
+//! Resources are addressed with `ynab://` URIs:
+//! - `ynab://budgets` lists the budgets available to the token
+//! - `ynab://budgets/{budget_id}/categories` lists a budget's categories
+//! - `ynab://budgets/{budget_id}/accounts` lists a budget's accounts
+
+use crate::domain::{Account, Budget, Category};
+use serde_json::{Value, json};
+use std::fmt;
+
+/// MIME type of every YNAB resource.
+pub const RESOURCE_MIME_TYPE: &str = "application/json";
+
+/// A concrete resource that clients can read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resource {
+    pub uri: String,
+    pub name: String,
+    pub description: String,
+    pub mime_type: String,
+}
+
+/// A parameterised resource URI that clients can fill in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceTemplate {
+    pub uri_template: String,
+    pub name: String,
+    pub description: String,
+    pub mime_type: String,
+}
+
+/// A parsed `ynab://` resource URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceUri {
+    /// All budgets available to the API token.
+    Budgets,
+    /// The categories of one budget.
+    Categories { budget_id: String },
+    /// The accounts of one budget.
+    Accounts { budget_id: String },
+}
+
+impl ResourceUri {
+    /// Parses a resource URI, returning `None` for URIs this server does not serve.
+    ///
+    /// # Example
+    /// ```
+    /// use ynab_mcp::server::ResourceUri;
+    ///
+    /// let uri = ResourceUri::parse("ynab://budgets/budget-1/accounts").unwrap();
+    /// assert_eq!(uri, ResourceUri::Accounts { budget_id: "budget-1".to_string() });
+    /// assert_eq!(uri.to_string(), "ynab://budgets/budget-1/accounts");
+    /// assert_eq!(ResourceUri::parse("ynab://payees"), None);
+    /// ```
+    pub fn parse(uri: &str) -> Option<Self> {
+        let path = uri.strip_prefix("ynab://budgets")?;
+        if path.is_empty() {
+            return Some(ResourceUri::Budgets);
+        }
+
+        let segments: Vec<&str> = path.strip_prefix('/')?.split('/').collect();
+        match segments.as_slice() {
+            [budget_id, "categories"] if !budget_id.is_empty() => Some(ResourceUri::Categories {
+                budget_id: budget_id.to_string(),
+            }),
+            [budget_id, "accounts"] if !budget_id.is_empty() => Some(ResourceUri::Accounts {
+                budget_id: budget_id.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ResourceUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceUri::Budgets => write!(f, "ynab://budgets"),
+            ResourceUri::Categories { budget_id } => {
+                write!(f, "ynab://budgets/{}/categories", budget_id)
+            }
+            ResourceUri::Accounts { budget_id } => {
+                write!(f, "ynab://budgets/{}/accounts", budget_id)
+            }
+        }
+    }
+}
+
+/// Returns the URI templates for per-budget resources.
+pub fn resource_templates() -> Vec<ResourceTemplate> {
+    vec![
+        ResourceTemplate {
+            uri_template: "ynab://budgets/{budget_id}/categories".to_string(),
+            name: "Budget categories".to_string(),
+            description: "Categories of a YNAB budget with their category groups".to_string(),
+            mime_type: RESOURCE_MIME_TYPE.to_string(),
+        },
+        ResourceTemplate {
+            uri_template: "ynab://budgets/{budget_id}/accounts".to_string(),
+            name: "Budget accounts".to_string(),
+            description: "Chart of accounts of a YNAB budget".to_string(),
+            mime_type: RESOURCE_MIME_TYPE.to_string(),
+        },
+    ]
+}
+
+/// Lists the budgets resource followed by the categories and accounts resources of each budget.
+pub fn list_budget_resources(budgets: &[Budget]) -> Vec<Resource> {
+    let mut resources = vec![Resource {
+        uri: ResourceUri::Budgets.to_string(),
+        name: "Budgets".to_string(),
+        description: "YNAB budgets available to this server".to_string(),
+        mime_type: RESOURCE_MIME_TYPE.to_string(),
+    }];
+
+    for budget in budgets {
+        let budget_id = budget.id().to_string();
+        resources.push(Resource {
+            uri: ResourceUri::Categories {
+                budget_id: budget_id.clone(),
+            }
+            .to_string(),
+            name: format!("{} categories", budget.name()),
+            description: format!("Categories of the {} budget", budget.name()),
+            mime_type: RESOURCE_MIME_TYPE.to_string(),
+        });
+        resources.push(Resource {
+            uri: ResourceUri::Accounts { budget_id }.to_string(),
+            name: format!("{} accounts", budget.name()),
+            description: format!("Accounts of the {} budget", budget.name()),
+            mime_type: RESOURCE_MIME_TYPE.to_string(),
+        });
+    }
+
+    resources
+}
+
+/// Renders budgets as the contents of the budgets resource.
+pub fn render_budgets(budgets: &[Budget]) -> Value {
+    let budgets: Vec<Value> = budgets
+        .iter()
+        .map(|budget| json!({"id": budget.id(), "name": budget.name()}))
+        .collect();
+
+    json!({ "budgets": budgets })
+}
+
+/// Renders a budget's categories as the contents of its categories resource.
+pub fn render_categories(budget_id: &str, categories: &[Category]) -> Value {
+    let categories: Vec<Value> = categories
+        .iter()
+        .map(|category| {
+            json!({
+                "id": category.id(),
+                "name": category.name(),
+                "category_group_id": category.group_id()
+            })
+        })
+        .collect();
+
+    json!({ "budget_id": budget_id, "categories": categories })
+}
+
+/// Renders a budget's accounts as the contents of its accounts resource.
+pub fn render_accounts(budget_id: &str, accounts: &[Account]) -> Value {
+    let accounts: Vec<Value> = accounts
+        .iter()
+        .map(|account| {
+            json!({
+                "id": account.id(),
+                "name": account.name(),
+                "type": account.account_type().api_name(),
+                "on_budget": account.is_on_budget(),
+                "is_liability": account.is_liability(),
+                "closed": account.is_closed(),
+                "balance_milliunits": account.balance().as_milliunits()
+            })
+        })
+        .collect();
+
+    json!({ "budget_id": budget_id, "accounts": accounts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::AccountType;
+
+    #[test]
+    fn should_parse_supported_resource_uris() {
+        assert_eq!(
+            ResourceUri::parse("ynab://budgets"),
+            Some(ResourceUri::Budgets)
+        );
+        assert_eq!(
+            ResourceUri::parse("ynab://budgets/b-1/categories"),
+            Some(ResourceUri::Categories {
+                budget_id: "b-1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn should_reject_unsupported_resource_uris() {
+        for uri in [
+            "ynab://budgets/",
+            "ynab://budgets//accounts",
+            "ynab://budgets/b-1",
+            "ynab://budgets/b-1/payees",
+            "ynab://budgets/b-1/accounts/extra",
+            "https://api.ynab.com/v1/budgets",
+        ] {
+            assert_eq!(ResourceUri::parse(uri), None, "{}", uri);
+        }
+    }
+
+    #[test]
+    fn should_list_resources_for_each_budget() {
+        let budgets = vec![Budget::new("b-1".to_string(), "Home".to_string())];
+
+        let resources = list_budget_resources(&budgets);
+
+        let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
+        assert_eq!(
+            uris,
+            vec![
+                "ynab://budgets",
+                "ynab://budgets/b-1/categories",
+                "ynab://budgets/b-1/accounts"
+            ]
+        );
+        assert_eq!(resources[2].name, "Home accounts");
+    }
+
+    #[test]
+    fn should_render_accounts_with_api_type_names() {
+        let accounts = vec![Account::new(
+            "acc-1".to_string(),
+            "Visa".to_string(),
+            AccountType::CreditCard,
+            true,
+        )];
+
+        let rendered = render_accounts("b-1", &accounts);
+
+        assert_eq!(rendered["budget_id"], "b-1");
+        assert_eq!(rendered["accounts"][0]["type"], "creditCard");
+        assert_eq!(rendered["accounts"][0]["is_liability"], true);
+    }
+}
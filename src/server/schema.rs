@@ -0,0 +1,443 @@
+//! JSON Schema validation for MCP tool arguments.
+//!
+//! Supports the subset of JSON Schema used by the tool definitions:
+//! `type`, `enum`, `required`, `properties`, `additionalProperties`,
+//! `minimum`, `maximum`, `minLength`, `items`, `minItems`, `anyOf`, and
+//! `$ref` to a definition under the root schema's `$defs`.
+
+use crate::domain::{YnabError, YnabResult};
+use serde_json::Value;
+
+/// Validates tool arguments against a tool's input schema.
+///
+/// Missing arguments (`null`) are treated as an empty object so tools with no
+/// required fields can be called without an `arguments` member.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::server::validate_arguments;
+/// use serde_json::json;
+///
+/// let schema = json!({
+///     "type": "object",
+///     "properties": {"months": {"type": "integer", "minimum": 1}},
+///     "required": ["months"]
+/// });
+///
+/// assert!(validate_arguments(&schema, &json!({"months": 3})).is_ok());
+/// assert!(validate_arguments(&schema, &json!({"months": 0})).is_err());
+/// ```
+pub fn validate_arguments(schema: &Value, arguments: &Value) -> YnabResult<()> {
+    let empty_arguments = Value::Object(serde_json::Map::new());
+    let arguments = if arguments.is_null() {
+        &empty_arguments
+    } else {
+        arguments
+    };
+
+    validate_value(schema, arguments, "", schema)
+}
+
+/// Recursively validates a value, reporting the first violation with its field path.
+///
+/// `root` is the tool's whole input schema, against which `$ref` pointers
+/// such as `#/$defs/transaction_filter` are resolved.
+fn validate_value(schema: &Value, value: &Value, path: &str, root: &Value) -> YnabResult<()> {
+    if let Some(referenced) = schema["$ref"]
+        .as_str()
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+    {
+        validate_value(referenced, value, path, root)?;
+    }
+
+    if let Some(expected) = schema.get("type") {
+        validate_type(expected, value, path)?;
+    }
+
+    if let Some(allowed) = schema["enum"].as_array()
+        && !allowed.contains(value)
+    {
+        let options: Vec<String> = allowed.iter().map(|option| option.to_string()).collect();
+        return Err(violation(
+            path,
+            format!("must be one of {}", options.join(", ")),
+        ));
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema["minimum"].as_f64()
+            && number < minimum
+        {
+            return Err(violation(path, format!("must be at least {}", minimum)));
+        }
+        if let Some(maximum) = schema["maximum"].as_f64()
+            && number > maximum
+        {
+            return Err(violation(path, format!("must be at most {}", maximum)));
+        }
+    }
+
+    if let (Some(text), Some(min_length)) = (value.as_str(), schema["minLength"].as_u64())
+        && (text.chars().count() as u64) < min_length
+    {
+        return Err(violation(
+            path,
+            format!("must be at least {} characters long", min_length),
+        ));
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min_items) = schema["minItems"].as_u64()
+            && (items.len() as u64) < min_items
+        {
+            return Err(violation(
+                path,
+                format!("must contain at least {} items", min_items),
+            ));
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                validate_value(item_schema, item, &format!("{}[{}]", path, index), root)?;
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        if let Some(required) = schema["required"].as_array() {
+            for field in required.iter().filter_map(|field| field.as_str()) {
+                if !object.contains_key(field) {
+                    return Err(violation(&child_path(path, field), "is required"));
+                }
+            }
+        }
+
+        let properties = schema["properties"].as_object();
+        for (field, field_value) in object {
+            match properties.and_then(|properties| properties.get(field)) {
+                Some(field_schema) => {
+                    validate_value(field_schema, field_value, &child_path(path, field), root)?
+                }
+                None if schema["additionalProperties"] == Value::Bool(false) => {
+                    return Err(violation(
+                        &child_path(path, field),
+                        "is not a recognised argument",
+                    ));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let Some(alternatives) = schema["anyOf"].as_array()
+        && !alternatives
+            .iter()
+            .any(|alternative| validate_value(alternative, value, path, root).is_ok())
+    {
+        // When only one alternative accepts the value's type, its own
+        // violation says more than a summary of every alternative.
+        let mut typed = alternatives.iter().filter(|alternative| {
+            alternative
+                .get("type")
+                .is_some_and(|expected| validate_type(expected, value, path).is_ok())
+        });
+        return match (typed.next(), typed.next()) {
+            (Some(alternative), None) => validate_value(alternative, value, path, root),
+            _ => Err(violation(path, describe_alternatives(alternatives))),
+        };
+    }
+
+    Ok(())
+}
+
+/// Describes `anyOf` alternatives, naming the fields when each alternative only requires fields.
+fn describe_alternatives(alternatives: &[Value]) -> String {
+    let required_sets: Option<Vec<String>> = alternatives
+        .iter()
+        .map(|alternative| {
+            let required = alternative["required"].as_array()?;
+            let fields: Vec<&str> = required.iter().filter_map(|f| f.as_str()).collect();
+            Some(fields.join(" and "))
+        })
+        .collect();
+
+    match required_sets {
+        Some(sets) if !sets.is_empty() => format!("requires {}", sets.join(" or ")),
+        _ => "does not match any of the allowed argument combinations".to_string(),
+    }
+}
+
+/// Checks a value against a `type` keyword, which may be a single type or a list.
+fn validate_type(expected: &Value, value: &Value, path: &str) -> YnabResult<()> {
+    let expected_types: Vec<&str> = match expected {
+        Value::String(single) => vec![single.as_str()],
+        Value::Array(many) => many.iter().filter_map(|t| t.as_str()).collect(),
+        _ => return Ok(()),
+    };
+
+    if expected_types
+        .iter()
+        .any(|expected_type| matches_type(expected_type, value))
+    {
+        return Ok(());
+    }
+
+    Err(violation(
+        path,
+        format!(
+            "must be of type {} but was {}",
+            expected_types.join(" or "),
+            type_name(value)
+        ),
+    ))
+}
+
+/// Returns whether a value is an instance of the named JSON Schema type.
+fn matches_type(expected_type: &str, value: &Value) -> bool {
+    match expected_type {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+/// Returns the JSON Schema type name of a value for error messages.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Joins a parent path and a property name into a dotted field path.
+fn child_path(parent: &str, field: &str) -> String {
+    if parent.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", parent, field)
+    }
+}
+
+/// Builds an InvalidArgument error, naming the root arguments object when no field applies.
+fn violation<S: Into<String>>(path: &str, message: S) -> YnabError {
+    let field = if path.is_empty() { "arguments" } else { path };
+    YnabError::invalid_argument(field, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn search_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "budget_id": {"type": "string", "minLength": 1},
+                "limit": {"type": "integer", "minimum": 1, "maximum": 500},
+                "sort_by": {"type": "string", "enum": ["amount_ascending", "date"]},
+                "categories": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["budget_id"],
+            "additionalProperties": false
+        })
+    }
+
+    fn field_of(result: YnabResult<()>) -> String {
+        match result {
+            Err(YnabError::InvalidArgument { field, .. }) => field,
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_accept_valid_arguments() {
+        let arguments = json!({
+            "budget_id": "budget-1",
+            "limit": 10,
+            "sort_by": "date",
+            "categories": ["groceries"]
+        });
+
+        assert!(validate_arguments(&search_schema(), &arguments).is_ok());
+    }
+
+    #[test]
+    fn should_report_missing_required_field() {
+        let result = validate_arguments(&search_schema(), &json!({"limit": 5}));
+
+        assert_eq!(field_of(result), "budget_id");
+    }
+
+    #[test]
+    fn should_treat_null_arguments_as_empty_object() {
+        let result = validate_arguments(&search_schema(), &Value::Null);
+
+        assert_eq!(field_of(result), "budget_id");
+    }
+
+    #[test]
+    fn should_report_wrong_type() {
+        let result = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "limit": "ten"}),
+        );
+
+        match result {
+            Err(YnabError::InvalidArgument { field, message }) => {
+                assert_eq!(field, "limit");
+                assert_eq!(message, "must be of type integer but was string");
+            }
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_fractional_integer() {
+        let result = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "limit": 2.5}),
+        );
+
+        assert_eq!(field_of(result), "limit");
+    }
+
+    #[test]
+    fn should_enforce_numeric_bounds() {
+        let too_small = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "limit": 0}),
+        );
+        let too_large = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "limit": 501}),
+        );
+
+        assert_eq!(field_of(too_small), "limit");
+        assert_eq!(field_of(too_large), "limit");
+    }
+
+    #[test]
+    fn should_enforce_enum_values() {
+        let result = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "sort_by": "payee"}),
+        );
+
+        match result {
+            Err(YnabError::InvalidArgument { field, message }) => {
+                assert_eq!(field, "sort_by");
+                assert!(message.contains("\"amount_ascending\""));
+            }
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_report_array_item_path() {
+        let result = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "categories": ["groceries", 7]}),
+        );
+
+        assert_eq!(field_of(result), "categories[1]");
+    }
+
+    #[test]
+    fn should_reject_unknown_arguments_when_additional_properties_disallowed() {
+        let result = validate_arguments(
+            &search_schema(),
+            &json!({"budget_id": "budget-1", "colour": "blue"}),
+        );
+
+        assert_eq!(field_of(result), "colour");
+    }
+
+    #[test]
+    fn should_enforce_min_length() {
+        let result = validate_arguments(&search_schema(), &json!({"budget_id": ""}));
+
+        assert_eq!(field_of(result), "budget_id");
+    }
+
+    #[test]
+    fn should_require_one_of_any_of_alternatives() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "category_id": {"type": "string"},
+                "category_name": {"type": "string"}
+            },
+            "anyOf": [{"required": ["category_id"]}, {"required": ["category_name"]}]
+        });
+
+        assert!(validate_arguments(&schema, &json!({"category_name": "Rent"})).is_ok());
+        match validate_arguments(&schema, &json!({})) {
+            Err(YnabError::InvalidArgument { field, message }) => {
+                assert_eq!(field, "arguments");
+                assert_eq!(message, "requires category_id or category_name");
+            }
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_follow_references_into_recursive_definitions() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"filter": {"$ref": "#/$defs/filter"}},
+            "$defs": {
+                "filter": {
+                    "type": "object",
+                    "properties": {
+                        "memo": {"type": "string"},
+                        "any": {"type": "array", "items": {"$ref": "#/$defs/filter"}}
+                    },
+                    "additionalProperties": false
+                }
+            }
+        });
+
+        assert!(
+            validate_arguments(&schema, &json!({"filter": {"any": [{"memo": "rent"}]}})).is_ok()
+        );
+        assert_eq!(
+            field_of(validate_arguments(
+                &schema,
+                &json!({"filter": {"any": [{"memo": "rent"}, {"colour": "blue"}]}})
+            )),
+            "filter.any[1].colour"
+        );
+    }
+
+    #[test]
+    fn should_report_violation_of_the_only_alternative_of_matching_type() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "cleared": {"anyOf": [
+                    {"type": "string", "enum": ["cleared", "uncleared"]},
+                    {"type": "array", "items": {"type": "string", "enum": ["cleared", "uncleared"]}}
+                ]}
+            }
+        });
+
+        match validate_arguments(&schema, &json!({"cleared": ["cleared", "pending"]})) {
+            Err(YnabError::InvalidArgument { field, message }) => {
+                assert_eq!(field, "cleared[1]");
+                assert_eq!(message, "must be one of \"cleared\", \"uncleared\"");
+            }
+            other => panic!("Expected InvalidArgument, got: {:?}", other),
+        }
+    }
+}
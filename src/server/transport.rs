@@ -1,43 +1,156 @@
 //! MCP server transport layer for stdin/stdout communication.
+//!
+//! Two stdio framings are supported: newline-delimited JSON, as used by the MCP
+//! stdio transport, and the older LSP-style `Content-Length` header framing.
 
 use crate::domain::{YnabError, YnabResult};
-use std::io::{BufRead, BufReader, Read, Write};
+use std::io::{BufRead, Write};
+
+/// Message framing used on a stdio stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framing {
+    /// One JSON message per line (the MCP stdio transport).
+    NewlineDelimited,
+    /// LSP-style `Content-Length` headers followed by a blank line and the body.
+    ContentLength,
+}
 
-/// Reads a message from the given reader using Content-Length header.
+/// Persistent reader that splits a byte stream into framed messages.
 ///
-/// MCP protocol uses HTTP-like headers with Content-Length to frame messages
-/// over stdio streams.
-pub fn read_message<R: Read>(reader: R) -> YnabResult<String> {
-    let mut buf_reader = BufReader::new(reader);
-    let mut header_line = String::new();
+/// The reader keeps its buffer between messages, so several messages delivered in
+/// a single read are returned one at a time. When no framing is configured it is
+/// detected from the first message: a message starting with `{` or `[` is treated
+/// as newline-delimited JSON, anything else as `Content-Length` framing.
+///
+/// # Example
+/// ```
+/// use ynab_mcp::server::{FramedReader, Framing};
+/// use std::io::Cursor;
+///
+/// let input = Cursor::new("{\"id\":1}\n{\"id\":2}\n");
+/// let mut reader = FramedReader::new(input);
+///
+/// assert_eq!(reader.read_message().unwrap(), Some("{\"id\":1}".to_string()));
+/// assert_eq!(reader.framing(), Some(Framing::NewlineDelimited));
+/// assert_eq!(reader.read_message().unwrap(), Some("{\"id\":2}".to_string()));
+/// assert_eq!(reader.read_message().unwrap(), None);
+/// ```
+#[derive(Debug)]
+pub struct FramedReader<R: BufRead> {
+    reader: R,
+    framing: Option<Framing>,
+}
+
+impl<R: BufRead> FramedReader<R> {
+    /// Creates a reader that detects the framing from the first message.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            framing: None,
+        }
+    }
 
-    // Read Content-Length header
-    buf_reader.read_line(&mut header_line)?;
+    /// Creates a reader that always uses the given framing.
+    pub fn with_framing(reader: R, framing: Framing) -> Self {
+        Self {
+            reader,
+            framing: Some(framing),
+        }
+    }
 
-    if !header_line.starts_with("Content-Length:") {
-        return Err(YnabError::api_error(
-            "Expected Content-Length header".to_string(),
-        ));
+    /// Returns the framing in use, or `None` if it has not been detected yet.
+    pub fn framing(&self) -> Option<Framing> {
+        self.framing
     }
 
-    // Parse content length
-    let length_str = header_line
-        .trim()
-        .strip_prefix("Content-Length:")
-        .ok_or_else(|| YnabError::api_error("Invalid Content-Length header".to_string()))?
-        .trim();
+    /// Reads the next message, returning `None` at the end of the stream.
+    pub fn read_message(&mut self) -> YnabResult<Option<String>> {
+        let framing = match self.framing {
+            Some(framing) => framing,
+            None => match self.detect_framing()? {
+                Some(framing) => {
+                    self.framing = Some(framing);
+                    framing
+                }
+                None => return Ok(None),
+            },
+        };
+
+        match framing {
+            Framing::NewlineDelimited => read_line_message(&mut self.reader),
+            Framing::ContentLength => {
+                if at_end_of_stream(&mut self.reader)? {
+                    return Ok(None);
+                }
+                read_message(&mut self.reader).map(Some)
+            }
+        }
+    }
 
-    let content_length: usize = length_str.parse().map_err(|_| {
-        YnabError::api_error(format!("Invalid Content-Length value: {}", length_str))
-    })?;
+    /// Skips leading whitespace and inspects the first byte of the stream.
+    fn detect_framing(&mut self) -> YnabResult<Option<Framing>> {
+        loop {
+            let buffer = self.reader.fill_buf()?;
+            let Some(&first) = buffer.first() else {
+                return Ok(None);
+            };
+
+            if first.is_ascii_whitespace() {
+                self.reader.consume(1);
+                continue;
+            }
 
-    // Read empty line (separator)
-    let mut empty_line = String::new();
-    buf_reader.read_line(&mut empty_line)?;
+            return Ok(Some(if first == b'{' || first == b'[' {
+                Framing::NewlineDelimited
+            } else {
+                Framing::ContentLength
+            }));
+        }
+    }
+}
+
+/// Reads a message from the given reader using Content-Length header.
+///
+/// MCP protocol uses HTTP-like headers with Content-Length to frame messages
+/// over stdio streams. Other headers such as `Content-Type` are accepted and
+/// ignored. Pass the same buffered reader to every call so bytes buffered past
+/// the end of one message are kept for the next.
+pub fn read_message<R: BufRead>(mut reader: R) -> YnabResult<String> {
+    let mut content_length = None;
+
+    // Read headers up to the blank separator line
+    loop {
+        let mut header_line = String::new();
+        reader.read_line(&mut header_line)?;
+
+        if !header_line.ends_with('\n') {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+
+        let header_line = header_line.trim();
+        if header_line.is_empty() {
+            break;
+        }
+
+        let (name, value) = header_line.split_once(':').ok_or_else(|| {
+            YnabError::api_error(format!("Malformed transport header: {}", header_line))
+        })?;
+
+        if name.trim().eq_ignore_ascii_case("Content-Length") {
+            let length_str = value.trim();
+            let length: usize = length_str.parse().map_err(|_| {
+                YnabError::api_error(format!("Invalid Content-Length value: {}", length_str))
+            })?;
+            content_length = Some(length);
+        }
+    }
+
+    let content_length = content_length
+        .ok_or_else(|| YnabError::api_error("Expected Content-Length header".to_string()))?;
 
     // Read message content
     let mut buffer = vec![0; content_length];
-    buf_reader.read_exact(&mut buffer)?;
+    reader.read_exact(&mut buffer)?;
 
     let message = String::from_utf8(buffer)
         .map_err(|_| YnabError::api_error("Message content is not valid UTF-8".to_string()))?;
@@ -45,6 +158,36 @@ pub fn read_message<R: Read>(reader: R) -> YnabResult<String> {
     Ok(message)
 }
 
+/// Reads the next non-blank line as a newline-delimited JSON message.
+fn read_line_message<R: BufRead>(reader: &mut R) -> YnabResult<Option<String>> {
+    loop {
+        let mut buffer = Vec::new();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            return Ok(None);
+        }
+
+        let line = String::from_utf8(buffer)
+            .map_err(|_| YnabError::api_error("Message content is not valid UTF-8".to_string()))?;
+        let message = line.trim();
+
+        if !message.is_empty() {
+            return Ok(Some(message.to_string()));
+        }
+    }
+}
+
+/// Consumes blank lines between messages and reports whether the stream has ended.
+fn at_end_of_stream<R: BufRead>(reader: &mut R) -> YnabResult<bool> {
+    loop {
+        let buffer = reader.fill_buf()?;
+        match buffer.first() {
+            None => return Ok(true),
+            Some(byte) if byte.is_ascii_whitespace() => reader.consume(1),
+            Some(_) => return Ok(false),
+        }
+    }
+}
+
 /// Writes a message to the given writer with Content-Length header.
 ///
 /// Formats the message with proper MCP transport framing.
@@ -58,6 +201,26 @@ pub fn write_message<W: Write>(mut writer: W, message: &str) -> YnabResult<()> {
     Ok(())
 }
 
+/// Writes a message using the given framing.
+///
+/// Newline-delimited messages must not contain embedded newlines, which holds
+/// for compact JSON produced by `serde_json`.
+pub fn write_framed_message<W: Write>(
+    mut writer: W,
+    message: &str,
+    framing: Framing,
+) -> YnabResult<()> {
+    match framing {
+        Framing::ContentLength => write_message(writer, message),
+        Framing::NewlineDelimited => {
+            writer.write_all(message.as_bytes())?;
+            writer.write_all(b"\n")?;
+            writer.flush()?;
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +355,91 @@ mod tests {
         let bytes_read = reader.read_line(&mut buf).unwrap();
         assert_eq!(bytes_read, 0); // EOF
     }
+
+    #[test]
+    fn should_accept_extra_headers_in_any_order() {
+        let json_message = r#"{"jsonrpc":"2.0","method":"tools/list","id":1}"#;
+        let input = format!(
+            "Content-Type: application/vscode-jsonrpc; charset=utf-8\r\ncontent-length: {}\r\n\r\n{}",
+            json_message.len(),
+            json_message
+        );
+        let mut reader = Cursor::new(input);
+
+        let message = read_message(&mut reader).unwrap();
+
+        assert_eq!(message, json_message);
+    }
+
+    #[test]
+    fn should_read_pipelined_content_length_messages() {
+        let first = r#"{"id":1}"#;
+        let second = r#"{"id":2}"#;
+        let input = format!(
+            "Content-Length: {}\r\n\r\n{}Content-Length: {}\r\n\r\n{}",
+            first.len(),
+            first,
+            second.len(),
+            second
+        );
+        let mut reader = FramedReader::new(Cursor::new(input));
+
+        assert_eq!(reader.read_message().unwrap(), Some(first.to_string()));
+        assert_eq!(reader.framing(), Some(Framing::ContentLength));
+        assert_eq!(reader.read_message().unwrap(), Some(second.to_string()));
+        assert_eq!(reader.read_message().unwrap(), None);
+    }
+
+    #[test]
+    fn should_read_newline_delimited_messages() {
+        let input = "{\"id\":1}\r\n\n{\"id\":2}\n{\"id\":3}";
+        let mut reader = FramedReader::new(Cursor::new(input));
+
+        assert_eq!(
+            reader.read_message().unwrap(),
+            Some(r#"{"id":1}"#.to_string())
+        );
+        assert_eq!(reader.framing(), Some(Framing::NewlineDelimited));
+        assert_eq!(
+            reader.read_message().unwrap(),
+            Some(r#"{"id":2}"#.to_string())
+        );
+        assert_eq!(
+            reader.read_message().unwrap(),
+            Some(r#"{"id":3}"#.to_string())
+        );
+        assert_eq!(reader.read_message().unwrap(), None);
+    }
+
+    #[test]
+    fn should_use_configured_framing() {
+        let input = "{\"id\":1}\n";
+        let mut reader = FramedReader::with_framing(Cursor::new(input), Framing::ContentLength);
+
+        let result = reader.read_message();
+
+        // The JSON line is read as a header, so the stream ends before any body
+        assert!(matches!(result, Err(YnabError::IoError(_))));
+    }
+
+    #[test]
+    fn should_report_end_of_empty_stream() {
+        let mut reader = FramedReader::new(Cursor::new("  \n"));
+
+        assert_eq!(reader.read_message().unwrap(), None);
+        assert_eq!(reader.framing(), None);
+    }
+
+    #[test]
+    fn should_write_newline_delimited_message() {
+        let response = r#"{"jsonrpc":"2.0","id":1,"result":{}}"#;
+        let mut writer = Vec::new();
+
+        write_framed_message(&mut writer, response, Framing::NewlineDelimited).unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            format!("{}\n", response)
+        );
+    }
 }
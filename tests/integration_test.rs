@@ -1,7 +1,8 @@
 //! Integration tests for the complete MCP server runtime.
 
 use std::io::Cursor;
-use ynab_mcp::server::run_mcp_server;
+use ynab_mcp::YnabClient;
+use ynab_mcp::server::{run_mcp_server, run_mcp_server_with_client};
 
 #[test]
 fn should_run_complete_mcp_server_session() {
@@ -59,7 +60,23 @@ fn should_handle_tools_list_request() {
 
 #[test]
 fn should_handle_tools_call_request() {
-    // Test 3: Tools call request
+    // Test 3: Tools call request against a mock YNAB API
+    let mut server = mockito::Server::new();
+    server
+        .mock("GET", "/budgets/test-budget/categories")
+        .with_status(200)
+        .with_body(
+            r#"{"data":{"category_groups":[{"id":"group-1","categories":[{"id":"cat-1","name":"Groceries"}]}]}}"#,
+        )
+        .create();
+    server
+        .mock("GET", "/budgets/test-budget/transactions")
+        .with_status(200)
+        .with_body(
+            r#"{"data":{"transactions":[{"id":"txn-1","account_id":"acc-1","category_id":"cat-1","amount":-42000}]}}"#,
+        )
+        .create();
+
     let call_message = r#"{"jsonrpc":"2.0","method":"tools/call","id":3,"params":{"name":"analyze_category_spending","arguments":{"budget_id":"test-budget","category_name":"Groceries"}}}"#;
     let input = format!(
         "Content-Length: {}\r\n\r\n{}",
@@ -69,15 +86,17 @@ fn should_handle_tools_call_request() {
 
     let mut stdin = Cursor::new(input);
     let mut stdout = Vec::new();
+    let ynab_client = YnabClient::new_with_base_url("test-api-token".to_string(), server.url());
 
-    run_mcp_server(&mut stdin, &mut stdout, "test-api-token").unwrap();
+    run_mcp_server_with_client(&mut stdin, &mut stdout, ynab_client).unwrap();
 
     let output = String::from_utf8(stdout).unwrap();
     println!("Tools call response: {}", output);
 
-    // Should contain tools call response with content
+    // Should contain tools call response with content calculated from the API data
     assert!(output.contains("Content-Length:"));
     assert!(output.contains("content"));
     assert!(output.contains("type"));
     assert!(output.contains("text"));
+    assert!(output.contains("42000"));
 }
@@ -114,3 +114,18 @@ fn should_start_server_with_valid_token() {
     // since we provided a non-empty token
     assert!(!stderr.contains("YNAB_API_TOKEN environment variable is required"));
 }
+
+#[test]
+fn should_reject_invalid_http_bind_address() {
+    let output = Command::new("cargo")
+        .args(["run", "--bin", "ynab-mcp"])
+        .env("YNAB_API_TOKEN", "test-token")
+        .env("YNAB_MCP_HTTP_ADDR", "not-an-address")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("YNAB_MCP_HTTP_ADDR must be a socket address"));
+}